@@ -57,7 +57,7 @@ fn test_register_pool() {
     assert_eq!(pool.risk_level, RiskLevel::Low);
     assert_eq!(pool.apy, 500);
     assert_eq!(pool.max_capacity, 1_000_000_000);
-    assert_eq!(pool.active, true);
+    assert!(pool.active);
     assert_eq!(pool.total_liquidity, 0);
 }
 
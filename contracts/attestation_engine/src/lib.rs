@@ -1,8 +1,10 @@
 #![no_std]
-use shared_utils::RateLimiter;
+use shared_utils::{
+    fee_from_bps, BatchError, BatchMode, BatchProcessor, BatchResultVoid, RateLimiter, BPS_MAX,
+};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, Address, Env, IntoVal,
-    Map, String, Symbol, TryIntoVal, Val, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, xdr::ToXdr, Address,
+    Bytes, BytesN, Env, IntoVal, Map, String, Symbol, TryIntoVal, Val, Vec,
 };
 
 pub const CURRENT_VERSION: u32 = 1;
@@ -38,6 +40,54 @@ pub enum AttestationError {
     FeeRecipientNotSet = 10,
     /// Insufficient collected fees to withdraw
     InsufficientFees = 11,
+    /// Upgrade WASM hash is zero
+    InvalidWasmHash = 12,
+    /// Migration source version does not match the stored version
+    InvalidVersion = 13,
+    /// Storage is already at CURRENT_VERSION
+    AlreadyMigrated = 14,
+    /// Staking has not been configured (no stake token set)
+    StakingNotConfigured = 15,
+    /// Invalid stake amount (must be positive)
+    InvalidStakeAmount = 16,
+    /// Verifier has no bonded stake
+    StakeNotFound = 17,
+    /// Stake is still bonded or unbonding delay has not elapsed
+    StakeLocked = 18,
+    /// Attestation index does not exist for the commitment
+    AttestationNotFound = 19,
+    /// Dispute not found
+    DisputeNotFound = 20,
+    /// Attestation already has an open dispute
+    DisputeAlreadyOpen = 21,
+    /// Dispute has already been resolved
+    DisputeAlreadyResolved = 22,
+    /// Voter has already voted on this dispute
+    AlreadyVoted = 23,
+    /// Invalid basis points value (must be 0-10000)
+    InvalidBps = 24,
+    /// Attestation has already been revoked
+    AttestationRevoked = 25,
+    /// Verifier has not registered an ed25519 signing key
+    SigningKeyNotRegistered = 26,
+    /// Signed attestation nonce is not the verifier's next nonce
+    InvalidNonce = 27,
+    /// Scoring configuration out of bounds
+    InvalidScoringConfig = 28,
+    /// Enforcement records cannot be disputed or revoked
+    EnforcementRecord = 29,
+    /// Attestations can only be archived once the commitment is no longer active
+    CommitmentStillActive = 30,
+    /// Health-check cadence is zero or looser than the commitment type allows
+    InvalidCadence = 31,
+    /// No pending enforcement for this attestation
+    EnforcementNotPending = 32,
+    /// Enforcement waits until the dispute window has closed
+    DisputeWindowOpen = 33,
+    /// Commitment has attestations with open disputes
+    CommitmentHasOpenDisputes = 34,
+    /// Verifier nonce space is exhausted
+    NonceOverflow = 35,
 }
 
 // ============================================================================
@@ -53,7 +103,7 @@ pub enum DataKey {
     CoreContract,
     /// Verifier whitelist (Address -> bool)
     Verifier(Address),
-    /// Attestations for a commitment (commitment_id -> Vec<Attestation>)
+    /// Legacy unpaged attestations (commitment_id -> Vec<Attestation>); migrated on write
     Attestations(String),
     /// Health metrics for a commitment (commitment_id -> HealthMetrics)
     HealthMetrics(String),
@@ -77,6 +127,68 @@ pub enum DataKey {
     AttestationFeeAsset,
     /// Collected fees per asset (asset -> i128)
     CollectedFees(Address),
+    /// On-chain storage version (u32)
+    Version,
+    /// Share of each attestation fee credited to the verifier (bps, 0 = all to protocol)
+    VerifierFeeShareBps,
+    /// Attestation fee override per attestation kind (attestation_type -> i128)
+    AttestationFeeTier(String),
+    /// Claimable verifier fee share ((verifier, asset) -> i128)
+    VerifierRewards(Address, Address),
+    /// Verifier staking configuration (StakingConfig)
+    StakingConfig,
+    /// Bonded stake per verifier (Address -> VerifierStake)
+    VerifierStake(Address),
+    /// Counter for generating dispute IDs
+    DisputeCounter,
+    /// Dispute record (dispute_id -> Dispute)
+    Dispute(u64),
+    /// Open dispute for an attestation ((commitment_id, index) -> dispute_id)
+    AttestationDispute(String, u32),
+    /// Quorum vote cast on a dispute ((dispute_id, voter) -> bool)
+    DisputeVote(u64, Address),
+    /// ed25519 public key for off-chain signed attestations (Address -> BytesN<32>)
+    VerifierSigningKey(Address),
+    /// Next acceptable signed-attestation nonce per verifier (Address -> u64)
+    VerifierNonce(Address),
+    /// Compliance scoring model (instance, -> ScoringConfig)
+    ScoringConfig,
+    /// Bounded compliance score history (String -> Vec<ScoreSnapshot>)
+    ScoreHistory(String),
+    /// Rolling window of health_check value observations (String -> Vec<i128>)
+    ValueObservations(String),
+    /// Penalties still fading under decay (String -> Vec<ScorePenalty>)
+    ScorePenalties(String),
+    /// Automatic enforcement policy (instance, -> EnforcementPolicy)
+    EnforcementPolicy,
+    /// Attestation log bucket ((commitment_id, bucket) -> Vec<Attestation>)
+    AttestationBucket(String, u32),
+    /// Attestation log length including archived entries (commitment_id -> u32)
+    AttestationLogLength(String),
+    /// Rolling summary of archived buckets (commitment_id -> AttestationSummary)
+    AttestationSummary(String),
+    /// Health metrics as of the last archival (commitment_id -> HealthMetrics)
+    ArchivedMetrics(String),
+    /// Value observations as of the last archival (commitment_id -> Vec<i128>)
+    ArchivedObservations(String),
+    /// Whitelisted verifiers in insertion order (instance, -> Vec<Address>)
+    VerifierList,
+    /// Per-verifier performance metrics (Address -> VerifierMetrics)
+    VerifierMetrics(Address),
+    /// Required health-check interval per commitment type (String -> u64 seconds)
+    HealthCheckCadence(String),
+    /// Health-check schedule for a monitored commitment (String -> HealthCheckSchedule)
+    HealthCheckSchedule(String),
+    /// Page of the monitored-commitment index (page -> Vec<String>)
+    MonitoredPage(u32),
+    /// Number of monitored-commitment index pages (instance, -> u32)
+    MonitoredPageCount,
+    /// Index page holding a monitored commitment (String -> u32)
+    MonitoredSlot(String),
+    /// Enforcement triggered by an attestation ((commitment_id, index) -> EnforcementRecord)
+    Enforcement(String, u32),
+    /// Open disputes against a commitment's attestations (String -> u32)
+    OpenDisputes(String),
 }
 
 #[contracttype]
@@ -88,6 +200,7 @@ pub struct Attestation {
     pub data: Map<String, String>, // Flexible data structure
     pub is_compliant: bool,
     pub verified_by: Address,
+    pub revoked: bool, // revoked attestations are kept for audit but ignored in scoring
 }
 
 /// Parameters for batch attestation operations
@@ -100,6 +213,18 @@ pub struct AttestParams {
     pub is_compliant: bool,
 }
 
+/// Attestation signed off-chain by a verifier's registered ed25519 key
+///
+/// The signature covers `get_signing_payload(verifier, params, nonce)`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SignedAttestation {
+    pub verifier: Address,
+    pub params: AttestParams,
+    pub nonce: u64,
+    pub signature: BytesN<64>,
+}
+
 // Import Commitment types from commitment_core (define locally for cross-contract calls)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -129,46 +254,208 @@ pub struct Commitment {
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum DataKey {
-    Admin,
-    CommitmentCore,
-    HealthState(String),
-    Attestations(String),
+pub struct HealthMetrics {
+    pub commitment_id: String,
+    pub current_value: i128,
+    pub initial_value: i128,
+    pub drawdown_percent: i128,
+    pub fees_generated: i128,
+    pub volatility_exposure: i128, // realized volatility of returns, in bps
+    pub max_drawdown_bps: i128,    // max drawdown from peak over the observation window
+    pub last_attestation: u64,
+    pub compliance_score: u32, // 0-100
 }
 
+/// Verifier staking and dispute parameters
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct HealthState {
-    pub fees_generated: i128,
-    pub volatility_exposure: i128,
-    pub last_attestation: u64,
-    pub compliance_score: u32, // 0-100; 0 means "unknown / not calculated"
+pub struct StakingConfig {
+    pub stake_token: Address,
+    pub min_stake: i128,           // bond required on add_verifier (0 = staking disabled)
+    pub unbonding_period: u64,     // seconds between remove_verifier and withdraw_stake
+    pub slash_bps: u32,            // share of bond slashed per lost dispute (0-10000)
+    pub dispute_quorum: u32,       // verifier votes needed to resolve (0 = admin only)
 }
 
+/// Bond posted by a verifier
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct Attestation {
+pub struct VerifierStake {
+    pub amount: i128,
+    pub bonded_at: u64,
+    pub unbonding_until: u64, // 0 while bonded; withdrawable once ledger time passes it
+    pub open_disputes: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,   // attestation was wrong; verifier slashed
+    Rejected, // attestation stands
+}
+
+/// Challenge raised against a recorded attestation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dispute {
+    pub dispute_id: u64,
     pub commitment_id: String,
+    pub attestation_index: u32,
+    pub verifier: Address,
+    pub challenger: Address,
+    pub reason: String,
+    pub status: DisputeStatus,
+    pub votes_upheld: u32,
+    pub votes_rejected: u32,
+    pub opened_at: u64,
+    pub resolved_at: u64,
+    pub slashed_amount: i128,
+}
+
+/// Compliance scoring model
+///
+/// Penalties are in score points; severity multipliers are in basis points of
+/// `violation_penalty`. The defaults reproduce the original fixed model.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoringConfig {
+    pub violation_penalty: u32,         // points per violation before severity
+    pub non_compliant_penalty: u32,     // points per non-compliant, non-violation attestation
+    pub compliant_bonus: u32,           // points per compliant attestation (score capped at 100)
+    pub on_track_bonus: u32,            // points when no attestations yet, scaled by loss headroom
+    pub severity_high_bps: u32,         // multiplier for severity "high"
+    pub severity_medium_bps: u32,       // multiplier for severity "medium"
+    pub severity_low_bps: u32,          // multiplier for any other severity
+    pub decay_half_life: u64,           // seconds for a penalty to halve (0 = no decay)
+    pub history_limit: u32,             // score snapshots kept per commitment
+    pub max_volatility_bps: u32,        // realized volatility above this is penalized (0 = off)
+    pub volatility_penalty: u32,        // points deducted while above max_volatility_bps
+    pub max_drawdown_bps: u32,          // drawdown from peak above this is penalized (0 = off)
+    pub drawdown_penalty: u32,          // points deducted while above max_drawdown_bps
+    pub overdue_penalty: u32,           // points deducted while a health_check is overdue
+    pub health_check_weight_bps: u32,   // scales health_check penalties and bonuses
+    pub fee_generation_weight_bps: u32, // scales fee_generation penalties and bonuses
+    pub drawdown_weight_bps: u32,       // scales drawdown penalties and bonuses
+}
+
+/// Required health-check cadence for one commitment
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthCheckSchedule {
+    pub interval: u64,     // seconds between required health_checks
+    pub next_due_at: u64,  // ledger timestamp the next health_check is due
+    pub last_check_at: u64, // 0 if never checked
+}
+
+/// Penalty that fades under `ScoringConfig::decay_half_life`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScorePenalty {
+    pub index: u32,     // attestation log index that caused it
+    pub timestamp: u64, // attestation timestamp
+    pub points: u32,    // undecayed points deducted
+}
+
+/// Point-in-time compliance score
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScoreSnapshot {
     pub timestamp: u64,
-    pub attestation_type: String, // "health_check", "violation", "fee_generation", "drawdown"
-    pub data: Map<String, String>, // Flexible data structure
-    pub is_compliant: bool,
-    pub verified_by: Address,
+    pub score: u32,
 }
 
-// Import Commitment types from commitment_core (define locally for cross-contract calls)
+/// Upper bound for `ScoringConfig::history_limit`
+pub const MAX_SCORE_HISTORY: u32 = 100;
 
+/// Upper bound for severity multipliers (5x)
+pub const MAX_SEVERITY_BPS: u32 = 50_000;
+
+/// Fading penalties tracked per commitment; older ones stop decaying
+pub const MAX_SCORE_PENALTIES: u32 = 50;
+
+/// Number of health_check value observations kept for volatility
+pub const VOLATILITY_WINDOW: u32 = 30;
+
+/// Attestations stored per persistent bucket
+pub const ATTESTATION_BUCKET_SIZE: u32 = 50;
+
+/// Commitments stored per monitored-index page
+pub const MONITORED_PAGE_SIZE: u32 = 50;
+
+/// Maximum page size for `get_attestations_page`
+pub const MAX_ATTESTATION_PAGE: u32 = 100;
+
+/// Extend attestation bucket TTL when it falls below this many ledgers (~7 days)
+pub const ATTESTATION_TTL_THRESHOLD: u32 = 120_960;
+
+/// Ledgers an attestation bucket lives after a write (~30 days)
+pub const ATTESTATION_TTL_EXTEND_TO: u32 = 518_400;
+
+/// Per-verifier performance analytics
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct HealthMetrics {
-    pub commitment_id: String,
-    pub current_value: i128,
-    pub initial_value: i128,
-    pub drawdown_percent: i128,
-    pub fees_generated: i128,
-    pub volatility_exposure: i128,
-    pub last_attestation: u64,
-    pub compliance_score: u32, // 0-100
+pub struct VerifierMetrics {
+    pub health_checks: u64,
+    pub violations: u64,
+    pub fee_generations: u64,
+    pub drawdowns: u64,
+    pub revoked: u64,
+    pub disputes_lost: u32,
+    pub disputes_won: u32,
+    pub total_latency: u64, // sum of seconds scheduled health_checks landed past due
+    pub latency_samples: u64, // health_checks recorded against a schedule
+    pub last_active: u64,
+}
+
+/// Reputation points lost per upheld dispute
+pub const REPUTATION_DISPUTE_PENALTY: u32 = 20;
+
+/// Reputation points lost per revoked attestation
+pub const REPUTATION_REVOCATION_PENALTY: u32 = 5;
+
+/// Average health-check lateness (seconds) that costs one reputation point, capped at 20 points
+pub const REPUTATION_LATENCY_UNIT: u64 = 3600;
+
+/// Aggregate of attestations removed by `archive_attestations`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationSummary {
+    pub archived_buckets: u32,  // buckets [0, archived_buckets) have been removed
+    pub archived_count: u32,    // log entries removed
+    pub compliant_count: u32,
+    pub violation_count: u32,   // violation-type or non-compliant
+    pub revoked_count: u32,
+    pub first_timestamp: u64,
+    pub last_timestamp: u64,
+}
+
+/// Action taken on commitment_core when an enforcement trigger fires
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnforcementAction {
+    None,
+    FlagForReview,
+    MarkViolated,
+}
+
+/// Which attestations trigger enforcement, and what they trigger
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnforcementPolicy {
+    pub on_violation: EnforcementAction,       // recorded "violation" attestation
+    pub on_drawdown_breach: EnforcementAction, // "drawdown" above the commitment's max_loss_percent
+    pub dispute_window: u64,             // seconds a trigger can be disputed before it is enforced
+}
+
+/// Enforcement triggered by an attestation, pending until its dispute window closes
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EnforcementRecord {
+    pub action: EnforcementAction,
+    pub trigger: String,
+    pub executable_at: u64,
+    pub applied: bool,
 }
 
 #[contract]
@@ -210,6 +497,10 @@ impl AttestationEngineContract {
 
     /// Add a verifier to the whitelist
     ///
+    /// When staking is configured (`min_stake > 0`), the verifier must also
+    /// authorize the call and bonds `min_stake` of the stake token (less any
+    /// bond it still holds from a previous registration).
+    ///
     /// # Arguments
     /// * `caller` - Must be admin
     /// * `verifier` - Address to add as authorized verifier
@@ -231,10 +522,54 @@ impl AttestationEngineContract {
             return Err(AttestationError::Unauthorized);
         }
 
+        // Bond the verifier's stake if staking is enabled
+        if let Some(config) = e
+            .storage()
+            .instance()
+            .get::<DataKey, StakingConfig>(&DataKey::StakingConfig)
+        {
+            if config.min_stake > 0 {
+                verifier.require_auth();
+
+                let stake_key = DataKey::VerifierStake(verifier.clone());
+                let mut stake: VerifierStake =
+                    e.storage()
+                        .persistent()
+                        .get(&stake_key)
+                        .unwrap_or(VerifierStake {
+                            amount: 0,
+                            bonded_at: 0,
+                            unbonding_until: 0,
+                            open_disputes: 0,
+                        });
+
+                let shortfall = config.min_stake.checked_sub(stake.amount).unwrap_or(0);
+                stake.amount = stake.amount.checked_add(shortfall.max(0)).unwrap_or(stake.amount);
+                stake.bonded_at = e.ledger().timestamp();
+                stake.unbonding_until = 0;
+                e.storage().persistent().set(&stake_key, &stake);
+
+                if shortfall > 0 {
+                    let token_client = token::Client::new(&e, &config.stake_token);
+                    token_client.transfer(&verifier, &e.current_contract_address(), &shortfall);
+                }
+
+                e.events().publish(
+                    (Symbol::new(&e, "StakeBonded"), verifier.clone()),
+                    (stake.amount, e.ledger().timestamp()),
+                );
+            }
+        }
+
         // Add verifier to whitelist
         e.storage()
             .instance()
             .set(&DataKey::Verifier(verifier.clone()), &true);
+        let mut verifiers = Self::verifier_list(&e);
+        if !verifiers.contains(&verifier) {
+            verifiers.push_back(verifier.clone());
+            e.storage().instance().set(&DataKey::VerifierList, &verifiers);
+        }
 
         // Emit event
         e.events()
@@ -270,6 +605,29 @@ impl AttestationEngineContract {
         e.storage()
             .instance()
             .remove(&DataKey::Verifier(verifier.clone()));
+        Self::remove_from_verifier_list(&e, &verifier);
+
+        // Start the unbonding delay; the bond stays slashable until it elapses
+        let stake_key = DataKey::VerifierStake(verifier.clone());
+        if let Some(mut stake) = e
+            .storage()
+            .persistent()
+            .get::<DataKey, VerifierStake>(&stake_key)
+        {
+            let unbonding_period = e
+                .storage()
+                .instance()
+                .get::<DataKey, StakingConfig>(&DataKey::StakingConfig)
+                .map(|config| config.unbonding_period)
+                .unwrap_or(0);
+            stake.unbonding_until = e.ledger().timestamp() + unbonding_period;
+            e.storage().persistent().set(&stake_key, &stake);
+
+            e.events().publish(
+                (Symbol::new(&e, "StakeUnbonding"), verifier.clone()),
+                (stake.amount, stake.unbonding_until),
+            );
+        }
 
         // Emit event
         e.events()
@@ -278,1316 +636,3365 @@ impl AttestationEngineContract {
         Ok(())
     }
 
-    /// Check if an address is an authorized verifier
-    fn is_authorized_verifier(e: &Env, address: &Address) -> bool {
-        // Admin is always authorized
-        if let Some(admin) = e
-            .storage()
-            .instance()
-            .get::<DataKey, Address>(&DataKey::Admin)
-        {
-            if *address == admin {
-                return true;
-            }
-        }
+    // ========================================================================
+    // Verifier Staking
+    // ========================================================================
 
-        // Check verifier whitelist
-        e.storage()
-            .instance()
-            .get(&DataKey::Verifier(address.clone()))
-            .unwrap_or(false)
+    /// Configure verifier staking and dispute parameters. Admin only.
+    ///
+    /// # Arguments
+    /// * `caller` - Must be admin
+    /// * `config` - Stake token, minimum bond, unbonding delay, slash share and dispute quorum
+    pub fn set_staking_config(
+        e: Env,
+        caller: Address,
+        config: StakingConfig,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if config.min_stake < 0 {
+            return Err(AttestationError::InvalidStakeAmount);
+        }
+        if config.slash_bps > BPS_MAX {
+            return Err(AttestationError::InvalidBps);
+        }
+        e.storage().instance().set(&DataKey::StakingConfig, &config);
+        e.events().publish(
+            (Symbol::new(&e, "StakingConfigSet"), caller),
+            (config.min_stake, config.slash_bps, config.dispute_quorum),
+        );
+        Ok(())
     }
 
-    /// Check if an address is a verifier (public version)
-    pub fn is_verifier(e: Env, address: Address) -> bool {
-        Self::is_authorized_verifier(&e, &address)
+    /// Get the staking configuration. None if staking was never configured.
+    pub fn get_staking_config(e: Env) -> Option<StakingConfig> {
+        e.storage().instance().get(&DataKey::StakingConfig)
     }
 
-    /// Get the admin address
-    pub fn get_admin(e: Env) -> Result<Address, AttestationError> {
+    /// Get a verifier's bonded stake. None if the verifier never bonded.
+    pub fn get_verifier_stake(e: Env, verifier: Address) -> Option<VerifierStake> {
         e.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(AttestationError::NotInitialized)
+            .persistent()
+            .get(&DataKey::VerifierStake(verifier))
     }
 
-    /// Get the core contract address
-    pub fn get_core_contract(e: Env) -> Result<Address, AttestationError> {
-        e.storage()
+    /// Add to an existing bond, e.g. to restore the minimum after a slash.
+    ///
+    /// # Arguments
+    /// * `verifier` - The bonded verifier (must sign)
+    /// * `amount` - Amount of stake token to add
+    pub fn add_stake(e: Env, verifier: Address, amount: i128) -> Result<(), AttestationError> {
+        verifier.require_auth();
+        if amount <= 0 {
+            return Err(AttestationError::InvalidStakeAmount);
+        }
+        let config: StakingConfig = e
+            .storage()
             .instance()
-            .get(&DataKey::CoreContract)
-            .ok_or(AttestationError::NotInitialized)
-    }
+            .get(&DataKey::StakingConfig)
+            .ok_or(AttestationError::StakingNotConfigured)?;
 
-    /// Get current on-chain version (0 if legacy/uninitialized).
-    pub fn get_version(e: Env) -> u32 {
-        read_version(&e)
-    }
+        let stake_key = DataKey::VerifierStake(verifier.clone());
+        let mut stake: VerifierStake = e
+            .storage()
+            .persistent()
+            .get(&stake_key)
+            .ok_or(AttestationError::StakeNotFound)?;
+        stake.amount = stake
+            .amount
+            .checked_add(amount)
+            .ok_or(AttestationError::InvalidStakeAmount)?;
+        e.storage().persistent().set(&stake_key, &stake);
 
-    /// Update admin (admin-only).
-    pub fn set_admin(
-        e: Env,
-        caller: Address,
-        new_admin: Address,
-    ) -> Result<(), AttestationError> {
-        require_admin(&e, &caller)?;
-        e.storage().instance().set(&DataKey::Admin, &new_admin);
-        Ok(())
-    }
+        let token_client = token::Client::new(&e, &config.stake_token);
+        token_client.transfer(&verifier, &e.current_contract_address(), &amount);
 
-    /// Upgrade contract WASM (admin-only).
-    pub fn upgrade(
-        e: Env,
-        caller: Address,
-        new_wasm_hash: BytesN<32>,
-    ) -> Result<(), AttestationError> {
-        require_admin(&e, &caller)?;
-        require_valid_wasm_hash(&e, &new_wasm_hash)?;
-        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        e.events().publish(
+            (Symbol::new(&e, "StakeBonded"), verifier),
+            (stake.amount, e.ledger().timestamp()),
+        );
         Ok(())
     }
 
-    /// Migrate storage from a previous version to CURRENT_VERSION (admin-only).
-    pub fn migrate(
-        e: Env,
-        caller: Address,
-        from_version: u32,
-    ) -> Result<(), AttestationError> {
-        require_admin(&e, &caller)?;
+    /// Withdraw a verifier's remaining bond once the unbonding delay has elapsed.
+    ///
+    /// The verifier must have been removed (which starts unbonding) and must have
+    /// no open disputes against it.
+    pub fn withdraw_stake(e: Env, verifier: Address) -> Result<i128, AttestationError> {
+        verifier.require_auth();
+        let config: StakingConfig = e
+            .storage()
+            .instance()
+            .get(&DataKey::StakingConfig)
+            .ok_or(AttestationError::StakingNotConfigured)?;
 
-        let stored_version = read_version(&e);
-        if stored_version == CURRENT_VERSION {
-            return Err(AttestationError::AlreadyMigrated);
+        let stake_key = DataKey::VerifierStake(verifier.clone());
+        let stake: VerifierStake = e
+            .storage()
+            .persistent()
+            .get(&stake_key)
+            .ok_or(AttestationError::StakeNotFound)?;
+
+        if stake.unbonding_until == 0
+            || e.ledger().timestamp() < stake.unbonding_until
+            || stake.open_disputes > 0
+        {
+            return Err(AttestationError::StakeLocked);
         }
-        if from_version != stored_version || from_version > CURRENT_VERSION {
-            return Err(AttestationError::InvalidVersion);
+
+        // EFFECTS before INTERACTIONS
+        e.storage().persistent().remove(&stake_key);
+
+        if stake.amount > 0 {
+            let token_client = token::Client::new(&e, &config.stake_token);
+            token_client.transfer(&e.current_contract_address(), &verifier, &stake.amount);
         }
 
-        // Ensure analytics counters are initialized
-        if !e.storage().instance().has(&DataKey::TotalAttestations) {
-            e.storage()
-                .instance()
-                .set(&DataKey::TotalAttestations, &0u64);
+        e.events().publish(
+            (Symbol::new(&e, "StakeWithdrawn"), verifier),
+            (stake.amount, e.ledger().timestamp()),
+        );
+        Ok(stake.amount)
+    }
+
+    // ========================================================================
+    // Disputes
+    // ========================================================================
+
+    /// Challenge a recorded attestation.
+    ///
+    /// # Arguments
+    /// * `challenger` - Commitment owner or admin
+    /// * `commitment_id` - The commitment the attestation belongs to
+    /// * `attestation_index` - Index into `get_attestations(commitment_id)`
+    /// * `reason` - Free-form justification
+    ///
+    /// # Returns
+    /// The new dispute ID
+    pub fn dispute_attestation(
+        e: Env,
+        challenger: Address,
+        commitment_id: String,
+        attestation_index: u32,
+        reason: String,
+    ) -> Result<u64, AttestationError> {
+        challenger.require_auth();
+
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AttestationError::NotInitialized)?;
+        if challenger != admin {
+            let commitment = Self::fetch_commitment(&e, &commitment_id)
+                .ok_or(AttestationError::CommitmentNotFound)?;
+            if commitment.owner != challenger {
+                return Err(AttestationError::Unauthorized);
+            }
         }
-        if !e.storage().instance().has(&DataKey::TotalViolations) {
-            e.storage()
-                .instance()
-                .set(&DataKey::TotalViolations, &0u64);
+
+        let attestation = Self::read_attestation(&e, &commitment_id, attestation_index)
+            .ok_or(AttestationError::AttestationNotFound)?;
+        if attestation.revoked {
+            return Err(AttestationError::AttestationRevoked);
         }
-        if !e.storage().instance().has(&DataKey::TotalFees) {
-            e.storage().instance().set(&DataKey::TotalFees, &0i128);
+        if attestation.attestation_type == String::from_str(&e, "enforcement") {
+            return Err(AttestationError::EnforcementRecord);
         }
-        if !e.storage().instance().has(&DataKey::ReentrancyGuard) {
-            e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
+
+        let open_key = DataKey::AttestationDispute(commitment_id.clone(), attestation_index);
+        if e.storage().persistent().has(&open_key) {
+            return Err(AttestationError::DisputeAlreadyOpen);
         }
 
+        let dispute_id: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::DisputeCounter)
+            .unwrap_or(0);
         e.storage()
             .instance()
-            .set(&DataKey::Version, &CURRENT_VERSION);
-        Ok(())
-    }
+            .set(&DataKey::DisputeCounter, &(dispute_id + 1));
 
-    /// Get stored health metrics for a commitment (without recalculation)
-    pub fn get_stored_health_metrics(e: Env, commitment_id: String) -> Option<HealthMetrics> {
-        let key = DataKey::HealthMetrics(commitment_id);
-        e.storage().persistent().get(&key)
-    }
+        let dispute = Dispute {
+            dispute_id,
+            commitment_id: commitment_id.clone(),
+            attestation_index,
+            verifier: attestation.verified_by.clone(),
+            challenger: challenger.clone(),
+            reason,
+            status: DisputeStatus::Open,
+            votes_upheld: 0,
+            votes_rejected: 0,
+            opened_at: e.ledger().timestamp(),
+            resolved_at: 0,
+            slashed_amount: 0,
+        };
+        e.storage()
+            .persistent()
+            .set(&DataKey::Dispute(dispute_id), &dispute);
+        e.storage().persistent().set(&open_key, &dispute_id);
+        Self::adjust_open_disputes(&e, &commitment_id, true);
 
-    // ========================================================================
-    // Validation Helpers
-    // ========================================================================
-
-    /// Validate attestation type is one of the allowed types
-    fn is_valid_attestation_type(e: &Env, att_type: &String) -> bool {
-        let health_check = String::from_str(e, "health_check");
-        let violation = String::from_str(e, "violation");
-        let fee_generation = String::from_str(e, "fee_generation");
-        let drawdown = String::from_str(e, "drawdown");
-
-        *att_type == health_check
-            || *att_type == violation
-            || *att_type == fee_generation
-            || *att_type == drawdown
-    }
-
-    /// Validate attestation data based on type
-    fn validate_attestation_data(e: &Env, att_type: &String, data: &Map<String, String>) -> bool {
-        let health_check = String::from_str(e, "health_check");
-        let violation = String::from_str(e, "violation");
-        let fee_generation = String::from_str(e, "fee_generation");
-        let drawdown = String::from_str(e, "drawdown");
-
-        if *att_type == health_check {
-            // health_check: optional fields, always valid
-            true
-        } else if *att_type == violation {
-            // violation: requires "violation_type" and "severity"
-            let violation_type_key = String::from_str(e, "violation_type");
-            let severity_key = String::from_str(e, "severity");
-            data.contains_key(violation_type_key) && data.contains_key(severity_key)
-        } else if *att_type == fee_generation {
-            // fee_generation: requires "fee_amount"
-            let fee_amount_key = String::from_str(e, "fee_amount");
-            data.contains_key(fee_amount_key)
-        } else if *att_type == drawdown {
-            // drawdown: requires "drawdown_percent"
-            let drawdown_percent_key = String::from_str(e, "drawdown_percent");
-            data.contains_key(drawdown_percent_key)
-        } else {
-            false
+        // Lock the verifier's bond while the dispute is open
+        let stake_key = DataKey::VerifierStake(attestation.verified_by.clone());
+        if let Some(mut stake) = e
+            .storage()
+            .persistent()
+            .get::<DataKey, VerifierStake>(&stake_key)
+        {
+            stake.open_disputes += 1;
+            e.storage().persistent().set(&stake_key, &stake);
         }
-    }
-
-    /// Check if commitment exists in core contract
-    fn commitment_exists(e: &Env, commitment_id: &String) -> bool {
-        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
-            Some(addr) => addr,
-            None => return false,
-        };
-
-        // Try to get commitment from core contract
-        let mut args = Vec::new(e);
-        args.push_back(commitment_id.clone().into_val(e));
 
-        // Use try_invoke_contract to handle potential failures
-        let result = e.try_invoke_contract::<Val, soroban_sdk::Error>(
-            &commitment_core,
-            &Symbol::new(e, "get_commitment"),
-            args,
+        e.events().publish(
+            (
+                Symbol::new(&e, "AttestationDisputed"),
+                commitment_id,
+                attestation.verified_by,
+            ),
+            (dispute_id, attestation_index, challenger),
         );
 
-        match result {
-            Ok(Ok(_)) => true,
-            _ => false,
-        }
+        Ok(dispute_id)
     }
 
-    // ========================================================================
-    // Health Metrics Update
-    // ========================================================================
-
-    /// Update health metrics after an attestation
-    fn update_health_metrics(e: &Env, commitment_id: &String, attestation: &Attestation) {
-        // Get or create health metrics
-        let key = DataKey::HealthMetrics(commitment_id.clone());
-        let mut metrics: HealthMetrics =
-            e.storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or_else(|| HealthMetrics {
-                    commitment_id: commitment_id.clone(),
-                    current_value: 0,
-                    initial_value: 0,
-                    drawdown_percent: 0,
-                    fees_generated: 0,
-                    volatility_exposure: 0,
-                    last_attestation: 0,
-                    compliance_score: 100,
-                });
-
-        // Update last_attestation timestamp
-        metrics.last_attestation = attestation.timestamp;
-
-        // Update type-specific metrics
-        let fee_generation = String::from_str(e, "fee_generation");
-        let drawdown_type = String::from_str(e, "drawdown");
-        let violation = String::from_str(e, "violation");
-
-        if attestation.attestation_type == fee_generation {
-            // Add to fees_generated
-            let fee_amount_key = String::from_str(e, "fee_amount");
-            if let Some(fee_str) = attestation.data.get(fee_amount_key) {
-                // Parse fee amount from string
-                if let Some(fee_amount) = Self::parse_i128_from_string(e, &fee_str) {
-                    metrics.fees_generated = metrics
-                        .fees_generated
-                        .checked_add(fee_amount)
-                        .unwrap_or(metrics.fees_generated);
-
-                    // Update global total fees analytics
-                    let total_fees: i128 =
-                        e.storage().instance().get(&DataKey::TotalFees).unwrap_or(0);
-                    let new_total = total_fees.checked_add(fee_amount).unwrap_or(total_fees);
-                    e.storage().instance().set(&DataKey::TotalFees, &new_total);
-                }
-            }
-        } else if attestation.attestation_type == drawdown_type {
-            // Update drawdown_percent
-            let drawdown_percent_key = String::from_str(e, "drawdown_percent");
-            if let Some(drawdown_str) = attestation.data.get(drawdown_percent_key) {
-                if let Some(drawdown_val) = Self::parse_i128_from_string(e, &drawdown_str) {
-                    metrics.drawdown_percent = drawdown_val;
-                }
-            }
-        } else if attestation.attestation_type == violation {
-            // Decrease compliance score for violations
-            let severity_key = String::from_str(e, "severity");
-            let penalty = if let Some(severity) = attestation.data.get(severity_key) {
-                let high = String::from_str(e, "high");
-                let medium = String::from_str(e, "medium");
-                if severity == high {
-                    30u32
-                } else if severity == medium {
-                    20u32
-                } else {
-                    10u32
-                }
-            } else {
-                20u32 // Default penalty
-            };
+    /// Cast a quorum vote on an open dispute.
+    ///
+    /// Any whitelisted verifier other than the accused one may vote once. The
+    /// dispute is resolved as soon as either side reaches `dispute_quorum` votes.
+    pub fn vote_on_dispute(
+        e: Env,
+        voter: Address,
+        dispute_id: u64,
+        uphold: bool,
+    ) -> Result<DisputeStatus, AttestationError> {
+        voter.require_auth();
 
-            metrics.compliance_score = metrics.compliance_score.saturating_sub(penalty);
+        let is_whitelisted: bool = e
+            .storage()
+            .instance()
+            .get(&DataKey::Verifier(voter.clone()))
+            .unwrap_or(false);
+        if !is_whitelisted {
+            return Err(AttestationError::Unauthorized);
         }
 
-        // Compliance bonus for compliant attestations
-        if attestation.is_compliant && attestation.attestation_type != violation {
-            // Small bonus for compliant attestations, capped at 100
-            metrics.compliance_score =
-                core::cmp::min(100, metrics.compliance_score.saturating_add(1));
+        let quorum = e
+            .storage()
+            .instance()
+            .get::<DataKey, StakingConfig>(&DataKey::StakingConfig)
+            .map(|config| config.dispute_quorum)
+            .unwrap_or(0);
+        if quorum == 0 {
+            return Err(AttestationError::Unauthorized);
         }
 
-        // Store updated metrics
-        e.storage().persistent().set(&key, &metrics);
-    }
+        let mut dispute: Dispute = e
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(AttestationError::DisputeNotFound)?;
+        if dispute.status != DisputeStatus::Open {
+            return Err(AttestationError::DisputeAlreadyResolved);
+        }
+        if dispute.verifier == voter {
+            return Err(AttestationError::Unauthorized);
+        }
 
-    /// Parse i128 from String (optimized implementation)
-    fn parse_i128_from_string(_e: &Env, s: &String) -> Option<i128> {
-        let len = s.len();
-        if len == 0 || len > 64 {
-            return None; // Early return for invalid lengths
+        let vote_key = DataKey::DisputeVote(dispute_id, voter.clone());
+        if e.storage().persistent().has(&vote_key) {
+            return Err(AttestationError::AlreadyVoted);
         }
+        e.storage().persistent().set(&vote_key, &uphold);
 
-        // Copy string to buffer
-        let mut buf = [0u8; 64];
-        s.copy_into_slice(&mut buf[..len as usize]);
+        if uphold {
+            dispute.votes_upheld += 1;
+        } else {
+            dispute.votes_rejected += 1;
+        }
 
-        let mut result: i128 = 0;
-        let mut start_idx = 0;
-        let is_negative = buf[0] == b'-';
+        e.events().publish(
+            (Symbol::new(&e, "DisputeVoteCast"), dispute_id, voter),
+            (uphold, dispute.votes_upheld, dispute.votes_rejected),
+        );
 
-        if is_negative {
-            start_idx = 1;
-            if len == 1 {
-                return None; // Just a minus sign
-            }
+        if dispute.votes_upheld >= quorum {
+            Self::finalize_dispute(&e, dispute, true)
+        } else if dispute.votes_rejected >= quorum {
+            Self::finalize_dispute(&e, dispute, false)
+        } else {
+            e.storage()
+                .persistent()
+                .set(&DataKey::Dispute(dispute_id), &dispute);
+            Ok(DisputeStatus::Open)
         }
+    }
 
-        // OPTIMIZATION: Single pass parsing with early exit on invalid char
-        for i in start_idx..len as usize {
-            let b = buf[i];
-            if b < b'0' || b > b'9' {
-                return None; // Invalid character - early exit
-            }
-            result = result.checked_mul(10)?;
-            result = result.checked_add((b - b'0') as i128)?;
-        }
+    /// Resolve an open dispute directly. Admin only.
+    ///
+    /// # Arguments
+    /// * `caller` - Must be admin
+    /// * `dispute_id` - The dispute to resolve
+    /// * `uphold` - `true` if the attestation was wrong (verifier is slashed)
+    pub fn resolve_dispute(
+        e: Env,
+        caller: Address,
+        dispute_id: u64,
+        uphold: bool,
+    ) -> Result<DisputeStatus, AttestationError> {
+        require_admin(&e, &caller)?;
 
-        if is_negative {
-            result = result.checked_neg()?;
+        let dispute: Dispute = e
+            .storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(AttestationError::DisputeNotFound)?;
+        if dispute.status != DisputeStatus::Open {
+            return Err(AttestationError::DisputeAlreadyResolved);
         }
 
-        Some(result)
+        Self::finalize_dispute(&e, dispute, uphold)
     }
 
-    // ========================================================================
-    // Access Control
-    // ========================================================================
+    /// Get a dispute by ID
+    pub fn get_dispute(e: Env, dispute_id: u64) -> Result<Dispute, AttestationError> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Dispute(dispute_id))
+            .ok_or(AttestationError::DisputeNotFound)
+    }
 
-    /// Record an attestation for a commitment
-    ///
-    /// # Arguments
-    /// * `caller` - The address recording the attestation (must be authorized verifier)
-    /// * `commitment_id` - The commitment being attested
-    /// * `attestation_type` - Type: "health_check", "violation", "fee_generation", "drawdown"
-    /// * `data` - Type-specific data map
-    /// * `is_compliant` - Whether the commitment is compliant
+    /// Revoke an attestation. Admin only.
     ///
-    /// # Returns
-    /// * `Ok(())` on success
-    /// * `Err(AttestationError::*)` on various validation failures
+    /// The record stays in `get_attestations` with `revoked = true`, but no
+    /// longer counts towards health metrics, compliance score or analytics.
     ///
-    /// # Reentrancy Protection
-    /// Uses checks-effects-interactions pattern with an explicit guard.
-    pub fn attest(
+    /// # Arguments
+    /// * `caller` - Must be admin
+    /// * `commitment_id` - The commitment the attestation belongs to
+    /// * `attestation_index` - Index into `get_attestations(commitment_id)`
+    /// * `reason` - Recorded in the audit event
+    pub fn revoke_attestation(
         e: Env,
         caller: Address,
         commitment_id: String,
-        attestation_type: String,
-        data: Map<String, String>,
-        is_compliant: bool,
+        attestation_index: u32,
+        reason: String,
     ) -> Result<(), AttestationError> {
-        // 1. Reentrancy protection
-        if e.storage().instance().has(&DataKey::ReentrancyGuard) {
-            panic!("Reentrancy detected");
-        }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
-
-        // 2. Verify caller signed the transaction
-        caller.require_auth();
+        require_admin(&e, &caller)?;
+        Self::revoke_attestation_internal(&e, &commitment_id, attestation_index, &caller, reason)
+    }
 
-        // 3. Check caller is authorized verifier
-        if !Self::is_authorized_verifier(&e, &caller) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::Unauthorized);
+    /// Mark an attestation revoked and reverse its effect on metrics and analytics
+    fn revoke_attestation_internal(
+        e: &Env,
+        commitment_id: &String,
+        attestation_index: u32,
+        revoked_by: &Address,
+        reason: String,
+    ) -> Result<(), AttestationError> {
+        let mut attestation = Self::read_attestation(e, commitment_id, attestation_index)
+            .ok_or(AttestationError::AttestationNotFound)?;
+        if attestation.revoked {
+            return Err(AttestationError::AttestationRevoked);
+        }
+        if attestation.attestation_type == String::from_str(e, "enforcement") {
+            return Err(AttestationError::EnforcementRecord);
         }
 
-        // 3b. Rate limit attestations per verifier
-        let fn_symbol = Symbol::new(&e, "attest");
-        RateLimiter::check(&e, &caller, &fn_symbol);
+        attestation.revoked = true;
+        Self::write_attestation(e, commitment_id, attestation_index, &attestation);
 
-        // 4. Validate commitment_id is not empty
-        if commitment_id.len() == 0 {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::InvalidCommitmentId);
-        }
+        let mut verifier_metrics =
+            Self::get_verifier_metrics(e.clone(), attestation.verified_by.clone());
+        verifier_metrics.revoked += 1;
+        e.storage().persistent().set(
+            &DataKey::VerifierMetrics(attestation.verified_by.clone()),
+            &verifier_metrics,
+        );
 
-        // 5. Validate commitment exists in core contract
-        if !Self::commitment_exists(&e, &commitment_id) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::CommitmentNotFound);
-        }
+        // Reverse analytics counters
+        let total_attestations: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalAttestations)
+            .unwrap_or(0);
+        e.storage().instance().set(
+            &DataKey::TotalAttestations,
+            &total_attestations.saturating_sub(1),
+        );
 
-        // 6. Validate attestation type
-        if !Self::is_valid_attestation_type(&e, &attestation_type) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::InvalidAttestationType);
+        let violation_type = String::from_str(e, "violation");
+        if attestation.attestation_type == violation_type || !attestation.is_compliant {
+            let total_violations: u64 = e
+                .storage()
+                .instance()
+                .get(&DataKey::TotalViolations)
+                .unwrap_or(0);
+            e.storage()
+                .instance()
+                .set(&DataKey::TotalViolations, &total_violations.saturating_sub(1));
         }
 
-        // 7. Validate data format for the attestation type
-        if !Self::validate_attestation_data(&e, &attestation_type, &data) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            return Err(AttestationError::InvalidAttestationData);
-        }
+        let verifier_key = DataKey::VerifierAttestationCount(attestation.verified_by.clone());
+        let verifier_count: u64 = e.storage().instance().get(&verifier_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&verifier_key, &verifier_count.saturating_sub(1));
 
-        // 7b. Collect attestation verification fee if configured
-        let fee_amount: i128 = e.storage().instance().get(&DataKey::AttestationFeeAmount).unwrap_or(0);
-        if fee_amount > 0 {
-            if let Some(fee_asset) = e.storage().instance().get::<DataKey, Address>(&DataKey::AttestationFeeAsset) {
-                let contract_address = e.current_contract_address();
-                let token_client = token::Client::new(&e, &fee_asset);
-                token_client.transfer(&caller, &contract_address, &fee_amount);
-                let key = DataKey::CollectedFees(fee_asset.clone());
-                let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
-                e.storage().instance().set(&key, &(current + fee_amount));
+        if attestation.attestation_type == String::from_str(e, "fee_generation") {
+            let fee_amount_key = String::from_str(e, "fee_amount");
+            if let Some(fee_amount) = attestation
+                .data
+                .get(fee_amount_key)
+                .and_then(|fee_str| Self::parse_i128_from_string(e, &fee_str))
+            {
+                let total_fees: i128 =
+                    e.storage().instance().get(&DataKey::TotalFees).unwrap_or(0);
+                let new_total = total_fees.checked_sub(fee_amount).unwrap_or(total_fees);
+                e.storage().instance().set(&DataKey::TotalFees, &new_total);
             }
         }
 
-        // 8. Create attestation record
-        let timestamp = e.ledger().timestamp();
-        let attestation = Attestation {
-            commitment_id: commitment_id_clone,
-            attestation_type: attestation_type_clone,
-            data: data_clone,
-            timestamp: e.ledger().timestamp(),
-            verified_by: verified_by_clone,
-            is_compliant: true, // Default to true, can be updated by logic
-        };
+        // Recompute metrics without the revoked record
+        Self::rebuild_health_metrics(e, commitment_id);
+        Self::reverse_enforcement(e, commitment_id, attestation_index);
 
-        // 9. Store attestation in commitment's list
-        let key = DataKey::Attestations(commitment_id.clone());
-        let mut attestations: Vec<Attestation> = e
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| Vec::new(&e));
-
-        // Add new attestation
-        attestations.push_back(attestation);
-
-        // Store updated list
-        e.storage().persistent().set(&key, &attestations);
-
-        // 10. Update health metrics
-        Self::update_health_metrics(&e, &commitment_id, &attestation);
+        e.events().publish(
+            (
+                Symbol::new(e, "AttestationRevoked"),
+                commitment_id.clone(),
+                attestation.verified_by,
+            ),
+            (
+                attestation_index,
+                revoked_by.clone(),
+                reason,
+                e.ledger().timestamp(),
+            ),
+        );
 
-        // 11. Increment attestation counter
-        let counter_key = DataKey::AttestationCounter(commitment_id.clone());
-        let counter: u64 = e.storage().persistent().get(&counter_key).unwrap_or(0);
-        e.storage().persistent().set(&counter_key, &(counter + 1));
+        Ok(())
+    }
 
-        // 11b. OPTIMIZATION: Batch update all analytics counters
-        let (total_attestations, total_violations, verifier_count) = {
-            let total_att = e
-                .storage()
-                .instance()
-                .get(&DataKey::TotalAttestations)
-                .unwrap_or(0u64);
-            let total_viol = e
-                .storage()
-                .instance()
-                .get(&DataKey::TotalViolations)
-                .unwrap_or(0u64);
-            let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
-            let ver_count = e.storage().instance().get(&verifier_key).unwrap_or(0u64);
-            (total_att, total_viol, ver_count)
+    /// Close a dispute and slash the verifier's bond if it was upheld.
+    ///
+    /// Slashed funds accrue to `CollectedFees(stake_token)` and are paid out
+    /// through `withdraw_fees`. A verifier whose bond drops below `min_stake`
+    /// is removed from the whitelist.
+    fn finalize_dispute(
+        e: &Env,
+        mut dispute: Dispute,
+        uphold: bool,
+    ) -> Result<DisputeStatus, AttestationError> {
+        dispute.status = if uphold {
+            DisputeStatus::Upheld
+        } else {
+            DisputeStatus::Rejected
         };
+        dispute.resolved_at = e.ledger().timestamp();
 
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalAttestations, &(total_attestations + 1));
+        let stake_key = DataKey::VerifierStake(dispute.verifier.clone());
+        if let Some(mut stake) = e
+            .storage()
+            .persistent()
+            .get::<DataKey, VerifierStake>(&stake_key)
+        {
+            stake.open_disputes = stake.open_disputes.saturating_sub(1);
+
+            if uphold {
+                if let Some(config) = e
+                    .storage()
+                    .instance()
+                    .get::<DataKey, StakingConfig>(&DataKey::StakingConfig)
+                {
+                    let slashed = fee_from_bps(stake.amount, config.slash_bps);
+                    if slashed > 0 {
+                        stake.amount -= slashed;
+                        dispute.slashed_amount = slashed;
+
+                        let fees_key = DataKey::CollectedFees(config.stake_token.clone());
+                        let collected: i128 = e.storage().instance().get(&fees_key).unwrap_or(0);
+                        e.storage().instance().set(&fees_key, &(collected + slashed));
+
+                        e.events().publish(
+                            (Symbol::new(e, "VerifierSlashed"), dispute.verifier.clone()),
+                            (dispute.dispute_id, slashed, stake.amount),
+                        );
+                    }
+
+                    if stake.amount < config.min_stake {
+                        e.storage()
+                            .instance()
+                            .remove(&DataKey::Verifier(dispute.verifier.clone()));
+                        Self::remove_from_verifier_list(e, &dispute.verifier);
+                        e.events().publish(
+                            (Symbol::new(e, "VerifierRemoved"),),
+                            (dispute.verifier.clone(),),
+                        );
+                    }
+                }
+            }
 
-        // Track violations (explicit or non-compliant)
-        let violation_type = String::from_str(&e, "violation");
-        if attestation.attestation_type == violation_type || !attestation.is_compliant {
-            e.storage()
-                .instance()
-                .set(&DataKey::TotalViolations, &(total_violations + 1));
+            e.storage().persistent().set(&stake_key, &stake);
+        }
+
+        let metrics_key = DataKey::VerifierMetrics(dispute.verifier.clone());
+        let mut verifier_metrics = Self::get_verifier_metrics(e.clone(), dispute.verifier.clone());
+        if uphold {
+            verifier_metrics.disputes_lost += 1;
+        } else {
+            verifier_metrics.disputes_won += 1;
+        }
+        e.storage().persistent().set(&metrics_key, &verifier_metrics);
+
+        if uphold {
+            // An upheld dispute means the attestation was wrong; drop it from scoring
+            match Self::revoke_attestation_internal(
+                e,
+                &dispute.commitment_id,
+                dispute.attestation_index,
+                &dispute.challenger,
+                dispute.reason.clone(),
+            ) {
+                Ok(()) | Err(AttestationError::AttestationRevoked) => {}
+                Err(err) => return Err(err),
+            }
         }
 
-        // Track per-verifier attestation count
-        let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
         e.storage()
-            .instance()
-            .set(&verifier_key, &(verifier_count + 1));
+            .persistent()
+            .set(&DataKey::Dispute(dispute.dispute_id), &dispute);
+        e.storage().persistent().remove(&DataKey::AttestationDispute(
+            dispute.commitment_id.clone(),
+            dispute.attestation_index,
+        ));
+        Self::adjust_open_disputes(e, &dispute.commitment_id, false);
 
-        // 12. Emit enhanced AttestationRecorded event
         e.events().publish(
             (
-                Symbol::new(&e, "AttestationRecorded"),
-                commitment_id,
-                caller,
+                Symbol::new(e, "DisputeResolved"),
+                dispute.commitment_id.clone(),
+                dispute.dispute_id,
             ),
-            (attestation_type, is_compliant, timestamp),
+            (uphold, dispute.slashed_amount, dispute.resolved_at),
         );
 
-        // 13. Clear reentrancy guard
-        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+        Ok(dispute.status)
+    }
 
-        Ok(())
+    /// Track the number of open disputes against a commitment's attestations
+    fn adjust_open_disputes(e: &Env, commitment_id: &String, opened: bool) {
+        let key = DataKey::OpenDisputes(commitment_id.clone());
+        let open: u32 = e.storage().persistent().get(&key).unwrap_or(0);
+        let open = if opened {
+            open + 1
+        } else {
+            open.saturating_sub(1)
+        };
+        if open == 0 {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, &open);
+        }
     }
 
-    /// Get all attestations for a commitment
-    pub fn get_attestations(e: Env, commitment_id: String) -> Vec<Attestation> {
-        // Retrieve attestations from persistent storage using commitment_id as key
-        let key = DataKey::Attestations(commitment_id);
+    /// Check if an address is an authorized verifier
+    fn is_authorized_verifier(e: &Env, address: &Address) -> bool {
+        // Admin is always authorized
+        if let Some(admin) = e
+            .storage()
+            .instance()
+            .get::<DataKey, Address>(&DataKey::Admin)
+        {
+            if *address == admin {
+                return true;
+            }
+        }
+
+        // Check verifier whitelist
         e.storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| Vec::new(&e))
+            .instance()
+            .get(&DataKey::Verifier(address.clone()))
+            .unwrap_or(false)
     }
 
-    /// Get attestation count for a commitment
-    pub fn get_attestation_count(e: Env, commitment_id: String) -> u64 {
-        let key = DataKey::AttestationCounter(commitment_id);
-        e.storage().persistent().get(&key).unwrap_or(0)
+    /// Check if an address is a verifier (public version)
+    pub fn is_verifier(e: Env, address: Address) -> bool {
+        Self::is_authorized_verifier(&e, &address)
     }
 
-    /// Get current health metrics for a commitment
-    pub fn get_health_metrics(e: Env, commitment_id: String) -> HealthMetrics {
-        let core = Self::get_commitment_core(&e);
-        let commitment = Self::core_get_commitment(&e, &core, &commitment_id);
-
-        let initial_value = commitment.amount;
-        let current_value = commitment.current_value;
-        let drawdown_percent = Self::calc_drawdown_percent(initial_value, current_value);
-
-        let state = Self::get_health_state_or_default(&e, &commitment_id);
-
-        // Get commitment from core contract
-        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
+    /// Get the admin address
+    pub fn get_admin(e: Env) -> Result<Address, AttestationError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AttestationError::NotInitialized)
+    }
 
-        // Call get_commitment on commitment_core contract
-        // Using Symbol::new() for function name longer than 9 characters
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val =
-            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+    /// Get the core contract address
+    pub fn get_core_contract(e: Env) -> Result<Address, AttestationError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .ok_or(AttestationError::NotInitialized)
+    }
 
-        // Convert Val to Commitment
-        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+    /// Get current on-chain version (0 if legacy/uninitialized).
+    pub fn get_version(e: Env) -> u32 {
+        read_version(&e)
+    }
 
-        // Get all attestations
-        let attestations = Self::get_attestations(e.clone(), commitment_id.clone());
+    /// Update admin (admin-only).
+    pub fn set_admin(
+        e: Env,
+        caller: Address,
+        new_admin: Address,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        e.storage().instance().set(&DataKey::Admin, &new_admin);
+        Ok(())
+    }
 
-        // Extract values from commitment
-        let initial_value = commitment.amount; // Using amount as initial value
-        let current_value = commitment.current_value;
+    /// Upgrade contract WASM (admin-only).
+    pub fn upgrade(
+        e: Env,
+        caller: Address,
+        new_wasm_hash: BytesN<32>,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        require_valid_wasm_hash(&e, &new_wasm_hash)?;
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+        Ok(())
+    }
 
-        // Calculate drawdown percentage: ((initial - current) / initial) * 100
-        // Handle zero initial value to prevent division by zero
-        let drawdown_percent = if initial_value > 0 {
-            let diff = initial_value.checked_sub(current_value).unwrap_or(0);
-            diff.checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(initial_value)
-                .unwrap_or(0)
-        } else {
-            0
-        };
+    /// Migrate storage from a previous version to CURRENT_VERSION (admin-only).
+    pub fn migrate(
+        e: Env,
+        caller: Address,
+        from_version: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
 
-        // Sum fees from fee attestations
-        // Extract fee_amount from data map where key is "fee_amount"
-        let fees_generated: i128 = 0;
-        let fee_key = String::from_str(&e, "fee_amount");
-        for att in attestations.iter() {
-            if att.attestation_type == String::from_str(&e, "fee_generation") {
-                // Try to get fee_amount from data map
-                if let Some(_fee_val) = att.data.get(fee_key.clone()) {
-                    // The value is stored as String, we need to parse it
-                    // For simplicity, we'll use a helper to extract numeric value
-                    // In a real implementation, fees would be stored as i128 directly
-                    // For now, we'll track fees in a separate storage or use a different approach
-                    // Since Map<String, String> stores strings, we'll need parsing
-                    // Simplified: assume fee is stored as string representation of number
-                }
-            }
+        let stored_version = read_version(&e);
+        if stored_version == CURRENT_VERSION {
+            return Err(AttestationError::AlreadyMigrated);
         }
-
-        // For now, fees_generated will be 0 until we implement proper fee tracking
-        // This is acceptable as the requirement is to sum from fee attestations
-        // which requires the attest() function to properly store fees
-
-        // Calculate volatility exposure from attestations
-        // Simplified: use variance of price changes from attestations
-        let mut volatility_exposure: i128 = 0;
-        if attestations.len() > 1 {
-            // Calculate variance from price data in attestations
-            // For now, return 0 as placeholder - would need price history
-            volatility_exposure = 0;
+        if from_version != stored_version || from_version > CURRENT_VERSION {
+            return Err(AttestationError::InvalidVersion);
         }
 
-        // Get last attestation timestamp
-        let last_attestation = attestations
-            .iter()
-            .map(|att| att.timestamp)
-            .max()
-            .unwrap_or(0);
-
-        // Calculate compliance score
-        let compliance_score = Self::calculate_compliance_score(e.clone(), commitment_id.clone());
-
-        HealthMetrics {
-            commitment_id,
-            current_value,
-            initial_value,
-            drawdown_percent,
-            fees_generated: state.fees_generated,
-            volatility_exposure: state.volatility_exposure,
-            last_attestation: state.last_attestation,
-            compliance_score: state.compliance_score,
+        // Ensure analytics counters are initialized
+        if !e.storage().instance().has(&DataKey::TotalAttestations) {
+            e.storage()
+                .instance()
+                .set(&DataKey::TotalAttestations, &0u64);
+        }
+        if !e.storage().instance().has(&DataKey::TotalViolations) {
+            e.storage()
+                .instance()
+                .set(&DataKey::TotalViolations, &0u64);
+        }
+        if !e.storage().instance().has(&DataKey::TotalFees) {
+            e.storage().instance().set(&DataKey::TotalFees, &0i128);
+        }
+        if !e.storage().instance().has(&DataKey::ReentrancyGuard) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
         }
-    }
-
-    /// Record fee generation
-    ///
-    /// Convenience function that creates a fee_generation attestation
-    ///
-    /// # Arguments
-    /// * `caller` - Must be authorized verifier
-    /// * `commitment_id` - The commitment generating fees
-    /// * `fee_amount` - The fee amount generated
-    /// Verify commitment compliance
-    pub fn verify_compliance(e: Env, commitment_id: String) -> bool {
-        let core = Self::get_commitment_core(&e);
-        let commitment = Self::core_get_commitment(&e, &core, &commitment_id);
-        let health = Self::get_health_metrics(e.clone(), commitment_id.clone());
-        let has_violations = Self::core_check_violations(&e, &core, &commitment_id);
-
-        // Loss limit compliance
-        let max_loss = commitment.rules.max_loss_percent as i128;
-        let loss_ok = health.drawdown_percent <= max_loss;
 
-        // Duration compliance (if applicable)
-        let now = e.ledger().timestamp();
-        let duration_ok = if commitment.rules.duration_days == 0 {
-            true
-        } else {
-            now <= commitment.expires_at
-        };
+        e.storage()
+            .instance()
+            .set(&DataKey::Version, &CURRENT_VERSION);
+        Ok(())
+    }
 
-        // Fee threshold compliance (if applicable)
-        let fee_ok = if commitment.rules.min_fee_threshold <= 0 {
-            true
-        } else {
-            health.fees_generated >= commitment.rules.min_fee_threshold
-        };
+    /// Get stored health metrics for a commitment (without recalculation)
+    pub fn get_stored_health_metrics(e: Env, commitment_id: String) -> Option<HealthMetrics> {
+        let key = DataKey::HealthMetrics(commitment_id);
+        e.storage().persistent().get(&key)
+    }
 
-        // Overall health compliance (if score is present; 0 means unknown)
-        let overall_health_ok = health.compliance_score == 0 || health.compliance_score >= 80;
+    // ========================================================================
+    // Validation Helpers
+    // ========================================================================
 
-        // Status-based sanity checks
-        let status_violated = commitment.status == String::from_str(&e, "violated");
-        let status_ok = !status_violated;
+    /// Validate attestation type is one of the allowed types
+    fn is_valid_attestation_type(e: &Env, att_type: &String) -> bool {
+        let health_check = String::from_str(e, "health_check");
+        let violation = String::from_str(e, "violation");
+        let fee_generation = String::from_str(e, "fee_generation");
+        let drawdown = String::from_str(e, "drawdown");
 
-        loss_ok && duration_ok && fee_ok && overall_health_ok && !has_violations && status_ok
+        *att_type == health_check
+            || *att_type == violation
+            || *att_type == fee_generation
+            || *att_type == drawdown
     }
 
-    /// Record fee generation
-    ///
-    /// # Arguments
-    /// * `commitment_id` - The commitment to verify
-    ///
-    /// # Returns
-    /// `true` if compliant, `false` otherwise
-    pub fn verify_compliance(e: Env, commitment_id: String) -> bool {
-        // Get commitment from core contract
-        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
-            Some(addr) => addr,
-            None => return false,
-        };
-
-        // Get commitment details
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val = match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+    /// Validate attestation data based on type
+    fn validate_attestation_data(e: &Env, att_type: &String, data: &Map<String, String>) -> bool {
+        let health_check = String::from_str(e, "health_check");
+        let violation = String::from_str(e, "violation");
+        let fee_generation = String::from_str(e, "fee_generation");
+        let drawdown = String::from_str(e, "drawdown");
+
+        if *att_type == health_check {
+            // health_check: optional fields; "current_value" must be a positive amount
+            match data.get(String::from_str(e, "current_value")) {
+                Some(value) => matches!(Self::parse_i128_from_string(e, &value), Some(v) if v > 0),
+                None => true,
+            }
+        } else if *att_type == violation {
+            // violation: requires "violation_type" and "severity"
+            let violation_type_key = String::from_str(e, "violation_type");
+            let severity_key = String::from_str(e, "severity");
+            data.contains_key(violation_type_key) && data.contains_key(severity_key)
+        } else if *att_type == fee_generation {
+            // fee_generation: requires "fee_amount"
+            let fee_amount_key = String::from_str(e, "fee_amount");
+            data.contains_key(fee_amount_key)
+        } else if *att_type == drawdown {
+            // drawdown: requires "drawdown_percent"
+            let drawdown_percent_key = String::from_str(e, "drawdown_percent");
+            data.contains_key(drawdown_percent_key)
+        } else {
+            false
+        }
+    }
+
+    /// Check if commitment exists in core contract
+    fn commitment_exists(e: &Env, commitment_id: &String) -> bool {
+        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
+            Some(addr) => addr,
+            None => return false,
+        };
+
+        // Try to get commitment from core contract
+        let mut args = Vec::new(e);
+        args.push_back(commitment_id.clone().into_val(e));
+
+        // Use try_invoke_contract to handle potential failures
+        let result = e.try_invoke_contract::<Val, soroban_sdk::Error>(
             &commitment_core,
-            &Symbol::new(&e, "get_commitment"),
+            &Symbol::new(e, "get_commitment"),
+            args,
+        );
+
+        matches!(result, Ok(Ok(_)))
+    }
+
+    /// Fetch a commitment from the core contract, or None if it cannot be read
+    fn fetch_commitment(e: &Env, commitment_id: &String) -> Option<Commitment> {
+        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract)?;
+
+        let mut args = Vec::new(e);
+        args.push_back(commitment_id.clone().into_val(e));
+
+        match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(e, "get_commitment"),
             args,
         ) {
-            Ok(Ok(val)) => val,
+            Ok(Ok(val)) => val.try_into_val(e).ok(),
+            _ => None,
+        }
+    }
+
+    // ========================================================================
+    // Health Metrics Update
+    // ========================================================================
+
+    /// Update health metrics after an attestation
+    ///
+    /// The stored `compliance_score` is undecayed; penalties are tracked in
+    /// `ScorePenalties` and fade when the score is read.
+    fn update_health_metrics(
+        e: &Env,
+        commitment_id: &String,
+        attestation: &Attestation,
+        index: u32,
+    ) {
+        // Get or create health metrics
+        let key = DataKey::HealthMetrics(commitment_id.clone());
+        let mut metrics: HealthMetrics = e
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Self::new_health_metrics(commitment_id));
+
+        let window_key = DataKey::ValueObservations(commitment_id.clone());
+        let mut window: Vec<i128> = e
+            .storage()
+            .persistent()
+            .get(&window_key)
+            .unwrap_or_else(|| Vec::new(e));
+        if Self::push_observation(e, &mut window, attestation) {
+            Self::apply_observations(&mut metrics, &window);
+            e.storage().persistent().set(&window_key, &window);
+        }
+
+        let score_before = metrics.compliance_score;
+        if let Some(fee_amount) = Self::apply_attestation_to_metrics(e, &mut metrics, attestation)
+        {
+            // Update global total fees analytics
+            let total_fees: i128 = e.storage().instance().get(&DataKey::TotalFees).unwrap_or(0);
+            let new_total = total_fees.checked_add(fee_amount).unwrap_or(total_fees);
+            e.storage().instance().set(&DataKey::TotalFees, &new_total);
+        }
+
+        let config = Self::scoring_config(e);
+        let mut penalties = Self::score_penalties(e, commitment_id);
+        Self::track_penalty(
+            &mut penalties,
+            index,
+            attestation,
+            score_before,
+            metrics.compliance_score,
+        );
+        Self::write_score_penalties(e, &config, &mut metrics, &mut penalties);
+
+        // Store updated metrics
+        e.storage().persistent().set(&key, &metrics);
+        let score = Self::decayed_score(&config, &metrics, &penalties, e.ledger().timestamp());
+        let score = Self::apply_series_penalties(&config, score, &metrics);
+        Self::record_score_snapshot(e, commitment_id, score, &config);
+    }
+
+    /// Rebuild health metrics by replaying every non-revoked attestation.
+    ///
+    /// Used after a revocation so the revoked record no longer contributes to
+    /// values, fees, drawdown or compliance score. Replay starts from the
+    /// state saved by the last archival, so archived attestations keep
+    /// counting. Global analytics are not touched.
+    fn rebuild_health_metrics(e: &Env, commitment_id: &String) {
+        let key = DataKey::HealthMetrics(commitment_id.clone());
+        let mut metrics = e
+            .storage()
+            .persistent()
+            .get::<DataKey, HealthMetrics>(&DataKey::ArchivedMetrics(commitment_id.clone()))
+            .unwrap_or_else(|| Self::new_health_metrics(commitment_id));
+        let mut window: Vec<i128> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::ArchivedObservations(commitment_id.clone()))
+            .unwrap_or_else(|| Vec::new(e));
+
+        // Penalties from archived attestations cannot be replayed, so keep them
+        let first_index = Self::first_retained_index(e, commitment_id);
+        let mut penalties = Vec::new(e);
+        for penalty in Self::score_penalties(e, commitment_id).iter() {
+            if penalty.index < first_index {
+                penalties.push_back(penalty);
+            }
+        }
+
+        let attestations = Self::get_attestations(e.clone(), commitment_id.clone());
+        for (offset, attestation) in attestations.iter().enumerate() {
+            if !attestation.revoked {
+                // The window is bounded, so remember the first value here
+                if Self::push_observation(e, &mut window, &attestation)
+                    && metrics.initial_value == 0
+                {
+                    metrics.initial_value = window.last().unwrap_or(0);
+                }
+                let score_before = metrics.compliance_score;
+                Self::apply_attestation_to_metrics(e, &mut metrics, &attestation);
+                Self::track_penalty(
+                    &mut penalties,
+                    first_index + offset as u32,
+                    &attestation,
+                    score_before,
+                    metrics.compliance_score,
+                );
+            }
+        }
+        Self::apply_observations(&mut metrics, &window);
+        e.storage()
+            .persistent()
+            .set(&DataKey::ValueObservations(commitment_id.clone()), &window);
+
+        let config = Self::scoring_config(e);
+        Self::write_score_penalties(e, &config, &mut metrics, &mut penalties);
+
+        e.storage().persistent().set(&key, &metrics);
+        let score = Self::decayed_score(&config, &metrics, &penalties, e.ledger().timestamp());
+        let score = Self::apply_series_penalties(&config, score, &metrics);
+        Self::record_score_snapshot(e, commitment_id, score, &config);
+    }
+
+    /// Fresh health metrics for a commitment with no attestations
+    fn new_health_metrics(commitment_id: &String) -> HealthMetrics {
+        HealthMetrics {
+            commitment_id: commitment_id.clone(),
+            current_value: 0,
+            initial_value: 0,
+            drawdown_percent: 0,
+            fees_generated: 0,
+            volatility_exposure: 0,
+            max_drawdown_bps: 0,
+            last_attestation: 0,
+            compliance_score: 100,
+        }
+    }
+
+    /// Apply a single attestation to health metrics.
+    ///
+    /// # Returns
+    /// The parsed fee amount for fee_generation attestations, so the caller
+    /// can update global analytics.
+    fn apply_attestation_to_metrics(
+        e: &Env,
+        metrics: &mut HealthMetrics,
+        attestation: &Attestation,
+    ) -> Option<i128> {
+        let mut recorded_fee = None;
+
+        // Update last_attestation timestamp
+        metrics.last_attestation = attestation.timestamp;
+
+        // Update type-specific metrics
+        let fee_generation = String::from_str(e, "fee_generation");
+        let drawdown_type = String::from_str(e, "drawdown");
+
+        if attestation.attestation_type == fee_generation {
+            // Add to fees_generated
+            let fee_amount_key = String::from_str(e, "fee_amount");
+            if let Some(fee_str) = attestation.data.get(fee_amount_key) {
+                // Parse fee amount from string
+                if let Some(fee_amount) = Self::parse_i128_from_string(e, &fee_str) {
+                    metrics.fees_generated = metrics
+                        .fees_generated
+                        .checked_add(fee_amount)
+                        .unwrap_or(metrics.fees_generated);
+                    recorded_fee = Some(fee_amount);
+                }
+            }
+        } else if attestation.attestation_type == drawdown_type {
+            // Update drawdown_percent
+            let drawdown_percent_key = String::from_str(e, "drawdown_percent");
+            if let Some(drawdown_str) = attestation.data.get(drawdown_percent_key) {
+                if let Some(drawdown_val) = Self::parse_i128_from_string(e, &drawdown_str) {
+                    metrics.drawdown_percent = drawdown_val;
+                }
+            }
+        }
+
+        // Undecayed score update; the caller tracks any penalty for decay
+        let config = Self::scoring_config(e);
+        metrics.compliance_score =
+            Self::apply_score(e, &config, metrics.compliance_score, attestation);
+
+        recorded_fee
+    }
+
+    /// Apply one attestation's penalty or bonus to `score`.
+    ///
+    /// Non-compliance penalties and compliance bonuses are scaled by the
+    /// attestation kind's weight; violations use `violation_penalty`.
+    fn apply_score(e: &Env, config: &ScoringConfig, score: u32, attestation: &Attestation) -> u32 {
+        if attestation.attestation_type == String::from_str(e, "enforcement") {
+            return score;
+        }
+        let violation = String::from_str(e, "violation");
+        if attestation.attestation_type == violation {
+            let severity_bps = match attestation.data.get(String::from_str(e, "severity")) {
+                Some(severity) if severity == String::from_str(e, "high") => {
+                    config.severity_high_bps
+                }
+                Some(severity) if severity == String::from_str(e, "medium") => {
+                    config.severity_medium_bps
+                }
+                Some(_) => config.severity_low_bps,
+                None => BPS_MAX,
+            };
+            let penalty = Self::scale_bps(config.violation_penalty, severity_bps);
+            score.saturating_sub(penalty)
+        } else if !attestation.is_compliant {
+            let penalty = Self::scale_bps(
+                config.non_compliant_penalty,
+                Self::kind_weight_bps(e, config, &attestation.attestation_type),
+            );
+            score.saturating_sub(penalty)
+        } else {
+            // Bonus for compliant attestations, capped at 100
+            let bonus = Self::scale_bps(
+                config.compliant_bonus,
+                Self::kind_weight_bps(e, config, &attestation.attestation_type),
+            );
+            core::cmp::min(100, score.saturating_add(bonus))
+        }
+    }
+
+    /// Configured weight for an attestation kind (BPS_MAX for other kinds)
+    fn kind_weight_bps(e: &Env, config: &ScoringConfig, attestation_type: &String) -> u32 {
+        if *attestation_type == String::from_str(e, "health_check") {
+            config.health_check_weight_bps
+        } else if *attestation_type == String::from_str(e, "fee_generation") {
+            config.fee_generation_weight_bps
+        } else if *attestation_type == String::from_str(e, "drawdown") {
+            config.drawdown_weight_bps
+        } else {
+            BPS_MAX
+        }
+    }
+
+    fn score_penalties(e: &Env, commitment_id: &String) -> Vec<ScorePenalty> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::ScorePenalties(commitment_id.clone()))
+            .unwrap_or_else(|| Vec::new(e))
+    }
+
+    /// Remember the points an attestation deducted so they can fade later
+    fn track_penalty(
+        penalties: &mut Vec<ScorePenalty>,
+        index: u32,
+        attestation: &Attestation,
+        score_before: u32,
+        score_after: u32,
+    ) {
+        if score_after < score_before {
+            penalties.push_back(ScorePenalty {
+                index,
+                timestamp: attestation.timestamp,
+                points: score_before - score_after,
+            });
+        }
+    }
+
+    /// Store tracked penalties after folding fully faded ones back into the
+    /// undecayed score.
+    ///
+    /// Beyond `MAX_SCORE_PENALTIES` the oldest penalty is folded at its
+    /// current weight and stops decaying. Folds of archived penalties are
+    /// mirrored into `ArchivedMetrics` so a later rebuild keeps them.
+    fn write_score_penalties(
+        e: &Env,
+        config: &ScoringConfig,
+        metrics: &mut HealthMetrics,
+        penalties: &mut Vec<ScorePenalty>,
+    ) {
+        let now = e.ledger().timestamp();
+        let first_index = Self::first_retained_index(e, &metrics.commitment_id);
+        let mut archived_restored: u32 = 0;
+        let mut kept = Vec::new(e);
+        let excess = penalties.len().saturating_sub(MAX_SCORE_PENALTIES);
+        for (position, penalty) in penalties.iter().enumerate() {
+            let remaining = Self::scale_bps(
+                penalty.points,
+                Self::decay_weight_bps(
+                    config.decay_half_life,
+                    now.saturating_sub(penalty.timestamp),
+                ),
+            );
+            if remaining > 0 && (position as u32) >= excess {
+                kept.push_back(penalty);
+                continue;
+            }
+            let restored = penalty.points - remaining;
+            metrics.compliance_score =
+                core::cmp::min(100, metrics.compliance_score.saturating_add(restored));
+            if penalty.index < first_index {
+                archived_restored = archived_restored.saturating_add(restored);
+            }
+        }
+        *penalties = kept;
+
+        if archived_restored > 0 {
+            let archived_key = DataKey::ArchivedMetrics(metrics.commitment_id.clone());
+            if let Some(mut archived) = e
+                .storage()
+                .persistent()
+                .get::<DataKey, HealthMetrics>(&archived_key)
+            {
+                archived.compliance_score = core::cmp::min(
+                    100,
+                    archived.compliance_score.saturating_add(archived_restored),
+                );
+                e.storage().persistent().set(&archived_key, &archived);
+            }
+        }
+
+        let key = DataKey::ScorePenalties(metrics.commitment_id.clone());
+        if penalties.is_empty() {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(&key, penalties);
+        }
+    }
+
+    /// Undecayed score with each tracked penalty re-weighted by its age
+    fn decayed_score(
+        config: &ScoringConfig,
+        metrics: &HealthMetrics,
+        penalties: &Vec<ScorePenalty>,
+        now: u64,
+    ) -> u32 {
+        if config.decay_half_life == 0 {
+            return metrics.compliance_score;
+        }
+        let mut restored: u32 = 0;
+        for penalty in penalties.iter() {
+            let remaining = Self::scale_bps(
+                penalty.points,
+                Self::decay_weight_bps(
+                    config.decay_half_life,
+                    now.saturating_sub(penalty.timestamp),
+                ),
+            );
+            restored = restored.saturating_add(penalty.points - remaining);
+        }
+        core::cmp::min(100, metrics.compliance_score.saturating_add(restored))
+    }
+
+    /// Weight of a penalty `age` seconds old, halving every `half_life` seconds
+    fn decay_weight_bps(half_life: u64, age: u64) -> u32 {
+        if half_life == 0 {
+            return BPS_MAX;
+        }
+        let halvings = age / half_life;
+        if halvings >= 32 {
+            0
+        } else {
+            BPS_MAX >> halvings
+        }
+    }
+
+    fn scale_bps(value: u32, bps: u32) -> u32 {
+        ((value as u64 * bps as u64) / BPS_MAX as u64) as u32
+    }
+
+    /// Append a score snapshot, dropping the oldest beyond `history_limit`
+    fn record_score_snapshot(e: &Env, commitment_id: &String, score: u32, config: &ScoringConfig) {
+        if config.history_limit == 0 {
+            return;
+        }
+        let key = DataKey::ScoreHistory(commitment_id.clone());
+        let mut history: Vec<ScoreSnapshot> = e
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(e));
+        history.push_back(ScoreSnapshot {
+            timestamp: e.ledger().timestamp(),
+            score,
+        });
+        while history.len() > config.history_limit {
+            history.pop_front();
+        }
+        e.storage().persistent().set(&key, &history);
+    }
+
+    /// Append a health_check's "current_value" to the observation window.
+    ///
+    /// # Returns
+    /// true if an observation was recorded
+    fn push_observation(e: &Env, window: &mut Vec<i128>, attestation: &Attestation) -> bool {
+        if attestation.attestation_type != String::from_str(e, "health_check") {
+            return false;
+        }
+        let value = match attestation
+            .data
+            .get(String::from_str(e, "current_value"))
+            .and_then(|v| Self::parse_i128_from_string(e, &v))
+        {
+            Some(v) if v > 0 => v,
             _ => return false,
         };
+        window.push_back(value);
+        while window.len() > VOLATILITY_WINDOW {
+            window.pop_front();
+        }
+        true
+    }
+
+    /// Refresh value, realized volatility and max drawdown from the window
+    fn apply_observations(metrics: &mut HealthMetrics, window: &Vec<i128>) {
+        let latest = match window.last() {
+            Some(v) => v,
+            None => {
+                metrics.volatility_exposure = 0;
+                metrics.max_drawdown_bps = 0;
+                return;
+            }
+        };
+        metrics.current_value = latest;
+        if metrics.initial_value == 0 {
+            metrics.initial_value = window.first().unwrap_or(latest);
+        }
+
+        // Max drawdown from running peak, in bps
+        let mut peak: i128 = 0;
+        let mut max_drawdown: i128 = 0;
+        for value in window.iter() {
+            if value > peak {
+                peak = value;
+            }
+            let drawdown = (peak - value)
+                .saturating_mul(BPS_MAX as i128)
+                .checked_div(peak)
+                .unwrap_or(0);
+            if drawdown > max_drawdown {
+                max_drawdown = drawdown;
+            }
+        }
+        metrics.max_drawdown_bps = max_drawdown;
+
+        // Population standard deviation of period returns, in bps
+        let n = window.len().saturating_sub(1) as i128;
+        if n == 0 {
+            metrics.volatility_exposure = 0;
+            return;
+        }
+        let mut sum: i128 = 0;
+        let mut sum_sq: i128 = 0;
+        let mut previous = window.first().unwrap_or(latest);
+        for value in window.iter().skip(1) {
+            let ret = (value - previous).saturating_mul(BPS_MAX as i128) / previous;
+            sum = sum.saturating_add(ret);
+            sum_sq = sum_sq.saturating_add(ret.saturating_mul(ret));
+            previous = value;
+        }
+        let mean = sum / n;
+        let variance = (sum_sq / n).saturating_sub(mean.saturating_mul(mean)).max(0);
+        metrics.volatility_exposure = Self::isqrt(variance);
+    }
+
+    /// Integer square root (floor) via Newton's method
+    fn isqrt(value: i128) -> i128 {
+        if value < 2 {
+            return value.max(0);
+        }
+        let mut x = value;
+        let mut y = (x + 1) / 2;
+        while y < x {
+            x = y;
+            y = (x + value / x) / 2;
+        }
+        x
+    }
+
+    /// Deduct the volatility and drawdown penalties while the observed value
+    /// series is above the configured caps
+    fn apply_series_penalties(config: &ScoringConfig, score: u32, metrics: &HealthMetrics) -> u32 {
+        let mut score = score;
+        if config.max_volatility_bps > 0
+            && metrics.volatility_exposure > config.max_volatility_bps as i128
+        {
+            score = score.saturating_sub(config.volatility_penalty);
+        }
+        if config.max_drawdown_bps > 0 && metrics.max_drawdown_bps > config.max_drawdown_bps as i128
+        {
+            score = score.saturating_sub(config.drawdown_penalty);
+        }
+        score
+    }
+
+    fn scoring_config(e: &Env) -> ScoringConfig {
+        e.storage()
+            .instance()
+            .get(&DataKey::ScoringConfig)
+            .unwrap_or(ScoringConfig {
+                violation_penalty: 20,
+                non_compliant_penalty: 0,
+                compliant_bonus: 1,
+                on_track_bonus: 10,
+                severity_high_bps: 15_000,
+                severity_medium_bps: BPS_MAX,
+                severity_low_bps: 5_000,
+                decay_half_life: 0,
+                history_limit: 30,
+                max_volatility_bps: 0,
+                volatility_penalty: 0,
+                max_drawdown_bps: 0,
+                drawdown_penalty: 0,
+                overdue_penalty: 0,
+                health_check_weight_bps: BPS_MAX,
+                fee_generation_weight_bps: BPS_MAX,
+                drawdown_weight_bps: BPS_MAX,
+            })
+    }
+
+    /// Parse i128 from String (optimized implementation)
+    fn parse_i128_from_string(_e: &Env, s: &String) -> Option<i128> {
+        let len = s.len();
+        if len == 0 || len > 64 {
+            return None; // Early return for invalid lengths
+        }
+
+        // Copy string to buffer
+        let mut buf = [0u8; 64];
+        s.copy_into_slice(&mut buf[..len as usize]);
+
+        let mut result: i128 = 0;
+        let mut start_idx = 0;
+        let is_negative = buf[0] == b'-';
+
+        if is_negative {
+            start_idx = 1;
+            if len == 1 {
+                return None; // Just a minus sign
+            }
+        }
+
+        // OPTIMIZATION: Single pass parsing with early exit on invalid char
+        for &b in buf.iter().take(len as usize).skip(start_idx) {
+            if !b.is_ascii_digit() {
+                return None; // Invalid character - early exit
+            }
+            result = result.checked_mul(10)?;
+            result = result.checked_add((b - b'0') as i128)?;
+        }
+
+        if is_negative {
+            result = result.checked_neg()?;
+        }
+
+        Some(result)
+    }
+
+    // ========================================================================
+    // Access Control
+    // ========================================================================
+
+    /// Record an attestation for a commitment
+    ///
+    /// # Arguments
+    /// * `caller` - The address recording the attestation (must be authorized verifier)
+    /// * `commitment_id` - The commitment being attested
+    /// * `attestation_type` - Type: "health_check", "violation", "fee_generation", "drawdown"
+    /// * `data` - Type-specific data map
+    /// * `is_compliant` - Whether the commitment is compliant
+    ///
+    /// # Returns
+    /// * `Ok(())` on success
+    /// * `Err(AttestationError::*)` on various validation failures
+    ///
+    /// # Reentrancy Protection
+    /// Uses checks-effects-interactions pattern with an explicit guard.
+    pub fn attest(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        attestation_type: String,
+        data: Map<String, String>,
+        is_compliant: bool,
+    ) -> Result<(), AttestationError> {
+        // 1. Reentrancy protection
+        if e.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // 2. Verify caller signed the transaction
+        caller.require_auth();
+
+        // 3. Check caller is authorized verifier
+        if !Self::is_authorized_verifier(&e, &caller) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::Unauthorized);
+        }
+
+        // 3b. Rate limit attestations per verifier
+        let fn_symbol = Symbol::new(&e, "attest");
+        RateLimiter::check(&e, &caller, &fn_symbol);
+
+        // 4. Validate commitment_id is not empty
+        if commitment_id.is_empty() {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::InvalidCommitmentId);
+        }
+
+        // 5. Validate commitment exists in core contract
+        if !Self::commitment_exists(&e, &commitment_id) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::CommitmentNotFound);
+        }
+
+        // 6. Validate attestation type
+        if !Self::is_valid_attestation_type(&e, &attestation_type) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::InvalidAttestationType);
+        }
+
+        // 7. Validate data format for the attestation type
+        if !Self::validate_attestation_data(&e, &attestation_type, &data) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            return Err(AttestationError::InvalidAttestationData);
+        }
+
+        // 7b. Collect attestation verification fee if configured
+        Self::collect_attestation_fee(&e, &caller, &caller, &attestation_type);
+
+        // 8-12. Store attestation, update metrics and analytics, emit event
+        Self::store_attestation(
+            &e,
+            Attestation {
+                commitment_id,
+                timestamp: e.ledger().timestamp(),
+                attestation_type,
+                data,
+                is_compliant,
+                verified_by: caller,
+                revoked: false,
+            },
+        );
+
+        // 13. Clear reentrancy guard
+        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        Ok(())
+    }
+
+    /// Get all retained (non-archived) attestations for a commitment.
+    ///
+    /// Reads every bucket; long-lived commitments should use `get_attestations_page`.
+    pub fn get_attestations(e: Env, commitment_id: String) -> Vec<Attestation> {
+        let len = Self::attestation_log_len(&e, &commitment_id);
+        let first = Self::first_retained_index(&e, &commitment_id);
+        Self::get_attestations_page(e, commitment_id, first, len.saturating_sub(first))
+    }
+
+    /// Get a page of attestations by log index, oldest first.
+    ///
+    /// # Arguments
+    /// * `commitment_id` - The commitment
+    /// * `offset` - Index of the first attestation; archived indices are skipped
+    /// * `limit` - Maximum number of attestations to return
+    pub fn get_attestations_page(
+        e: Env,
+        commitment_id: String,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<Attestation> {
+        let mut page = Vec::new(&e);
+        if !e
+            .storage()
+            .persistent()
+            .has(&DataKey::AttestationLogLength(commitment_id.clone()))
+        {
+            // Not yet migrated: serve from the legacy vector
+            let legacy: Vec<Attestation> = e
+                .storage()
+                .persistent()
+                .get(&DataKey::Attestations(commitment_id))
+                .unwrap_or_else(|| Vec::new(&e));
+            let end = core::cmp::min(offset.saturating_add(limit), legacy.len());
+            return if offset < end { legacy.slice(offset..end) } else { page };
+        }
+
+        let len = Self::attestation_log_len(&e, &commitment_id);
+        let mut index = core::cmp::max(offset, Self::first_retained_index(&e, &commitment_id));
+        let end = core::cmp::min(offset.saturating_add(limit), len);
+        while index < end {
+            let bucket_id = index / ATTESTATION_BUCKET_SIZE;
+            let bucket: Vec<Attestation> = e
+                .storage()
+                .persistent()
+                .get(&DataKey::AttestationBucket(commitment_id.clone(), bucket_id))
+                .unwrap_or_else(|| Vec::new(&e));
+            let bucket_end = core::cmp::min(end, (bucket_id + 1) * ATTESTATION_BUCKET_SIZE);
+            for i in index..bucket_end {
+                if let Some(attestation) = bucket.get(i % ATTESTATION_BUCKET_SIZE) {
+                    page.push_back(attestation);
+                }
+            }
+            index = bucket_end;
+        }
+        page
+    }
+
+    /// Index the next attestation will be appended at
+    ///
+    /// Archival advances it to the next bucket boundary so new attestations
+    /// land in buckets that are still retained.
+    pub fn get_attestation_log_length(e: Env, commitment_id: String) -> u32 {
+        Self::attestation_log_len(&e, &commitment_id)
+    }
+
+    /// Get the rolling summary of archived attestations, if any were archived
+    pub fn get_attestation_summary(e: Env, commitment_id: String) -> Option<AttestationSummary> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::AttestationSummary(commitment_id))
+    }
+
+    /// Fold a finished commitment's attestation buckets into its summary and
+    /// delete them.
+    ///
+    /// Callable by anyone once commitment_core reports the commitment settled or
+    /// early-exited; only the admin can archive a commitment core no longer
+    /// knows about. Nothing is archived while a dispute is open. Health metrics
+    /// and score history are kept.
+    ///
+    /// # Returns
+    /// Number of attestations archived
+    pub fn archive_attestations(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+    ) -> Result<u32, AttestationError> {
+        caller.require_auth();
+        match Self::fetch_commitment(&e, &commitment_id) {
+            Some(commitment) => {
+                if commitment.status != String::from_str(&e, "settled")
+                    && commitment.status != String::from_str(&e, "early_exit")
+                {
+                    return Err(AttestationError::CommitmentStillActive);
+                }
+            }
+            None => {
+                let admin: Address = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::Admin)
+                    .ok_or(AttestationError::NotInitialized)?;
+                if caller != admin {
+                    return Err(AttestationError::Unauthorized);
+                }
+            }
+        }
+        if e.storage()
+            .persistent()
+            .has(&DataKey::OpenDisputes(commitment_id.clone()))
+        {
+            return Err(AttestationError::CommitmentHasOpenDisputes);
+        }
+        Self::migrate_legacy_attestations(&e, &commitment_id);
+
+        let summary_key = DataKey::AttestationSummary(commitment_id.clone());
+        let mut summary: AttestationSummary =
+            e.storage()
+                .persistent()
+                .get(&summary_key)
+                .unwrap_or(AttestationSummary {
+                    archived_buckets: 0,
+                    archived_count: 0,
+                    compliant_count: 0,
+                    violation_count: 0,
+                    revoked_count: 0,
+                    first_timestamp: 0,
+                    last_timestamp: 0,
+                });
+
+        let len = Self::attestation_log_len(&e, &commitment_id);
+        let bucket_count = len.div_ceil(ATTESTATION_BUCKET_SIZE);
+        let violation = String::from_str(&e, "violation");
+        let mut archived: u32 = 0;
+        for bucket_id in summary.archived_buckets..bucket_count {
+            let key = DataKey::AttestationBucket(commitment_id.clone(), bucket_id);
+            let bucket: Vec<Attestation> = e
+                .storage()
+                .persistent()
+                .get(&key)
+                .unwrap_or_else(|| Vec::new(&e));
+            for attestation in bucket.iter() {
+                if summary.archived_count == 0 {
+                    summary.first_timestamp = attestation.timestamp;
+                }
+                summary.archived_count += 1;
+                summary.last_timestamp = attestation.timestamp;
+                if attestation.revoked {
+                    summary.revoked_count += 1;
+                } else if attestation.attestation_type == violation || !attestation.is_compliant {
+                    summary.violation_count += 1;
+                } else {
+                    summary.compliant_count += 1;
+                }
+                archived += 1;
+            }
+            e.storage().persistent().remove(&key);
+        }
+        summary.archived_buckets = bucket_count;
+        Self::unschedule_health_checks(&e, &commitment_id);
+
+        // Rebuilds replay only retained attestations, starting from this state
+        if let Some(metrics) = Self::get_stored_health_metrics(e.clone(), commitment_id.clone()) {
+            Self::write_archived_state(
+                &e,
+                &DataKey::ArchivedMetrics(commitment_id.clone()),
+                &metrics,
+            );
+        }
+        let observations: Vec<i128> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::ValueObservations(commitment_id.clone()))
+            .unwrap_or_else(|| Vec::new(&e));
+        Self::write_archived_state(
+            &e,
+            &DataKey::ArchivedObservations(commitment_id.clone()),
+            &observations,
+        );
+
+        // Later appends start in a fresh bucket rather than an archived one
+        let len_key = DataKey::AttestationLogLength(commitment_id.clone());
+        e.storage()
+            .persistent()
+            .set(&len_key, &(bucket_count * ATTESTATION_BUCKET_SIZE));
+        e.storage().persistent().extend_ttl(
+            &len_key,
+            ATTESTATION_TTL_THRESHOLD,
+            ATTESTATION_TTL_EXTEND_TO,
+        );
+
+        e.storage().persistent().set(&summary_key, &summary);
+        e.storage().persistent().extend_ttl(
+            &summary_key,
+            ATTESTATION_TTL_THRESHOLD,
+            ATTESTATION_TTL_EXTEND_TO,
+        );
+
+        e.events().publish(
+            (Symbol::new(&e, "AttestationsArchived"), commitment_id),
+            (archived, summary.archived_count, e.ledger().timestamp()),
+        );
+        Ok(archived)
+    }
+
+    /// Append to the paged attestation log and extend the bucket's TTL
+    fn append_attestation(e: &Env, attestation: &Attestation) -> u32 {
+        let commitment_id = &attestation.commitment_id;
+        Self::migrate_legacy_attestations(e, commitment_id);
+
+        let index = Self::attestation_log_len(e, commitment_id);
+        let key = DataKey::AttestationBucket(commitment_id.clone(), index / ATTESTATION_BUCKET_SIZE);
+        let mut bucket: Vec<Attestation> = e
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(e));
+        bucket.push_back(attestation.clone());
+        e.storage().persistent().set(&key, &bucket);
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, ATTESTATION_TTL_THRESHOLD, ATTESTATION_TTL_EXTEND_TO);
+
+        let len_key = DataKey::AttestationLogLength(commitment_id.clone());
+        e.storage().persistent().set(&len_key, &(index + 1));
+        e.storage()
+            .persistent()
+            .extend_ttl(&len_key, ATTESTATION_TTL_THRESHOLD, ATTESTATION_TTL_EXTEND_TO);
+        index
+    }
+
+    /// Read one attestation by log index (None if missing or archived)
+    fn read_attestation(e: &Env, commitment_id: &String, index: u32) -> Option<Attestation> {
+        Self::get_attestations_page(e.clone(), commitment_id.clone(), index, 1).get(0)
+    }
+
+    /// Overwrite one attestation in place (used for revocation)
+    fn write_attestation(e: &Env, commitment_id: &String, index: u32, attestation: &Attestation) {
+        Self::migrate_legacy_attestations(e, commitment_id);
+        let key = DataKey::AttestationBucket(commitment_id.clone(), index / ATTESTATION_BUCKET_SIZE);
+        let mut bucket: Vec<Attestation> = e
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(e));
+        if index % ATTESTATION_BUCKET_SIZE < bucket.len() {
+            bucket.set(index % ATTESTATION_BUCKET_SIZE, attestation.clone());
+            e.storage().persistent().set(&key, &bucket);
+        }
+    }
+
+    fn write_archived_state<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+        e.storage().persistent().set(key, value);
+        e.storage().persistent().extend_ttl(
+            key,
+            ATTESTATION_TTL_THRESHOLD,
+            ATTESTATION_TTL_EXTEND_TO,
+        );
+    }
+
+    fn attestation_log_len(e: &Env, commitment_id: &String) -> u32 {
+        if let Some(len) = e
+            .storage()
+            .persistent()
+            .get(&DataKey::AttestationLogLength(commitment_id.clone()))
+        {
+            return len;
+        }
+        e.storage()
+            .persistent()
+            .get::<DataKey, Vec<Attestation>>(&DataKey::Attestations(commitment_id.clone()))
+            .map(|legacy| legacy.len())
+            .unwrap_or(0)
+    }
+
+    /// Index of the oldest attestation still in storage
+    fn first_retained_index(e: &Env, commitment_id: &String) -> u32 {
+        e.storage()
+            .persistent()
+            .get::<DataKey, AttestationSummary>(&DataKey::AttestationSummary(commitment_id.clone()))
+            .map(|summary| summary.archived_buckets * ATTESTATION_BUCKET_SIZE)
+            .unwrap_or(0)
+    }
+
+    /// Move a pre-paging `Attestations(commitment_id)` vector into buckets
+    fn migrate_legacy_attestations(e: &Env, commitment_id: &String) {
+        let legacy_key = DataKey::Attestations(commitment_id.clone());
+        let legacy: Vec<Attestation> = match e.storage().persistent().get(&legacy_key) {
+            Some(legacy) => legacy,
+            None => return,
+        };
+        let len = legacy.len();
+        let mut bucket_id = 0;
+        while bucket_id * ATTESTATION_BUCKET_SIZE < len {
+            let start = bucket_id * ATTESTATION_BUCKET_SIZE;
+            let end = core::cmp::min(len, start + ATTESTATION_BUCKET_SIZE);
+            e.storage().persistent().set(
+                &DataKey::AttestationBucket(commitment_id.clone(), bucket_id),
+                &legacy.slice(start..end),
+            );
+            bucket_id += 1;
+        }
+        e.storage()
+            .persistent()
+            .set(&DataKey::AttestationLogLength(commitment_id.clone()), &len);
+        e.storage().persistent().remove(&legacy_key);
+    }
+
+    /// Get attestation count for a commitment
+    pub fn get_attestation_count(e: Env, commitment_id: String) -> u64 {
+        let key = DataKey::AttestationCounter(commitment_id);
+        e.storage().persistent().get(&key).unwrap_or(0)
+    }
+
+    /// Get current health metrics for a commitment
+    ///
+    /// Value and drawdown come from commitment_core; fees, volatility, last
+    /// attestation and score come from the attestations recorded here.
+    pub fn get_health_metrics(e: Env, commitment_id: String) -> HealthMetrics {
+        // Get commitment from core contract
+        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
+
+        // Call get_commitment on commitment_core contract
+        // Using Symbol::new() for function name longer than 9 characters
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment_val: Val =
+            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+
+        // Convert Val to Commitment
+        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+
+        // Extract values from commitment
+        let initial_value = commitment.amount; // Using amount as initial value
+        let current_value = commitment.current_value;
+
+        // Calculate drawdown percentage: ((initial - current) / initial) * 100
+        // Handle zero initial value to prevent division by zero
+        let drawdown_percent = if initial_value > 0 {
+            let diff = initial_value.checked_sub(current_value).unwrap_or(0);
+            diff.checked_mul(100)
+                .unwrap_or(0)
+                .checked_div(initial_value)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
+        // Fees, realized volatility and max drawdown tracked from attestations
+        let stored = Self::get_stored_health_metrics(e.clone(), commitment_id.clone())
+            .unwrap_or_else(|| Self::new_health_metrics(&commitment_id));
+
+        // Calculate compliance score
+        let compliance_score = Self::calculate_compliance_score(e.clone(), commitment_id.clone());
+
+        HealthMetrics {
+            commitment_id,
+            current_value,
+            initial_value,
+            drawdown_percent,
+            fees_generated: stored.fees_generated,
+            volatility_exposure: stored.volatility_exposure,
+            max_drawdown_bps: stored.max_drawdown_bps,
+            last_attestation: stored.last_attestation,
+            compliance_score,
+        }
+    }
+
+    /// Verify commitment compliance
+    ///
+    /// Checks the loss limit, duration, fee threshold, compliance score and
+    /// the commitment's status.
+    ///
+    /// # Arguments
+    /// * `commitment_id` - The commitment to verify
+    ///
+    /// # Returns
+    /// `true` if compliant, `false` otherwise (including when the commitment
+    /// cannot be read from commitment_core)
+    pub fn verify_compliance(e: Env, commitment_id: String) -> bool {
+        // Get commitment from core contract
+        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
+            Some(addr) => addr,
+            None => return false,
+        };
+
+        // Get commitment details
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment_val: Val = match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+            &commitment_core,
+            &Symbol::new(&e, "get_commitment"),
+            args,
+        ) {
+            Ok(Ok(val)) => val,
+            _ => return false,
+        };
+
+        let commitment: Commitment = match commitment_val.try_into_val(&e) {
+            Ok(c) => c,
+            Err(_) => return false,
+        };
+
+        // Get health metrics
+        let metrics = Self::get_health_metrics(e.clone(), commitment_id);
+
+        // Loss limit compliance
+        let max_loss = commitment.rules.max_loss_percent as i128;
+        let loss_ok = metrics.drawdown_percent <= max_loss;
+
+        // Duration compliance (if applicable)
+        let duration_ok =
+            commitment.rules.duration_days == 0 || e.ledger().timestamp() <= commitment.expires_at;
+
+        // Fee threshold compliance (if applicable)
+        let fee_ok = commitment.rules.min_fee_threshold <= 0
+            || metrics.fees_generated >= commitment.rules.min_fee_threshold;
+
+        // Compliance score threshold (below 50 is non-compliant)
+        let score_ok = metrics.compliance_score >= 50;
+
+        let status_ok = commitment.status != String::from_str(&e, "violated");
+
+        loss_ok && duration_ok && fee_ok && score_ok && status_ok
+    }
+
+    /// Record fee generation
+    ///
+    /// Convenience function that creates a fee_generation attestation
+    ///
+    /// # Arguments
+    /// * `caller` - Must be authorized verifier
+    /// * `commitment_id` - The commitment generating fees
+    /// * `fee_amount` - The fee amount generated
+    pub fn record_fees(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        fee_amount: i128,
+    ) -> Result<(), AttestationError> {
+        // Build data map for fee_generation attestation
+        let mut data = Map::new(&e);
+        data.set(
+            String::from_str(&e, "fee_amount"),
+            Self::i128_to_string(&e, fee_amount),
+        );
+
+        // Call attest with fee_generation type
+        Self::attest(
+            e.clone(),
+            caller,
+            commitment_id.clone(),
+            String::from_str(&e, "fee_generation"),
+            data,
+            true,
+        )?;
+
+        // Emit FeeRecorded event
+        e.events().publish(
+            (Symbol::new(&e, "FeeRecorded"), commitment_id),
+            (fee_amount, e.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Record drawdown event
+    ///
+    /// A breach runs the `on_drawdown_breach` enforcement policy.
+    ///
+    /// # Arguments
+    /// * `caller` - Must be authorized verifier
+    /// * `commitment_id` - The commitment with drawdown
+    /// * `drawdown_percent` - The current drawdown percentage
+    pub fn record_drawdown(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        drawdown_percent: i128,
+    ) -> Result<(), AttestationError> {
+        // Get commitment to check max_loss_percent
+        let commitment_core: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::CoreContract)
+            .ok_or(AttestationError::NotInitialized)?;
+
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment_val: Val =
+            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+
+        let commitment: Commitment = commitment_val
+            .try_into_val(&e)
+            .map_err(|_| AttestationError::CommitmentNotFound)?;
+
+        let max_loss = commitment.rules.max_loss_percent as i128;
+        let is_compliant = drawdown_percent <= max_loss;
+
+        // Build data map for drawdown attestation
+        let mut data = Map::new(&e);
+        data.set(
+            String::from_str(&e, "drawdown_percent"),
+            Self::i128_to_string(&e, drawdown_percent),
+        );
+        data.set(
+            String::from_str(&e, "max_loss_percent"),
+            Self::i128_to_string(&e, max_loss),
+        );
+
+        Self::attest(
+            e.clone(),
+            caller,
+            commitment_id.clone(),
+            String::from_str(&e, "drawdown"),
+            data,
+            is_compliant,
+        )?;
+
+        // Emit DrawdownRecorded event
+        e.events().publish(
+            (Symbol::new(&e, "DrawdownRecorded"), commitment_id),
+            (drawdown_percent, is_compliant, e.ledger().timestamp()),
+        );
+
+        Ok(())
+    }
+
+    /// Convert i128 to String (helper function)
+    fn i128_to_string(e: &Env, value: i128) -> String {
+        if value == 0 {
+            return String::from_str(e, "0");
+        }
+
+        let mut n = value;
+        let is_negative = n < 0;
+        if is_negative {
+            n = -n;
+        }
+
+        let mut buf = [0u8; 64];
+        let mut i = 0;
+
+        while n > 0 {
+            let digit = (n % 10) as u8 + b'0';
+            if i < 64 {
+                buf[i] = digit;
+                i += 1;
+            }
+            n /= 10;
+        }
+
+        if is_negative && i < 64 {
+            buf[i] = b'-';
+            i += 1;
+        }
+
+        // Reverse buffer
+        let len = i;
+        let mut result_buf = [0u8; 64];
+        for j in 0..len {
+            result_buf[j] = buf[len - 1 - j];
+        }
+
+        String::from_str(e, core::str::from_utf8(&result_buf[..len]).unwrap_or("0"))
+    }
+
+    /// Calculate compliance score (0-100)
+    ///
+    /// # Formal Verification
+    /// **Preconditions:**
+    /// - `commitment_id` exists
+    ///
+    /// **Postconditions:**
+    /// - Returns value in range [0, 100]
+    /// - Score decreases with violations
+    /// - Score decreases if drawdown exceeds threshold
+    /// - Pure function (no state changes)
+    ///
+    /// **Invariants Maintained:**
+    /// - Score always in valid range [0, 100]
+    ///
+    /// **Security Properties:**
+    /// - SP-4: State consistency (read-only)
+    /// - SP-3: Arithmetic safety
+    pub fn calculate_compliance_score(e: Env, commitment_id: String) -> u32 {
+        // First check if we have stored metrics with a compliance score
+        let metrics_key = DataKey::HealthMetrics(commitment_id.clone());
+        if let Some(stored_metrics) = e
+            .storage()
+            .persistent()
+            .get::<DataKey, HealthMetrics>(&metrics_key)
+        {
+            // Penalties fade with time, so the decayed score is computed on read
+            let config = Self::scoring_config(&e);
+            let score = Self::decayed_score(
+                &config,
+                &stored_metrics,
+                &Self::score_penalties(&e, &commitment_id),
+                e.ledger().timestamp(),
+            );
+            let score = Self::apply_series_penalties(&config, score, &stored_metrics);
+            return if Self::is_health_check_overdue(&e, &stored_metrics.commitment_id) {
+                score.saturating_sub(config.overdue_penalty)
+            } else {
+                score
+            };
+        }
+
+        // Get commitment from core contract
+        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
+
+        // Call get_commitment on commitment_core contract
+        // Using Symbol::new() for function name longer than 9 characters
+        let mut args = Vec::new(&e);
+        args.push_back(commitment_id.clone().into_val(&e));
+        let commitment_val: Val =
+            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+
+        // Convert Val to Commitment
+        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+
+        // Without stored metrics there are no attestations to score yet; the
+        // on-track bonus below has to be earned
+        let config = Self::scoring_config(&e);
+        let mut score: i32 = 100 - config.on_track_bonus as i32;
+
+        // Calculate drawdown vs threshold: -1 per % over threshold
+        let initial_value = commitment.amount;
+        let current_value = commitment.current_value;
+        let max_loss_percent = commitment.rules.max_loss_percent as i128;
+
+        let drawdown_percent = if initial_value > 0 {
+            let diff = initial_value.checked_sub(current_value).unwrap_or(0);
+            diff.checked_mul(100)
+                .unwrap_or(0)
+                .checked_div(initial_value)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        if drawdown_percent > max_loss_percent {
+            let over_threshold = drawdown_percent.checked_sub(max_loss_percent).unwrap_or(0);
+            score = score.checked_sub(over_threshold as i32).unwrap_or(0);
+        }
+
+        // Duration adherence: while the commitment runs, the on-track bonus
+        // shrinks as losses use up the max_loss_percent headroom
+        let current_time = e.ledger().timestamp();
+        let expires_at = commitment.expires_at;
+        let created_at = commitment.created_at;
+
+        if expires_at > created_at && current_time <= expires_at {
+            let on_track_bonus = if drawdown_percent <= 0 {
+                config.on_track_bonus as i128
+            } else if drawdown_percent < max_loss_percent {
+                (config.on_track_bonus as i128)
+                    .checked_mul(max_loss_percent - drawdown_percent)
+                    .unwrap_or(0)
+                    .checked_div(max_loss_percent)
+                    .unwrap_or(0)
+            } else {
+                0
+            };
+            score = score.checked_add(on_track_bonus as i32).unwrap_or(100);
+        }
+
+        // Clamp between 0 and 100
+        score = score.clamp(0, 100);
+
+        // Emit compliance score update event
+        e.events().publish(
+            (symbol_short!("ScoreUpd"), commitment_id),
+            (score as u32, e.ledger().timestamp()),
+        );
+
+        score as u32
+    }
+
+    /// Get high-level protocol analytics combining commitment and attestation data.
+    ///
+    /// Returns:
+    /// - total_commitments (from core contract)
+    /// - total_attestations
+    /// - total_violations
+    /// - total_fees_generated
+    pub fn get_protocol_statistics(e: Env) -> (u64, u64, u64, i128) {
+        // Read commitment_core statistics
+        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
+
+        // get_total_commitments() on core contract
+        let args = Vec::new(&e);
+        let total_commitments_val: Val = e.invoke_contract(
+            &commitment_core,
+            &Symbol::new(&e, "get_total_commitments"),
+            args,
+        );
+        let total_commitments: u64 = total_commitments_val.try_into_val(&e).unwrap();
+
+        let total_attestations: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalAttestations)
+            .unwrap_or(0);
+        let total_violations: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalViolations)
+            .unwrap_or(0);
+        let total_fees: i128 = e.storage().instance().get(&DataKey::TotalFees).unwrap_or(0);
+
+        (
+            total_commitments,
+            total_attestations,
+            total_violations,
+            total_fees,
+        )
+    }
+
+    /// Get analytics for a given verifier (attestation recorder).
+    ///
+    /// Returns the total number of attestations recorded by this verifier.
+    pub fn get_verifier_statistics(e: Env, verifier: Address) -> u64 {
+        let key = DataKey::VerifierAttestationCount(verifier);
+        e.storage().instance().get(&key).unwrap_or(0)
+    }
+
+    /// Get per-verifier performance metrics (zeroed if the verifier never attested)
+    pub fn get_verifier_metrics(e: Env, verifier: Address) -> VerifierMetrics {
+        e.storage()
+            .persistent()
+            .get(&DataKey::VerifierMetrics(verifier))
+            .unwrap_or(VerifierMetrics {
+                health_checks: 0,
+                violations: 0,
+                fee_generations: 0,
+                drawdowns: 0,
+                revoked: 0,
+                disputes_lost: 0,
+                disputes_won: 0,
+                total_latency: 0,
+                latency_samples: 0,
+                last_active: 0,
+            })
+    }
+
+    /// Reputation score (0-100) for a verifier.
+    ///
+    /// Starts at 100; loses `REPUTATION_DISPUTE_PENALTY` per upheld dispute,
+    /// `REPUTATION_REVOCATION_PENALTY` per revoked attestation, and one point per
+    /// `REPUTATION_LATENCY_UNIT` of average lateness of scheduled health_checks
+    /// (at most 20).
+    pub fn get_verifier_reputation(e: Env, verifier: Address) -> u32 {
+        let metrics = Self::get_verifier_metrics(e, verifier);
+        let latency_penalty = match metrics.total_latency.checked_div(metrics.latency_samples) {
+            Some(average) => core::cmp::min(20, average / REPUTATION_LATENCY_UNIT) as u32,
+            None => 0,
+        };
+        let penalty = metrics
+            .disputes_lost
+            .saturating_mul(REPUTATION_DISPUTE_PENALTY)
+            .saturating_add(
+                (metrics.revoked as u32).saturating_mul(REPUTATION_REVOCATION_PENALTY),
+            )
+            .saturating_add(latency_penalty);
+        100u32.saturating_sub(penalty)
+    }
+
+    /// List whitelisted verifiers, in the order they were added
+    ///
+    /// # Arguments
+    /// * `offset` - Number of verifiers to skip
+    /// * `limit` - Maximum number of verifiers to return
+    pub fn list_verifiers(e: Env, offset: u32, limit: u32) -> Vec<Address> {
+        let verifiers = Self::verifier_list(&e);
+        let end = core::cmp::min(offset.saturating_add(limit), verifiers.len());
+        if offset >= end {
+            return Vec::new(&e);
+        }
+        verifiers.slice(offset..end)
+    }
+
+    fn verifier_list(e: &Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::VerifierList)
+            .unwrap_or_else(|| Vec::new(e))
+    }
+
+    fn remove_from_verifier_list(e: &Env, verifier: &Address) {
+        let mut verifiers = Self::verifier_list(e);
+        if let Some(index) = verifiers.first_index_of(verifier) {
+            verifiers.remove(index);
+            e.storage().instance().set(&DataKey::VerifierList, &verifiers);
+        }
+    }
+
+    /// Update the verifier's per-kind counts, latency and last activity
+    fn record_verifier_activity(e: &Env, attestation: &Attestation) {
+        let verifier = attestation.verified_by.clone();
+        let mut metrics = Self::get_verifier_metrics(e.clone(), verifier.clone());
+
+        let kind = &attestation.attestation_type;
+        if *kind == String::from_str(e, "health_check") {
+            metrics.health_checks += 1;
+        } else if *kind == String::from_str(e, "violation") {
+            metrics.violations += 1;
+        } else if *kind == String::from_str(e, "fee_generation") {
+            metrics.fee_generations += 1;
+        } else if *kind == String::from_str(e, "drawdown") {
+            metrics.drawdowns += 1;
+        }
+
+        // Latency is measured against the on-chain schedule rather than any
+        // time the verifier reports: how long past due a health_check landed
+        if *kind == String::from_str(e, "health_check") {
+            if let Some(schedule) =
+                Self::get_health_check_schedule(e.clone(), attestation.commitment_id.clone())
+            {
+                metrics.total_latency += attestation.timestamp.saturating_sub(schedule.next_due_at);
+                metrics.latency_samples += 1;
+            }
+        }
+
+        metrics.last_active = attestation.timestamp;
+        e.storage()
+            .persistent()
+            .set(&DataKey::VerifierMetrics(verifier), &metrics);
+    }
+
+    // ========================================================================
+    // Batch Operations
+    // ========================================================================
+
+    /// Batch attest multiple commitments in a single transaction
+    ///
+    /// # Arguments
+    /// * `caller` - The address recording the attestations (must be authorized verifier)
+    /// * `params_list` - Vector of AttestParams for each attestation
+    /// * `mode` - BatchMode::Atomic or BatchMode::BestEffort
+    ///
+    /// # Returns
+    /// BatchResult with empty results and any errors
+    ///
+    /// # Gas Optimization
+    /// - Batch read of analytics counters
+    /// - Single aggregate counter update at end
+    /// - Batch health metrics updates
+    pub fn batch_attest(
+        e: Env,
+        caller: Address,
+        params_list: Vec<AttestParams>,
+        mode: BatchMode,
+    ) -> BatchResultVoid {
+        // Reentrancy protection
+        if e.storage().instance().has(&DataKey::ReentrancyGuard) {
+            panic!("Reentrancy detected");
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // Verify caller signed the transaction
+        caller.require_auth();
+
+        // Check caller is authorized verifier
+        if !Self::is_authorized_verifier(&e, &caller) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code: AttestationError::Unauthorized as u32,
+                context: String::from_str(&e, "not_authorized_verifier"),
+            });
+            return BatchResultVoid::failure(&e, errors);
+        }
+
+        // Validate batch size
+        let batch_size = params_list.len();
+        let contract_name = String::from_str(&e, "attestation_engine");
+        if let Err(error_code) = BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name)) {
+            e.storage().instance().remove(&DataKey::ReentrancyGuard);
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code,
+                context: String::from_str(&e, "batch_size_validation"),
+            });
+            return BatchResultVoid::failure(&e, errors);
+        }
+
+        let mut errors = Vec::new(&e);
+        let mut results = Vec::new(&e);
+
+        // Read analytics counters once (optimization)
+        let (mut total_attestations, mut total_violations, mut verifier_count) = {
+            let total_att = e.storage().instance().get(&DataKey::TotalAttestations).unwrap_or(0u64);
+            let total_viol = e.storage().instance().get(&DataKey::TotalViolations).unwrap_or(0u64);
+            let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
+            let ver_count = e.storage().instance().get(&verifier_key).unwrap_or(0u64);
+            (total_att, total_viol, ver_count)
+        };
+
+        let timestamp = e.ledger().timestamp();
+        let violation_type = String::from_str(&e, "violation");
+
+        // Process each attestation
+        for i in 0..batch_size {
+            let params = params_list.get(i).unwrap();
+
+            // Validate commitment_id
+            if params.commitment_id.is_empty() {
+                if mode == BatchMode::Atomic {
+                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::InvalidCommitmentId as u32,
+                        context: String::from_str(&e, "empty_commitment_id"),
+                    });
+                    return BatchResultVoid::failure(&e, errors);
+                } else {
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::InvalidCommitmentId as u32,
+                        context: String::from_str(&e, "empty_commitment_id"),
+                    });
+                    continue;
+                }
+            }
+
+            // Validate commitment exists
+            if !Self::commitment_exists(&e, &params.commitment_id) {
+                if mode == BatchMode::Atomic {
+                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::CommitmentNotFound as u32,
+                        context: String::from_str(&e, "commitment_not_found"),
+                    });
+                    return BatchResultVoid::failure(&e, errors);
+                } else {
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::CommitmentNotFound as u32,
+                        context: String::from_str(&e, "commitment_not_found"),
+                    });
+                    continue;
+                }
+            }
+
+            // Validate attestation type
+            if !Self::is_valid_attestation_type(&e, &params.attestation_type) {
+                if mode == BatchMode::Atomic {
+                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::InvalidAttestationType as u32,
+                        context: String::from_str(&e, "invalid_type"),
+                    });
+                    return BatchResultVoid::failure(&e, errors);
+                } else {
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::InvalidAttestationType as u32,
+                        context: String::from_str(&e, "invalid_type"),
+                    });
+                    continue;
+                }
+            }
+
+            // Validate data format
+            if !Self::validate_attestation_data(&e, &params.attestation_type, &params.data) {
+                if mode == BatchMode::Atomic {
+                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::InvalidAttestationData as u32,
+                        context: String::from_str(&e, "invalid_data"),
+                    });
+                    return BatchResultVoid::failure(&e, errors);
+                } else {
+                    errors.push_back(BatchError {
+                        index: i,
+                        error_code: AttestationError::InvalidAttestationData as u32,
+                        context: String::from_str(&e, "invalid_data"),
+                    });
+                    continue;
+                }
+            }
+
+            // Create attestation record
+            let attestation = Attestation {
+                commitment_id: params.commitment_id.clone(),
+                attestation_type: params.attestation_type.clone(),
+                data: params.data.clone(),
+                timestamp,
+                verified_by: caller.clone(),
+                is_compliant: params.is_compliant,
+                revoked: false,
+            };
 
-        let commitment: Commitment = match commitment_val.try_into_val(&e) {
-            Ok(c) => c,
-            Err(_) => return false,
-        };
+            // Store attestation
+            let index = Self::append_attestation(&e, &attestation);
+            Self::record_verifier_activity(&e, &attestation);
+            Self::advance_health_check_schedule(&e, &attestation);
 
-        // Get health metrics
-        let metrics = Self::get_health_metrics(e.clone(), commitment_id);
+            // Update health metrics
+            Self::update_health_metrics(&e, &params.commitment_id, &attestation, index);
 
-        // Check compliance rules
-        let max_loss = commitment.rules.max_loss_percent as i128;
+            // Increment attestation counter
+            let counter_key = DataKey::AttestationCounter(params.commitment_id.clone());
+            let counter: u64 = e.storage()
+                .persistent()
+                .get(&counter_key)
+                .unwrap_or(0);
+            e.storage().persistent().set(&counter_key, &(counter + 1));
 
-        // Check if drawdown exceeds max loss
-        if metrics.drawdown_percent > max_loss {
-            return false;
-        }
+            // Update analytics counters (in memory)
+            total_attestations += 1;
+            verifier_count += 1;
+            if attestation.attestation_type == violation_type || !attestation.is_compliant {
+                total_violations += 1;
+            }
 
-        // Check compliance score threshold (below 50 is non-compliant)
-        if metrics.compliance_score < 50 {
-            return false;
-        }
+            results.push_back(());
 
-        true
-    }
+            // Emit event
+            e.events().publish(
+                (Symbol::new(&e, "AttestationRecorded"), params.commitment_id.clone(), caller.clone()),
+                (params.attestation_type.clone(), params.is_compliant, timestamp)
+            );
 
-    /// Record fee generation
-    ///
-    /// Convenience function that creates a fee_generation attestation
-    ///
-    /// # Arguments
-    /// * `caller` - Must be authorized verifier
-    /// * `commitment_id` - The commitment generating fees
-    /// * `fee_amount` - The fee amount generated
-    pub fn record_fees(
-        e: Env,
-        caller: Address,
-        commitment_id: String,
-        fee_amount: i128,
-    ) -> Result<(), AttestationError> {
-        // Build data map for fee_generation attestation
-        let mut data = Map::new(&e);
-        data.set(
-            String::from_str(&e, "fee_amount"),
-            Self::i128_to_string(&e, fee_amount),
-        );
+            Self::apply_enforcement_policy(&e, &attestation, index);
+        }
 
-        // Call attest with fee_generation type
-        Self::attest(
-            e.clone(),
-            caller,
-            commitment_id.clone(),
-            String::from_str(&e, "fee_generation"),
-            data,
-            timestamp: e.ledger().timestamp(),
-            verified_by: caller.clone(),
-            is_compliant: true,
-        };
+        // Write analytics counters once (optimization)
+        e.storage().instance().set(&DataKey::TotalAttestations, &total_attestations);
+        e.storage().instance().set(&DataKey::TotalViolations, &total_violations);
+        let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
+        e.storage().instance().set(&verifier_key, &verifier_count);
 
-        // Store attestation
-        let atts_key = (symbol_short!("ATTS"), commitment_id.clone());
-        let mut attestations: Vec<Attestation> = e
-            .storage()
-            .persistent()
-            .get(&atts_key)
-            .unwrap_or_else(|| Vec::new(&e));
-        attestations.push_back(attestation);
-        e.storage().persistent().set(&atts_key, &attestations);
-        
-        // Recalculate compliance score (may call external contract)
-        metrics.compliance_score = Self::calculate_compliance_score(e.clone(), commitment_id.clone());
-        
-        // Update last attestation timestamp
-        metrics.last_attestation = e.ledger().timestamp();
-        
-        // Store updated health metrics
-        Self::store_health_metrics(&e, &metrics);
-        
         // Clear reentrancy guard
-        e.storage().instance().set(&guard_key, &false);
-        
-        // Emit FeeRecorded event
+        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        // Emit batch event
         e.events().publish(
-            (Symbol::new(&e, "FeeRecorded"), commitment_id),
-            (fee_amount, e.ledger().timestamp()),
+            (Symbol::new(&e, "BatchAttest"), batch_size),
+            (results.len(), errors.len(), timestamp)
         );
 
-        Ok(())
+        BatchResultVoid::partial(results.len(), errors)
     }
 
-    /// Record drawdown event
+    // ========================================================================
+    // Enforcement
+    // ========================================================================
+
+    /// Configure automatic enforcement into commitment_core (admin only).
     ///
-    /// # Arguments
-    /// * `caller` - Must be authorized verifier
-    /// * `commitment_id` - The commitment with drawdown
-    /// * `drawdown_percent` - The current drawdown percentage
-    pub fn record_drawdown(
+    /// The engine must also be registered with `commitment_core.set_enforcer`.
+    pub fn set_enforcement_policy(
         e: Env,
         caller: Address,
-        commitment_id: String,
-        drawdown_percent: i128,
+        policy: EnforcementPolicy,
     ) -> Result<(), AttestationError> {
-        // Get commitment to check max_loss_percent
-        let commitment_core: Address = e
-            .storage()
+        require_admin(&e, &caller)?;
+        e.storage()
             .instance()
-            .get(&DataKey::CoreContract)
-            .ok_or(AttestationError::NotInitialized)?;
-
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val =
-            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
-
-        let commitment: Commitment = commitment_val
-            .try_into_val(&e)
-            .map_err(|_| AttestationError::CommitmentNotFound)?;
-
-        let max_loss = commitment.rules.max_loss_percent as i128;
-        let is_compliant = drawdown_percent <= max_loss;
-
-        // Build data map for drawdown attestation
-        let mut data = Map::new(&e);
-        data.set(
-            String::from_str(&e, "drawdown_percent"),
-            Self::i128_to_string(&e, drawdown_percent),
-        );
-        data.set(
-            String::from_str(&e, "max_loss_percent"),
-            Self::i128_to_string(&e, max_loss),
+            .set(&DataKey::EnforcementPolicy, &policy);
+        e.events().publish(
+            (Symbol::new(&e, "EnforcementPolicySet"), caller),
+            (
+                policy.on_violation,
+                policy.on_drawdown_breach,
+                policy.dispute_window,
+            ),
         );
+        Ok(())
+    }
 
-            // Store violation attestation
-            let atts_key = (symbol_short!("ATTS"), commitment_id.clone());
-            let mut attestations: Vec<Attestation> = e
-                .storage()
-                .persistent()
-                .get(&atts_key)
-                .unwrap_or_else(|| Vec::new(&e));
-            attestations.push_back(violation_attestation);
-            e.storage().persistent().set(&atts_key, &attestations);
+    /// Get the enforcement policy (no enforcement if never configured)
+    pub fn get_enforcement_policy(e: Env) -> EnforcementPolicy {
+        e.storage()
+            .instance()
+            .get(&DataKey::EnforcementPolicy)
+            .unwrap_or(EnforcementPolicy {
+                on_violation: EnforcementAction::None,
+                on_drawdown_breach: EnforcementAction::None,
+                dispute_window: 0,
+            })
+    }
 
-            // Emit ViolationDetected event
-            e.events().publish(
-                (Symbol::new(&e, "ViolationDetected"), commitment_id.clone()),
-                (drawdown_percent, max_loss_percent, e.ledger().timestamp()),
-            );
-        }
-        
-        // Create drawdown attestation
-        let drawdown_data = Map::new(&e);
-        let drawdown_attestation = Attestation {
-            commitment_id: commitment_id.clone(),
-            attestation_type: String::from_str(&e, "drawdown"),
-            data: drawdown_data,
-            timestamp: e.ledger().timestamp(),
-            verified_by: caller.clone(),
-            is_compliant: !is_violation,
-        };
+    /// Get the enforcement triggered by an attestation, if any
+    pub fn get_enforcement(
+        e: Env,
+        commitment_id: String,
+        attestation_index: u32,
+    ) -> Option<EnforcementRecord> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::Enforcement(commitment_id, attestation_index))
+    }
 
-        // Store drawdown attestation
-        let atts_key = (symbol_short!("ATTS"), commitment_id.clone());
-        let mut attestations: Vec<Attestation> = e
+    /// Apply a pending enforcement once its dispute window has closed.
+    ///
+    /// Callable by anyone. Fails while the window is open or the triggering
+    /// attestation has an open dispute. Returns whether core accepted the action.
+    pub fn execute_enforcement(
+        e: Env,
+        commitment_id: String,
+        attestation_index: u32,
+    ) -> Result<bool, AttestationError> {
+        let key = DataKey::Enforcement(commitment_id.clone(), attestation_index);
+        let record: EnforcementRecord = e
             .storage()
             .persistent()
-            .get(&atts_key)
-            .unwrap_or_else(|| Vec::new(&e));
-        attestations.push_back(drawdown_attestation);
-        e.storage().persistent().set(&atts_key, &attestations);
-        
-        // Recalculate compliance score (may call external contract)
-        metrics.compliance_score = Self::calculate_compliance_score(e.clone(), commitment_id.clone());
-        
-        // Update last attestation timestamp
-        metrics.last_attestation = e.ledger().timestamp();
-        
-        // Store updated health metrics
-        Self::store_health_metrics(&e, &metrics);
-        
-        // Clear reentrancy guard
-        e.storage().instance().set(&guard_key, &false);
-        
-        // Emit DrawdownRecorded event
-        e.events().publish(
-            (Symbol::new(&e, "DrawdownRecorded"), commitment_id),
-            (drawdown_percent, is_compliant, e.ledger().timestamp()),
-        );
+            .get(&key)
+            .ok_or(AttestationError::EnforcementNotPending)?;
+        if record.applied {
+            return Err(AttestationError::EnforcementNotPending);
+        }
+        if e.ledger().timestamp() < record.executable_at {
+            return Err(AttestationError::DisputeWindowOpen);
+        }
+        if e.storage().persistent().has(&DataKey::AttestationDispute(
+            commitment_id.clone(),
+            attestation_index,
+        )) {
+            return Err(AttestationError::DisputeAlreadyOpen);
+        }
 
-        Ok(())
+        Ok(Self::execute_enforcement_record(
+            &e,
+            &commitment_id,
+            attestation_index,
+            record,
+        ))
     }
 
-    /// Convert i128 to String (helper function)
-    fn i128_to_string(e: &Env, value: i128) -> String {
-        if value == 0 {
-            return String::from_str(e, "0");
+    /// Enforce a record and keep it (if applied) so a later revocation can undo it
+    fn execute_enforcement_record(
+        e: &Env,
+        commitment_id: &String,
+        attestation_index: u32,
+        mut record: EnforcementRecord,
+    ) -> bool {
+        let key = DataKey::Enforcement(commitment_id.clone(), attestation_index);
+        let applied = Self::enforce(e, commitment_id, record.action, &record.trigger);
+        if applied {
+            record.applied = true;
+            e.storage().persistent().set(&key, &record);
+        } else {
+            e.storage().persistent().remove(&key);
         }
+        applied
+    }
 
-        let mut n = value;
-        let is_negative = n < 0;
-        if is_negative {
-            n = -n;
+    /// Drop the enforcement triggered by a revoked attestation, undoing it in
+    /// commitment_core if it was already applied.
+    fn reverse_enforcement(e: &Env, commitment_id: &String, attestation_index: u32) {
+        let key = DataKey::Enforcement(commitment_id.clone(), attestation_index);
+        let record: EnforcementRecord = match e.storage().persistent().get(&key) {
+            Some(record) => record,
+            None => return,
+        };
+        e.storage().persistent().remove(&key);
+        if !record.applied {
+            return;
         }
 
-        let mut buf = [0u8; 64];
-        let mut i = 0;
+        let function = match record.action {
+            EnforcementAction::None => return,
+            EnforcementAction::FlagForReview => "clear_review_flag",
+            EnforcementAction::MarkViolated => "clear_violation",
+        };
+        let reversed = Self::call_core_enforcement(e, commitment_id, function, &record.trigger);
+        e.events().publish(
+            (Symbol::new(e, "EnforcementReversed"), commitment_id.clone()),
+            (record.action, reversed, e.ledger().timestamp()),
+        );
+    }
 
-        while n > 0 {
-            let digit = (n % 10) as u8 + b'0';
-            if i < 64 {
-                buf[i] = digit;
-                i += 1;
+    /// Run the enforcement policy for a newly stored attestation.
+    ///
+    /// The action is recorded as pending and applied once the dispute window
+    /// closes (immediately if the window is zero).
+    fn apply_enforcement_policy(e: &Env, attestation: &Attestation, attestation_index: u32) {
+        let policy = Self::get_enforcement_policy(e.clone());
+
+        let (action, trigger) = if attestation.attestation_type
+            == String::from_str(e, "violation")
+        {
+            (policy.on_violation, "violation")
+        } else if attestation.attestation_type == String::from_str(e, "drawdown") {
+            if policy.on_drawdown_breach == EnforcementAction::None {
+                return;
             }
-            n /= 10;
+            let drawdown = attestation
+                .data
+                .get(String::from_str(e, "drawdown_percent"))
+                .and_then(|v| Self::parse_i128_from_string(e, &v));
+            let max_loss = Self::fetch_commitment(e, &attestation.commitment_id)
+                .map(|c| c.rules.max_loss_percent as i128);
+            match (drawdown, max_loss) {
+                (Some(drawdown), Some(max_loss)) if drawdown > max_loss => {
+                    (policy.on_drawdown_breach, "drawdown_breach")
+                }
+                _ => return,
+            }
+        } else {
+            return;
+        };
+        if action == EnforcementAction::None {
+            return;
         }
 
-        if is_negative && i < 64 {
-            buf[i] = b'-';
-            i += 1;
-        }
+        let record = EnforcementRecord {
+            action,
+            trigger: String::from_str(e, trigger),
+            executable_at: e.ledger().timestamp().saturating_add(policy.dispute_window),
+            applied: false,
+        };
+        e.storage().persistent().set(
+            &DataKey::Enforcement(attestation.commitment_id.clone(), attestation_index),
+            &record,
+        );
+        e.events().publish(
+            (
+                Symbol::new(e, "EnforcementScheduled"),
+                attestation.commitment_id.clone(),
+            ),
+            (attestation_index, action, record.executable_at),
+        );
 
-        // Reverse buffer
-        let len = i;
-        let mut result_buf = [0u8; 64];
-        for j in 0..len {
-            result_buf[j] = buf[len - 1 - j];
+        if policy.dispute_window == 0 {
+            Self::execute_enforcement_record(
+                e,
+                &attestation.commitment_id,
+                attestation_index,
+                record,
+            );
         }
+    }
 
-        String::from_str(e, core::str::from_utf8(&result_buf[..len]).unwrap_or("0"))
+    /// Apply an enforcement action in commitment_core. Returns whether it succeeded.
+    fn enforce(
+        e: &Env,
+        commitment_id: &String,
+        action: EnforcementAction,
+        trigger: &String,
+    ) -> bool {
+        let function = match action {
+            EnforcementAction::None => return false,
+            EnforcementAction::FlagForReview => "flag_for_review",
+            EnforcementAction::MarkViolated => "mark_violated",
+        };
+        let applied = Self::call_core_enforcement(e, commitment_id, function, trigger);
+        e.events().publish(
+            (Symbol::new(e, "EnforcementAction"), commitment_id.clone()),
+            (action, applied, e.ledger().timestamp()),
+        );
+        applied
     }
 
-    /// Calculate compliance score (0-100)
-    ///
-    /// # Formal Verification
-    /// **Preconditions:**
-    /// - `commitment_id` exists
-    ///
-    /// **Postconditions:**
-    /// - Returns value in range [0, 100]
-    /// - Score decreases with violations
-    /// - Score decreases if drawdown exceeds threshold
-    /// - Pure function (no state changes)
+    /// Call an enforcer entrypoint on commitment_core and record the outcome in
+    /// the attestation log.
     ///
-    /// **Invariants Maintained:**
-    /// - Score always in valid range [0, 100]
-    ///
-    /// **Security Properties:**
-    /// - SP-4: State consistency (read-only)
-    /// - SP-3: Arithmetic safety
-    pub fn calculate_compliance_score(e: Env, commitment_id: String) -> u32 {
-        // First check if we have stored metrics with a compliance score
-        let metrics_key = DataKey::HealthMetrics(commitment_id.clone());
-        if let Some(stored_metrics) = e
-            .storage()
-            .persistent()
-            .get::<DataKey, HealthMetrics>(&metrics_key)
-        {
-            return stored_metrics.compliance_score;
+    /// A failed call (engine not registered as enforcer, commitment no longer
+    /// active) is recorded but does not revert the caller.
+    fn call_core_enforcement(
+        e: &Env,
+        commitment_id: &String,
+        function: &str,
+        trigger: &String,
+    ) -> bool {
+        let commitment_core: Address = match e.storage().instance().get(&DataKey::CoreContract) {
+            Some(addr) => addr,
+            None => return false,
+        };
+
+        let engine = e.current_contract_address();
+        let reason = trigger.clone();
+        let mut args = Vec::new(e);
+        args.push_back(engine.clone().into_val(e));
+        args.push_back(commitment_id.clone().into_val(e));
+        // clear_review_flag takes no reason
+        if function != "clear_review_flag" {
+            args.push_back(reason.clone().into_val(e));
         }
+        let applied = matches!(
+            e.try_invoke_contract::<(), soroban_sdk::Error>(
+                &commitment_core,
+                &Symbol::new(e, function),
+                args,
+            ),
+            Ok(Ok(()))
+        );
+
+        // Record the action in the attestation log; it does not count in analytics
+        let mut data = Map::new(e);
+        data.set(String::from_str(e, "action"), String::from_str(e, function));
+        data.set(String::from_str(e, "trigger"), reason);
+        data.set(
+            String::from_str(e, "result"),
+            String::from_str(e, if applied { "applied" } else { "failed" }),
+        );
+        Self::append_attestation(
+            e,
+            &Attestation {
+                commitment_id: commitment_id.clone(),
+                timestamp: e.ledger().timestamp(),
+                attestation_type: String::from_str(e, "enforcement"),
+                data,
+                is_compliant: false,
+                verified_by: engine,
+                revoked: false,
+            },
+        );
 
-        // Get commitment from core contract
-        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
+        applied
+    }
 
-        // Call get_commitment on commitment_core contract
-        // Using Symbol::new() for function name longer than 9 characters
-        let mut args = Vec::new(&e);
-        args.push_back(commitment_id.clone().into_val(&e));
-        let commitment_val: Val =
-            e.invoke_contract(&commitment_core, &Symbol::new(&e, "get_commitment"), args);
+    // ========================================================================
+    // Health-check Schedule
+    // ========================================================================
 
-        // Convert Val to Commitment
-        let commitment: Commitment = commitment_val.try_into_val(&e).unwrap();
+    /// Require health_checks every `interval` seconds for a commitment type
+    /// (admin only; 0 removes the requirement for new schedules).
+    pub fn set_health_check_cadence(
+        e: Env,
+        caller: Address,
+        commitment_type: String,
+        interval: u64,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        let key = DataKey::HealthCheckCadence(commitment_type.clone());
+        if interval == 0 {
+            e.storage().instance().remove(&key);
+        } else {
+            e.storage().instance().set(&key, &interval);
+        }
+        e.events().publish(
+            (Symbol::new(&e, "CadenceSet"), commitment_type),
+            (interval, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
 
-        // Get all attestations
-        let attestations = Self::get_attestations(e.clone(), commitment_id.clone());
+    /// Get the required health-check interval for a commitment type (0 = none)
+    pub fn get_health_check_cadence(e: Env, commitment_type: String) -> u64 {
+        e.storage()
+            .instance()
+            .get(&DataKey::HealthCheckCadence(commitment_type))
+            .unwrap_or(0)
+    }
+
+    /// Subscribe a commitment to health-check monitoring with its own interval.
+    ///
+    /// Admin may set any non-zero interval; the commitment owner may only
+    /// tighten the cadence configured for the commitment type.
+    pub fn set_commitment_cadence(
+        e: Env,
+        caller: Address,
+        commitment_id: String,
+        interval: u64,
+    ) -> Result<(), AttestationError> {
+        caller.require_auth();
+        if interval == 0 {
+            return Err(AttestationError::InvalidCadence);
+        }
 
-        // Base score: 100
-        let mut score: i32 = 100;
+        let commitment = Self::fetch_commitment(&e, &commitment_id)
+            .ok_or(AttestationError::CommitmentNotFound)?;
+        let admin: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(AttestationError::NotInitialized)?;
+        if caller != admin {
+            if caller != commitment.owner {
+                return Err(AttestationError::Unauthorized);
+            }
+            let type_interval =
+                Self::get_health_check_cadence(e.clone(), commitment.rules.commitment_type);
+            if type_interval > 0 && interval > type_interval {
+                return Err(AttestationError::InvalidCadence);
+            }
+        }
 
-        // Count violations: -20 per violation
-        let violation_count = attestations
-            .iter()
-            .filter(|att| {
-                !att.is_compliant || att.attestation_type == String::from_str(&e, "violation")
-            })
-            .count() as i32;
-        score = score
-            .checked_sub(violation_count.checked_mul(20).unwrap_or(0))
+        let key = DataKey::HealthCheckSchedule(commitment_id.clone());
+        let last_check_at = e
+            .storage()
+            .persistent()
+            .get::<DataKey, HealthCheckSchedule>(&key)
+            .map(|schedule| schedule.last_check_at)
             .unwrap_or(0);
+        let from = if last_check_at > 0 {
+            last_check_at
+        } else {
+            e.ledger().timestamp()
+        };
+        Self::write_health_check_schedule(
+            &e,
+            &commitment_id,
+            &HealthCheckSchedule {
+                interval,
+                next_due_at: from.saturating_add(interval),
+                last_check_at,
+            },
+        );
+        Ok(())
+    }
 
-        // Calculate drawdown vs threshold: -1 per % over threshold
-        let initial_value = commitment.amount;
-        let current_value = commitment.current_value;
-        let max_loss_percent = commitment.rules.max_loss_percent as i128;
+    /// Get a commitment's health-check schedule, if monitored
+    pub fn get_health_check_schedule(e: Env, commitment_id: String) -> Option<HealthCheckSchedule> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::HealthCheckSchedule(commitment_id))
+    }
 
-        if initial_value > 0 {
-            let drawdown_percent = {
-                let diff = initial_value.checked_sub(current_value).unwrap_or(0);
-                diff.checked_mul(100)
-                    .unwrap_or(0)
-                    .checked_div(initial_value)
-                    .unwrap_or(0)
-            };
+    /// Number of pages in the monitored-commitment index
+    pub fn get_monitored_page_count(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::MonitoredPageCount)
+            .unwrap_or(0)
+    }
 
-            if drawdown_percent > max_loss_percent {
-                let over_threshold = drawdown_percent.checked_sub(max_loss_percent).unwrap_or(0);
-                score = score.checked_sub(over_threshold as i32).unwrap_or(0);
+    /// List overdue commitments on one page of the monitored index
+    ///
+    /// Commitments that core no longer reports as active are dropped from
+    /// the index instead of being returned.
+    ///
+    /// # Arguments
+    /// * `page` - Index page to scan (see `get_monitored_page_count`)
+    /// * `limit` - Maximum number of commitment ids to return
+    pub fn get_overdue_commitments(e: Env, page: u32, limit: u32) -> Vec<String> {
+        let mut overdue = Vec::new(&e);
+        let monitored: Vec<String> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::MonitoredPage(page))
+            .unwrap_or_else(|| Vec::new(&e));
+        let active = String::from_str(&e, "active");
+        for commitment_id in monitored.iter() {
+            if overdue.len() >= limit {
+                break;
+            }
+            if !Self::is_health_check_overdue(&e, &commitment_id) {
+                continue;
+            }
+            match Self::fetch_commitment(&e, &commitment_id) {
+                Some(commitment) if commitment.status != active => {
+                    Self::unschedule_health_checks(&e, &commitment_id);
+                }
+                _ => overdue.push_back(commitment_id),
             }
         }
+        overdue
+    }
 
-        // Calculate fee generation vs expectations: +1 per % of expected fees
-        let min_fee_threshold = commitment.rules.min_fee_threshold;
-        // Get fees from health metrics (which sums from attestations)
-        // We'll calculate this from the attestations directly
-        let total_fees: i128 = 0;
-        let fee_key = String::from_str(&e, "fee_amount");
-
-        for att in attestations.iter() {
-            if att.attestation_type == String::from_str(&e, "fee_generation") {
-                // Extract fee from data map
-                // Since Map<String, String> stores strings, we need to parse
-                // For this implementation, we'll use a simplified approach:
-                // If fee_amount exists in data, we'll try to extract it
-                // In production, fees should be stored as i128 in a separate field
-                if let Some(_fee_str) = att.data.get(fee_key.clone()) {
-                    // Parse would be needed here - for now, we'll use 0
-                    // This is acceptable as fee tracking requires proper implementation
-                    // of the attest() function to store fees correctly
+    fn is_health_check_overdue(e: &Env, commitment_id: &String) -> bool {
+        e.storage()
+            .persistent()
+            .get::<DataKey, HealthCheckSchedule>(&DataKey::HealthCheckSchedule(
+                commitment_id.clone(),
+            ))
+            .map(|schedule| e.ledger().timestamp() > schedule.next_due_at)
+            .unwrap_or(false)
+    }
+
+    /// Start or advance a commitment's schedule after an attestation.
+    ///
+    /// Commitments are scheduled on their first attestation when their type
+    /// has a cadence; health_checks push `next_due_at` forward.
+    fn advance_health_check_schedule(e: &Env, attestation: &Attestation) {
+        let key = DataKey::HealthCheckSchedule(attestation.commitment_id.clone());
+        let is_health_check = attestation.attestation_type == String::from_str(e, "health_check");
+        let mut schedule = match e.storage().persistent().get::<DataKey, HealthCheckSchedule>(&key) {
+            Some(schedule) => schedule,
+            None => {
+                // First attestation: schedule if the commitment type requires it
+                if e.storage().persistent().has(&DataKey::AttestationSummary(
+                    attestation.commitment_id.clone(),
+                )) {
+                    return;
+                }
+                let interval = match Self::fetch_commitment(e, &attestation.commitment_id) {
+                    Some(commitment) => {
+                        Self::get_health_check_cadence(e.clone(), commitment.rules.commitment_type)
+                    }
+                    None => 0,
+                };
+                if interval == 0 {
+                    return;
+                }
+                HealthCheckSchedule {
+                    interval,
+                    next_due_at: attestation.timestamp.saturating_add(interval),
+                    last_check_at: 0,
                 }
             }
+        };
+        if is_health_check {
+            schedule.last_check_at = attestation.timestamp;
+            schedule.next_due_at = attestation.timestamp.saturating_add(schedule.interval);
         }
+        Self::write_health_check_schedule(e, &attestation.commitment_id, &schedule);
+    }
 
-        // Only add fee bonus if we have fees and a threshold
-        if min_fee_threshold > 0 && total_fees > 0 {
-            let fee_percent = total_fees
-                .checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(min_fee_threshold)
-                .unwrap_or(0);
-            // Cap the bonus to prevent excessive score inflation
-            let bonus = if fee_percent > 100 { 100 } else { fee_percent };
-            score = score.checked_add(bonus as i32).unwrap_or(100);
+    fn write_health_check_schedule(e: &Env, commitment_id: &String, schedule: &HealthCheckSchedule) {
+        let key = DataKey::HealthCheckSchedule(commitment_id.clone());
+        if !e.storage().persistent().has(&key) {
+            Self::index_monitored_commitment(e, commitment_id);
         }
+        e.storage().persistent().set(&key, schedule);
+        e.storage().persistent().extend_ttl(
+            &key,
+            ATTESTATION_TTL_THRESHOLD,
+            ATTESTATION_TTL_EXTEND_TO,
+        );
+        e.events().publish(
+            (Symbol::new(e, "HealthCheckScheduled"), commitment_id.clone()),
+            (schedule.interval, schedule.next_due_at),
+        );
+    }
 
-        // Duration adherence: +10 if on track
-        let current_time = e.ledger().timestamp();
-        let expires_at = commitment.expires_at;
-        let created_at = commitment.created_at;
-
-        if expires_at > created_at {
-            let total_duration = expires_at.checked_sub(created_at).unwrap_or(1);
-            let elapsed = current_time.checked_sub(created_at).unwrap_or(0);
+    /// Append a commitment to the last monitored-index page, opening a new
+    /// page when it is full.
+    fn index_monitored_commitment(e: &Env, commitment_id: &String) {
+        let mut page_count: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::MonitoredPageCount)
+            .unwrap_or(0);
+        let mut page = page_count.saturating_sub(1);
+        let mut monitored: Vec<String> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::MonitoredPage(page))
+            .unwrap_or_else(|| Vec::new(e));
+        if page_count == 0 || monitored.len() >= MONITORED_PAGE_SIZE {
+            page = page_count;
+            page_count += 1;
+            monitored = Vec::new(e);
+            e.storage()
+                .instance()
+                .set(&DataKey::MonitoredPageCount, &page_count);
+        }
+        monitored.push_back(commitment_id.clone());
+        Self::write_monitored_page(e, page, &monitored);
+
+        let slot_key = DataKey::MonitoredSlot(commitment_id.clone());
+        e.storage().persistent().set(&slot_key, &page);
+        e.storage().persistent().extend_ttl(
+            &slot_key,
+            ATTESTATION_TTL_THRESHOLD,
+            ATTESTATION_TTL_EXTEND_TO,
+        );
+    }
 
-            // Check if we're on track (not too far behind or ahead)
-            // Simplified: if elapsed is within reasonable bounds of expected progress
-            let expected_progress = (elapsed as u128)
-                .checked_mul(100)
-                .unwrap_or(0)
-                .checked_div(total_duration as u128)
-                .unwrap_or(0);
+    fn write_monitored_page(e: &Env, page: u32, monitored: &Vec<String>) {
+        let key = DataKey::MonitoredPage(page);
+        e.storage().persistent().set(&key, monitored);
+        e.storage().persistent().extend_ttl(
+            &key,
+            ATTESTATION_TTL_THRESHOLD,
+            ATTESTATION_TTL_EXTEND_TO,
+        );
+    }
 
-            // Consider "on track" if between 0-100% of expected time
-            if expected_progress <= 100 {
-                score = score.checked_add(10).unwrap_or(100);
-            }
+    fn unschedule_health_checks(e: &Env, commitment_id: &String) {
+        e.storage()
+            .persistent()
+            .remove(&DataKey::HealthCheckSchedule(commitment_id.clone()));
+        let slot_key = DataKey::MonitoredSlot(commitment_id.clone());
+        let page = match e.storage().persistent().get::<DataKey, u32>(&slot_key) {
+            Some(page) => page,
+            None => return,
+        };
+        e.storage().persistent().remove(&slot_key);
+        let mut monitored: Vec<String> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::MonitoredPage(page))
+            .unwrap_or_else(|| Vec::new(e));
+        if let Some(index) = monitored.first_index_of(commitment_id) {
+            monitored.remove(index);
+            Self::write_monitored_page(e, page, &monitored);
         }
+    }
 
-        // Clamp between 0 and 100
-        if score < 0 {
-            score = 0;
-        } else if score > 100 {
-            score = 100;
-        }
+    // ========================================================================
+    // Scoring Model
+    // ========================================================================
 
-        // Emit compliance score update event
+    /// Configure the compliance scoring model (admin only).
+    ///
+    /// Stored scores are not re-computed; the new model applies from the next
+    /// attestation (or immediately in `calculate_compliance_score` when decay is on).
+    pub fn set_scoring_config(
+        e: Env,
+        caller: Address,
+        config: ScoringConfig,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if config.violation_penalty > 100
+            || config.non_compliant_penalty > 100
+            || config.compliant_bonus > 100
+            || config.on_track_bonus > 100
+            || config.volatility_penalty > 100
+            || config.drawdown_penalty > 100
+            || config.max_drawdown_bps > BPS_MAX
+            || config.overdue_penalty > 100
+            || config.severity_high_bps > MAX_SEVERITY_BPS
+            || config.severity_medium_bps > MAX_SEVERITY_BPS
+            || config.severity_low_bps > MAX_SEVERITY_BPS
+            || config.health_check_weight_bps > MAX_SEVERITY_BPS
+            || config.fee_generation_weight_bps > MAX_SEVERITY_BPS
+            || config.drawdown_weight_bps > MAX_SEVERITY_BPS
+            || config.history_limit > MAX_SCORE_HISTORY
+        {
+            return Err(AttestationError::InvalidScoringConfig);
+        }
+        e.storage().instance().set(&DataKey::ScoringConfig, &config);
         e.events().publish(
-            (symbol_short!("ScoreUpd"), commitment_id),
-            (score as u32, e.ledger().timestamp()),
+            (Symbol::new(&e, "ScoringConfigSet"), caller),
+            (
+                config.violation_penalty,
+                config.decay_half_life,
+                config.history_limit,
+            ),
         );
+        Ok(())
+    }
 
-        score as u32
+    /// Get the active scoring model (defaults if never configured)
+    pub fn get_scoring_config(e: Env) -> ScoringConfig {
+        Self::scoring_config(&e)
     }
 
-    /// Get high-level protocol analytics combining commitment and attestation data.
+    /// Get recent compliance score snapshots, oldest first.
     ///
-    /// Returns:
-    /// - total_commitments (from core contract)
-    /// - total_attestations
-    /// - total_violations
-    /// - total_fees_generated
-    pub fn get_protocol_statistics(e: Env) -> (u64, u64, u64, i128) {
-        // Read commitment_core statistics
-        let commitment_core: Address = e.storage().instance().get(&DataKey::CoreContract).unwrap();
+    /// # Arguments
+    /// * `commitment_id` - The commitment
+    /// * `limit` - Maximum number of most recent snapshots (0 = all kept)
+    pub fn get_score_history(e: Env, commitment_id: String, limit: u32) -> Vec<ScoreSnapshot> {
+        let history: Vec<ScoreSnapshot> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::ScoreHistory(commitment_id))
+            .unwrap_or_else(|| Vec::new(&e));
+        if limit == 0 || limit >= history.len() {
+            return history;
+        }
+        history.slice(history.len() - limit..)
+    }
 
-        // get_total_commitments() on core contract
-        let args = Vec::new(&e);
-        let total_commitments_val: Val = e.invoke_contract(
-            &commitment_core,
-            &Symbol::new(&e, "get_total_commitments"),
-            args,
+    // ========================================================================
+    // Signed (Off-chain) Attestations
+    // ========================================================================
+
+    /// Register the ed25519 public key used to sign attestations off-chain.
+    ///
+    /// # Arguments
+    /// * `verifier` - Authorized verifier (must sign)
+    /// * `public_key` - ed25519 public key; replaces any previously registered key
+    pub fn register_signing_key(
+        e: Env,
+        verifier: Address,
+        public_key: BytesN<32>,
+    ) -> Result<(), AttestationError> {
+        verifier.require_auth();
+        if !Self::is_authorized_verifier(&e, &verifier) {
+            return Err(AttestationError::Unauthorized);
+        }
+
+        e.storage()
+            .persistent()
+            .set(&DataKey::VerifierSigningKey(verifier.clone()), &public_key);
+
+        e.events().publish(
+            (Symbol::new(&e, "SigningKeyRegistered"), verifier),
+            (public_key, e.ledger().timestamp()),
         );
-        let total_commitments: u64 = total_commitments_val.try_into_val(&e).unwrap();
+        Ok(())
+    }
 
-        let total_attestations: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TotalAttestations)
-            .unwrap_or(0);
-        let total_violations: u64 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TotalViolations)
-            .unwrap_or(0);
-        let total_fees: i128 = e.storage().instance().get(&DataKey::TotalFees).unwrap_or(0);
+    /// Get a verifier's registered signing key
+    pub fn get_signing_key(e: Env, verifier: Address) -> Option<BytesN<32>> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::VerifierSigningKey(verifier))
+    }
 
-        (
-            total_commitments,
-            total_attestations,
-            total_violations,
-            total_fees,
-        )
+    /// Get the nonce the next signed attestation from `verifier` must use
+    pub fn get_verifier_nonce(e: Env, verifier: Address) -> u64 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::VerifierNonce(verifier))
+            .unwrap_or(0)
     }
 
-    /// Get analytics for a given verifier (attestation recorder).
+    /// Build the bytes a verifier signs for an off-chain attestation.
     ///
-    /// Returns the total number of attestations recorded by this verifier.
-    pub fn get_verifier_statistics(e: Env, verifier: Address) -> u64 {
-        let key = DataKey::VerifierAttestationCount(verifier);
-        e.storage().instance().get(&key).unwrap_or(0)
+    /// XDR encoding of `(engine_contract, verifier, params, nonce)`; binding the
+    /// contract address prevents signatures being replayed on another deployment.
+    pub fn get_signing_payload(e: Env, verifier: Address, params: AttestParams, nonce: u64) -> Bytes {
+        Self::signing_payload(&e, &verifier, &params, nonce)
     }
 
-    // ========================================================================
-    // Batch Operations
-    // ========================================================================
-
-    /// Batch attest multiple commitments in a single transaction
+    /// Record attestations signed off-chain by verifiers.
+    ///
+    /// Anyone may relay; each entry is checked against its verifier's registered
+    /// key and must carry the verifier's next nonce (nonces are consumed in
+    /// order, so a relayer cannot skip ahead), then recorded exactly as
+    /// `attest` would record it. The relayer pays any configured attestation
+    /// fee. The call is atomic: one invalid entry rejects the whole submission.
     ///
     /// # Arguments
-    /// * `caller` - The address recording the attestations (must be authorized verifier)
-    /// * `params_list` - Vector of AttestParams for each attestation
-    /// * `mode` - BatchMode::Atomic or BatchMode::BestEffort
+    /// * `relayer` - Address submitting the batch (must sign)
+    /// * `attestations` - Signed attestations to record
     ///
     /// # Returns
-    /// BatchResult with empty results and any errors
+    /// Number of attestations recorded
     ///
-    /// # Gas Optimization
-    /// - Batch read of analytics counters
-    /// - Single aggregate counter update at end
-    /// - Batch health metrics updates
-    pub fn batch_attest(
+    /// # Panics
+    /// If a signature does not verify against the verifier's registered key.
+    /// This is the one invalid entry that traps instead of returning an
+    /// `AttestationError`, since the host's ed25519 check has no fallible form.
+    /// The other checks run first, so an unauthorized verifier, missing key,
+    /// wrong nonce or invalid params are still reported as errors.
+    pub fn submit_signed_attestations(
         e: Env,
-        caller: Address,
-        params_list: Vec<AttestParams>,
-        mode: BatchMode,
-    ) -> BatchResultVoid {
+        relayer: Address,
+        attestations: Vec<SignedAttestation>,
+    ) -> Result<u32, AttestationError> {
         // Reentrancy protection
         if e.storage().instance().has(&DataKey::ReentrancyGuard) {
             panic!("Reentrancy detected");
         }
         e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
 
-        // Verify caller signed the transaction
-        caller.require_auth();
+        relayer.require_auth();
 
-        // Check caller is authorized verifier
-        if !Self::is_authorized_verifier(&e, &caller) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            let mut errors = Vec::new(&e);
-            errors.push_back(BatchError {
-                index: 0,
-                error_code: AttestationError::Unauthorized as u32,
-                context: String::from_str(&e, "not_authorized_verifier"),
-            });
-            return BatchResultVoid::failure(&e, errors);
+        let mut recorded: u32 = 0;
+        for signed in attestations.iter() {
+            if let Err(err) = Self::record_signed_attestation(&e, &relayer, &signed) {
+                e.storage().instance().remove(&DataKey::ReentrancyGuard);
+                return Err(err);
+            }
+            recorded += 1;
+        }
+
+        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+
+        e.events().publish(
+            (Symbol::new(&e, "SignedAttestationsSubmitted"), relayer),
+            (recorded, e.ledger().timestamp()),
+        );
+
+        Ok(recorded)
+    }
+
+    fn signing_payload(e: &Env, verifier: &Address, params: &AttestParams, nonce: u64) -> Bytes {
+        (
+            e.current_contract_address(),
+            verifier.clone(),
+            params.clone(),
+            nonce,
+        )
+            .to_xdr(e)
+    }
+
+    /// Verify one signed attestation, consume its nonce and record it
+    fn record_signed_attestation(
+        e: &Env,
+        relayer: &Address,
+        signed: &SignedAttestation,
+    ) -> Result<(), AttestationError> {
+        if !Self::is_authorized_verifier(e, &signed.verifier) {
+            return Err(AttestationError::Unauthorized);
         }
 
-        // Validate batch size
-        let batch_size = params_list.len();
-        let contract_name = String::from_str(&e, "attestation_engine");
-        if let Err(error_code) = BatchProcessor::enforce_batch_limits(&e, batch_size, Some(contract_name)) {
-            e.storage().instance().remove(&DataKey::ReentrancyGuard);
-            let mut errors = Vec::new(&e);
-            errors.push_back(BatchError {
-                index: 0,
-                error_code,
-                context: String::from_str(&e, "batch_size_validation"),
-            });
-            return BatchResultVoid::failure(&e, errors);
+        let public_key: BytesN<32> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::VerifierSigningKey(signed.verifier.clone()))
+            .ok_or(AttestationError::SigningKeyNotRegistered)?;
+
+        let nonce_key = DataKey::VerifierNonce(signed.verifier.clone());
+        let next_nonce: u64 = e.storage().persistent().get(&nonce_key).unwrap_or(0);
+        if signed.nonce != next_nonce {
+            return Err(AttestationError::InvalidNonce);
         }
+        let following_nonce = next_nonce
+            .checked_add(1)
+            .ok_or(AttestationError::NonceOverflow)?;
 
-        let mut errors = Vec::new(&e);
-        let mut results = Vec::new(&e);
+        Self::validate_attest_params(e, &signed.params)?;
 
-        // Read analytics counters once (optimization)
-        let (mut total_attestations, mut total_violations, mut verifier_count) = {
-            let total_att = e.storage().instance().get(&DataKey::TotalAttestations).unwrap_or(0u64);
-            let total_viol = e.storage().instance().get(&DataKey::TotalViolations).unwrap_or(0u64);
-            let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
-            let ver_count = e.storage().instance().get(&verifier_key).unwrap_or(0u64);
-            (total_att, total_viol, ver_count)
-        };
+        let payload = Self::signing_payload(e, &signed.verifier, &signed.params, signed.nonce);
+        e.crypto()
+            .ed25519_verify(&public_key, &payload, &signed.signature);
 
-        let timestamp = e.ledger().timestamp();
-        let violation_type = String::from_str(&e, "violation");
+        e.storage().persistent().set(&nonce_key, &following_nonce);
 
-        // Process each attestation
-        for i in 0..batch_size {
-            let params = params_list.get(i).unwrap();
+        // Same per-verifier rate limit as direct attestations
+        RateLimiter::check(e, &signed.verifier, &Symbol::new(e, "attest"));
 
-            // Validate commitment_id
-            if params.commitment_id.len() == 0 {
-                if mode == BatchMode::Atomic {
-                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidCommitmentId as u32,
-                        context: String::from_str(&e, "empty_commitment_id"),
-                    });
-                    return BatchResultVoid::failure(&e, errors);
-                } else {
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidCommitmentId as u32,
-                        context: String::from_str(&e, "empty_commitment_id"),
-                    });
-                    continue;
-                }
-            }
+        Self::collect_attestation_fee(
+            e,
+            relayer,
+            &signed.verifier,
+            &signed.params.attestation_type,
+        );
 
-            // Validate commitment exists
-            if !Self::commitment_exists(&e, &params.commitment_id) {
-                if mode == BatchMode::Atomic {
-                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::CommitmentNotFound as u32,
-                        context: String::from_str(&e, "commitment_not_found"),
-                    });
-                    return BatchResultVoid::failure(&e, errors);
-                } else {
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::CommitmentNotFound as u32,
-                        context: String::from_str(&e, "commitment_not_found"),
-                    });
-                    continue;
-                }
-            }
+        Self::store_attestation(
+            e,
+            Attestation {
+                commitment_id: signed.params.commitment_id.clone(),
+                timestamp: e.ledger().timestamp(),
+                attestation_type: signed.params.attestation_type.clone(),
+                data: signed.params.data.clone(),
+                is_compliant: signed.params.is_compliant,
+                verified_by: signed.verifier.clone(),
+                revoked: false,
+            },
+        );
 
-            // Validate attestation type
-            if !Self::is_valid_attestation_type(&e, &params.attestation_type) {
-                if mode == BatchMode::Atomic {
-                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidAttestationType as u32,
-                        context: String::from_str(&e, "invalid_type"),
-                    });
-                    return BatchResultVoid::failure(&e, errors);
-                } else {
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidAttestationType as u32,
-                        context: String::from_str(&e, "invalid_type"),
-                    });
-                    continue;
-                }
-            }
+        Ok(())
+    }
 
-            // Validate data format
-            if !Self::validate_attestation_data(&e, &params.attestation_type, &params.data) {
-                if mode == BatchMode::Atomic {
-                    e.storage().instance().remove(&DataKey::ReentrancyGuard);
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidAttestationData as u32,
-                        context: String::from_str(&e, "invalid_data"),
-                    });
-                    return BatchResultVoid::failure(&e, errors);
-                } else {
-                    errors.push_back(BatchError {
-                        index: i,
-                        error_code: AttestationError::InvalidAttestationData as u32,
-                        context: String::from_str(&e, "invalid_data"),
-                    });
-                    continue;
+    /// Validate attestation parameters the same way `attest` does
+    fn validate_attest_params(e: &Env, params: &AttestParams) -> Result<(), AttestationError> {
+        if params.commitment_id.is_empty() {
+            return Err(AttestationError::InvalidCommitmentId);
+        }
+        if !Self::commitment_exists(e, &params.commitment_id) {
+            return Err(AttestationError::CommitmentNotFound);
+        }
+        if !Self::is_valid_attestation_type(e, &params.attestation_type) {
+            return Err(AttestationError::InvalidAttestationType);
+        }
+        if !Self::validate_attestation_data(e, &params.attestation_type, &params.data) {
+            return Err(AttestationError::InvalidAttestationData);
+        }
+        Ok(())
+    }
+
+    /// Charge the configured attestation fee to `payer`
+    ///
+    /// The verifier's configured share accrues to its claimable rewards; the
+    /// rest goes to the protocol's `CollectedFees`.
+    fn collect_attestation_fee(
+        e: &Env,
+        payer: &Address,
+        verifier: &Address,
+        attestation_type: &String,
+    ) {
+        let fee_amount = Self::get_attestation_fee_for(e.clone(), attestation_type.clone());
+        if fee_amount > 0 {
+            if let Some(fee_asset) = e
+                .storage()
+                .instance()
+                .get::<DataKey, Address>(&DataKey::AttestationFeeAsset)
+            {
+                let token_client = token::Client::new(e, &fee_asset);
+                token_client.transfer(payer, &e.current_contract_address(), &fee_amount);
+
+                let share_bps: u32 = e
+                    .storage()
+                    .instance()
+                    .get(&DataKey::VerifierFeeShareBps)
+                    .unwrap_or(0);
+                let verifier_share = fee_from_bps(fee_amount, share_bps);
+                if verifier_share > 0 {
+                    let rewards_key = DataKey::VerifierRewards(verifier.clone(), fee_asset.clone());
+                    let rewards: i128 = e.storage().persistent().get(&rewards_key).unwrap_or(0);
+                    e.storage()
+                        .persistent()
+                        .set(&rewards_key, &(rewards + verifier_share));
                 }
-            }
 
-            // Create attestation record
-            let attestation = Attestation {
-                commitment_id: params.commitment_id.clone(),
-                attestation_type: params.attestation_type.clone(),
-                data: params.data.clone(),
-                timestamp,
-                verified_by: caller.clone(),
-                is_compliant: params.is_compliant,
-            };
+                let key = DataKey::CollectedFees(fee_asset);
+                let current: i128 = e.storage().instance().get(&key).unwrap_or(0);
+                e.storage()
+                    .instance()
+                    .set(&key, &(current + fee_amount - verifier_share));
+            }
+        }
+    }
 
-            // Store attestation
-            let key = DataKey::Attestations(params.commitment_id.clone());
-            let mut attestations: Vec<Attestation> = e.storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or_else(|| Vec::new(&e));
-            attestations.push_back(attestation.clone());
-            e.storage().persistent().set(&key, &attestations);
+    /// Persist a validated attestation and update metrics, counters and analytics
+    fn store_attestation(e: &Env, attestation: Attestation) {
+        let commitment_id = attestation.commitment_id.clone();
 
-            // Update health metrics
-            Self::update_health_metrics(&e, &params.commitment_id, &attestation);
+        let index = Self::append_attestation(e, &attestation);
+        Self::record_verifier_activity(e, &attestation);
+        Self::advance_health_check_schedule(e, &attestation);
 
-            // Increment attestation counter
-            let counter_key = DataKey::AttestationCounter(params.commitment_id.clone());
-            let counter: u64 = e.storage()
-                .persistent()
-                .get(&counter_key)
-                .unwrap_or(0);
-            e.storage().persistent().set(&counter_key, &(counter + 1));
+        Self::update_health_metrics(e, &commitment_id, &attestation, index);
 
-            // Update analytics counters (in memory)
-            total_attestations += 1;
-            verifier_count += 1;
-            if attestation.attestation_type == violation_type || !attestation.is_compliant {
-                total_violations += 1;
-            }
+        let counter_key = DataKey::AttestationCounter(commitment_id.clone());
+        let counter: u64 = e.storage().persistent().get(&counter_key).unwrap_or(0);
+        e.storage().persistent().set(&counter_key, &(counter + 1));
 
-            results.push_back(());
+        let total_attestations: u64 = e
+            .storage()
+            .instance()
+            .get(&DataKey::TotalAttestations)
+            .unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&DataKey::TotalAttestations, &(total_attestations + 1));
 
-            // Emit event
-            e.events().publish(
-                (Symbol::new(&e, "AttestationRecorded"), params.commitment_id.clone(), caller.clone()),
-                (params.attestation_type.clone(), params.is_compliant, timestamp)
-            );
+        let violation_type = String::from_str(e, "violation");
+        if attestation.attestation_type == violation_type || !attestation.is_compliant {
+            let total_violations: u64 = e
+                .storage()
+                .instance()
+                .get(&DataKey::TotalViolations)
+                .unwrap_or(0);
+            e.storage()
+                .instance()
+                .set(&DataKey::TotalViolations, &(total_violations + 1));
         }
 
-        // Write analytics counters once (optimization)
-        e.storage().instance().set(&DataKey::TotalAttestations, &total_attestations);
-        e.storage().instance().set(&DataKey::TotalViolations, &total_violations);
-        let verifier_key = DataKey::VerifierAttestationCount(caller.clone());
-        e.storage().instance().set(&verifier_key, &verifier_count);
-
-        // Clear reentrancy guard
-        e.storage().instance().remove(&DataKey::ReentrancyGuard);
+        let verifier_key = DataKey::VerifierAttestationCount(attestation.verified_by.clone());
+        let verifier_count: u64 = e.storage().instance().get(&verifier_key).unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&verifier_key, &(verifier_count + 1));
 
-        // Emit batch event
         e.events().publish(
-            (Symbol::new(&e, "BatchAttest"), batch_size),
-            (results.len(), errors.len(), timestamp)
+            (
+                Symbol::new(e, "AttestationRecorded"),
+                commitment_id,
+                attestation.verified_by.clone(),
+            ),
+            (
+                attestation.attestation_type.clone(),
+                attestation.is_compliant,
+                attestation.timestamp,
+            ),
         );
 
-        BatchResultVoid::partial(results.len(), errors)
+        Self::apply_enforcement_policy(e, &attestation, index);
     }
 
     /// Configure rate limits for this contract's functions (e.g. `attest`).
@@ -1736,6 +4143,108 @@ impl AttestationEngineContract {
         (amount, asset)
     }
 
+    /// Set the share of each attestation fee credited to the submitting verifier (admin only)
+    pub fn set_verifier_fee_share(
+        e: Env,
+        caller: Address,
+        share_bps: u32,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if share_bps > BPS_MAX {
+            return Err(AttestationError::InvalidBps);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::VerifierFeeShareBps, &share_bps);
+        e.events().publish(
+            (Symbol::new(&e, "VerifierFeeShareSet"), caller),
+            (share_bps, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the verifier fee share in basis points
+    pub fn get_verifier_fee_share(e: Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&DataKey::VerifierFeeShareBps)
+            .unwrap_or(0)
+    }
+
+    /// Override the attestation fee for one attestation kind (admin only).
+    ///
+    /// Uses the asset from `set_attestation_fee`. `None` removes the override.
+    pub fn set_attestation_fee_tier(
+        e: Env,
+        caller: Address,
+        attestation_type: String,
+        amount: Option<i128>,
+    ) -> Result<(), AttestationError> {
+        require_admin(&e, &caller)?;
+        if !Self::is_valid_attestation_type(&e, &attestation_type) {
+            return Err(AttestationError::InvalidAttestationType);
+        }
+        let key = DataKey::AttestationFeeTier(attestation_type.clone());
+        match amount {
+            Some(amount) if amount < 0 => return Err(AttestationError::InvalidFeeAmount),
+            Some(amount) => e.storage().instance().set(&key, &amount),
+            None => e.storage().instance().remove(&key),
+        }
+        e.events().publish(
+            (Symbol::new(&e, "AttestationFeeTierSet"), attestation_type),
+            (amount, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the attestation fee charged for an attestation kind
+    pub fn get_attestation_fee_for(e: Env, attestation_type: String) -> i128 {
+        e.storage()
+            .instance()
+            .get(&DataKey::AttestationFeeTier(attestation_type))
+            .unwrap_or_else(|| {
+                e.storage()
+                    .instance()
+                    .get(&DataKey::AttestationFeeAmount)
+                    .unwrap_or(0)
+            })
+    }
+
+    /// Get a verifier's claimable fee share for an asset
+    pub fn get_verifier_rewards(e: Env, verifier: Address, asset_address: Address) -> i128 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::VerifierRewards(verifier, asset_address))
+            .unwrap_or(0)
+    }
+
+    /// Transfer a verifier's accrued fee share to the verifier.
+    ///
+    /// # Returns
+    /// Amount claimed
+    pub fn claim_verifier_rewards(
+        e: Env,
+        verifier: Address,
+        asset_address: Address,
+    ) -> Result<i128, AttestationError> {
+        verifier.require_auth();
+        let key = DataKey::VerifierRewards(verifier.clone(), asset_address.clone());
+        let amount: i128 = e.storage().persistent().get(&key).unwrap_or(0);
+        if amount <= 0 {
+            return Err(AttestationError::InsufficientFees);
+        }
+        e.storage().persistent().remove(&key);
+
+        let token_client = token::Client::new(&e, &asset_address);
+        token_client.transfer(&e.current_contract_address(), &verifier, &amount);
+
+        e.events().publish(
+            (Symbol::new(&e, "VerifierRewardsClaimed"), verifier),
+            (asset_address, amount, e.ledger().timestamp()),
+        );
+        Ok(amount)
+    }
+
     /// Get fee recipient. None if not set.
     pub fn get_fee_recipient(e: Env) -> Option<Address> {
         e.storage().instance().get(&DataKey::FeeRecipient)
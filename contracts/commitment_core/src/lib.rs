@@ -1,11 +1,4 @@
 #![no_std]
-use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, Address, Env, String, Vec, Map,
-    Val, BytesN, IntoVal,
-};
-use soroban_sdk::storage::Storage;
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String};
-use soroban_sdk::{contract, contractimpl, contracttype, Address, Env, String, symbol_short, Symbol};
 
 use shared_utils::{
     emit_error_event, fee_from_bps, BPS_MAX, EmergencyControl, RateLimiter, SafeMath, TimeUtils,
@@ -99,6 +92,16 @@ pub struct CommitmentRules {
     pub grace_period_days: u32,
 }
 
+/// Lifecycle states understood by commitment_nft::set_lifecycle_state
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NftLifecycleState {
+    Active,
+    Settled,
+    EarlyExited,
+    Violated,
+}
+
 /// Metadata for a supported asset (symbol, decimals).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -159,145 +162,6 @@ pub struct AllocationTracking {
 // Storage Data Keys
 #[contracttype]
 #[derive(Clone)]
-pub enum DataKey {
-    Admin,
-    AuthorizedAllocator(Address),
-    Commitment(String),
-    CommitmentBalance(String),
-    AllocationTracking(String),
-    InitFlag,
-}
-
-// Error helper functions using panic with error codes
-fn panic_unauthorized() -> ! {
-    panic!("Unauthorized: caller is not an authorized allocation contract");
-}
-
-fn panic_insufficient_balance() -> ! {
-    panic!("InsufficientBalance: commitment does not have enough balance");
-}
-
-fn panic_inactive_commitment() -> ! {
-    panic!("InactiveCommitment: commitment is not active or does not exist");
-}
-
-fn panic_transfer_failed() -> ! {
-    panic!("TransferFailed: asset transfer failed");
-}
-
-fn panic_already_initialized() -> ! {
-    panic!("AlreadyInitialized: contract is already initialized");
-}
-
-fn panic_invalid_amount() -> ! {
-    panic!("InvalidAmount: amount must be greater than zero");
-}
-
-// Helper functions for storage operations
-fn has_admin(e: &Env) -> bool {
-    let key = DataKey::Admin;
-    e.storage().instance().has(&key)
-}
-
-fn get_admin(e: &Env) -> Address {
-    let key = DataKey::Admin;
-    e.storage().instance().get(&key).unwrap()
-}
-
-fn set_admin(e: &Env, admin: &Address) {
-    let key = DataKey::Admin;
-    e.storage().instance().set(&key, admin);
-}
-
-fn is_authorized_allocator(e: &Env, allocator: &Address) -> bool {
-    let key = DataKey::AuthorizedAllocator(allocator.clone());
-    if e.storage().instance().has(&key) {
-        e.storage().instance().get::<DataKey, bool>(&key).unwrap_or(false)
-    } else {
-        false
-    }
-}
-
-fn set_authorized_allocator(e: &Env, allocator: &Address, authorized: bool) {
-    let key = DataKey::AuthorizedAllocator(allocator.clone());
-    e.storage().instance().set(&key, &authorized);
-}
-
-fn get_commitment(e: &Env, commitment_id: &String) -> Option<Commitment> {
-    let key = DataKey::Commitment(commitment_id.clone());
-    e.storage().persistent().get(&key)
-}
-
-fn set_commitment(e: &Env, commitment: &Commitment) {
-    let key = DataKey::Commitment(commitment.commitment_id.clone());
-    e.storage().persistent().set(&key, commitment);
-}
-
-fn get_commitment_balance(e: &Env, commitment_id: &String) -> i128 {
-    let key = DataKey::CommitmentBalance(commitment_id.clone());
-    e.storage().persistent().get(&key).unwrap_or(0)
-}
-
-fn set_commitment_balance(e: &Env, commitment_id: &String, balance: i128) {
-    let key = DataKey::CommitmentBalance(commitment_id.clone());
-    e.storage().persistent().set(&key, &balance);
-}
-
-fn get_allocation_tracking(e: &Env, commitment_id: &String) -> AllocationTracking {
-    let key = DataKey::AllocationTracking(commitment_id.clone());
-    e.storage().persistent().get(&key).unwrap_or(AllocationTracking {
-        total_allocated: 0,
-        allocations: Vec::new(&e),
-    })
-}
-
-fn set_allocation_tracking(e: &Env, commitment_id: &String, tracking: &AllocationTracking) {
-    let key = DataKey::AllocationTracking(commitment_id.clone());
-    e.storage().persistent().set(&key, tracking);
-}
-
-fn is_initialized(e: &Env) -> bool {
-    let key = DataKey::InitFlag;
-    if e.storage().instance().has(&key) {
-        e.storage().instance().get::<DataKey, bool>(&key).unwrap_or(false)
-    } else {
-        false
-    }
-}
-
-fn set_initialized(e: &Env) {
-    let key = DataKey::InitFlag;
-    e.storage().instance().set(&key, &true);
-}
-
-// Asset transfer helper function using Stellar asset contract
-fn transfer_asset(e: &Env, asset: &Address, from: &Address, to: &Address, amount: i128) {
-    if amount <= 0 {
-        panic_invalid_amount();
-    }
-
-    // Call the asset contract's transfer function
-    // The asset contract should have a transfer function with signature:
-    // transfer(from: Address, to: Address, amount: i128)
-    // Using invoke_contract to call the asset contract's transfer function
-    let transfer_symbol = symbol_short!("transfer");
-    
-    // Invoke the contract's transfer function
-    // Note: This assumes the asset contract follows the standard token interface
-    let _: () = e.invoke_contract(
-        asset,
-        &transfer_symbol,
-        soroban_sdk::vec![e, from.clone().into_val(e), to.clone().into_val(e), amount.into_val(e)],
-    );
-}
-
-#[contract]
-pub struct CommitmentCoreContract;
-
-// Storage keys - using Symbol for efficient storage (max 9 chars)
-fn commitment_key(_e: &Env) -> Symbol {
-    symbol_short!("Commit")
-#[derive(Clone)]
 pub enum DataKey {
     Admin,
     NftContract,
@@ -315,6 +179,10 @@ pub enum DataKey {
     AssetMetadata(Address),   // asset -> AssetMetadata (optional)
     TotalValueLockedByAsset(Address), // asset -> i128
     Version,
+    AuthorizedAllocator(Address), // allocator -> bool (allocation strategy contracts)
+    AllocationTracking(String),  // commitment_id -> AllocationTracking
+    AuthorizedEnforcer(Address), // enforcer -> bool (e.g. attestation_engine)
+    ReviewFlag(String),          // commitment_id -> review reason
 }
 
 /// Transfer assets from owner to contract
@@ -332,28 +200,56 @@ fn transfer_assets(e: &Env, from: &Address, to: &Address, asset_address: &Addres
     token_client.transfer(from, to, &amount);
 }
 
+/// Mirror a closing lifecycle state onto the commitment's NFT
+fn set_nft_lifecycle_state(
+    e: &Env,
+    nft_contract: &Address,
+    token_id: u32,
+    state: NftLifecycleState,
+) {
+    let mut args = Vec::new(e);
+    args.push_back(e.current_contract_address().into_val(e));
+    args.push_back(token_id.into_val(e));
+    args.push_back(state.into_val(e));
+    e.invoke_contract::<()>(nft_contract, &Symbol::new(e, "set_lifecycle_state"), args);
+}
+
+/// Current holder of a commitment's NFT, falling back to the commitment owner
+///
+/// Settlement proceeds follow the NFT, so buyers and vaults holding it get paid.
+fn nft_holder(e: &Env, nft_contract: &Address, token_id: u32, fallback: &Address) -> Address {
+    let mut args = Vec::new(e);
+    args.push_back(token_id.into_val(e));
+    match e.try_invoke_contract::<Address, soroban_sdk::Error>(
+        nft_contract,
+        &Symbol::new(e, "owner_of"),
+        args,
+    ) {
+        Ok(Ok(holder)) => holder,
+        _ => fallback.clone(),
+    }
+}
+
 /// Helper function to call NFT contract mint function
 fn call_nft_mint(
     e: &Env,
     nft_contract: &Address,
     owner: &Address,
     commitment_id: &String,
-    duration_days: u32,
-    max_loss_percent: u32,
-    commitment_type: &String,
+    rules: &CommitmentRules,
     initial_amount: i128,
     asset_address: &Address,
-    early_exit_penalty: u32,
 ) -> u32 {
     let mut args = Vec::new(e);
+    args.push_back(e.current_contract_address().into_val(e));
     args.push_back(owner.clone().into_val(e));
     args.push_back(commitment_id.clone().into_val(e));
-    args.push_back(duration_days.into_val(e));
-    args.push_back(max_loss_percent.into_val(e));
-    args.push_back(commitment_type.clone().into_val(e));
+    args.push_back(rules.duration_days.into_val(e));
+    args.push_back(rules.max_loss_percent.into_val(e));
+    args.push_back(rules.commitment_type.clone().into_val(e));
     args.push_back(initial_amount.into_val(e));
     args.push_back(asset_address.clone().into_val(e));
-    args.push_back(early_exit_penalty.into_val(e));
+    args.push_back(rules.early_exit_penalty.into_val(e));
 
     // In Soroban, contract calls return the value directly
     // Failures cause the entire transaction to fail
@@ -372,13 +268,6 @@ fn set_admin(e: &Env, admin: &Address) {
     e.storage().instance().set(&DataKey::Admin, admin);
 }
 
-fn get_nft_contract(e: &Env) -> Address {
-    e.storage()
-        .instance()
-        .get::<_, Address>(&DataKey::NftContract)
-        .unwrap_or_else(|| panic!("Contract not initialized"))
-}
-
 fn set_nft_contract(e: &Env, nft_contract: &Address) {
     e.storage()
         .instance()
@@ -451,6 +340,58 @@ fn remove_active_commitment(e: &Env, commitment_id: &String) {
     }
 }
 
+fn is_authorized_allocator(e: &Env, allocator: &Address) -> bool {
+    e.storage()
+        .instance()
+        .get::<_, bool>(&DataKey::AuthorizedAllocator(allocator.clone()))
+        .unwrap_or(false)
+}
+
+fn set_authorized_allocator(e: &Env, allocator: &Address, authorized: bool) {
+    e.storage().instance().set(
+        &DataKey::AuthorizedAllocator(allocator.clone()),
+        &authorized,
+    );
+}
+
+fn get_allocation_tracking(e: &Env, commitment_id: &String) -> AllocationTracking {
+    e.storage()
+        .persistent()
+        .get::<_, AllocationTracking>(&DataKey::AllocationTracking(commitment_id.clone()))
+        .unwrap_or(AllocationTracking {
+            total_allocated: 0,
+            allocations: Vec::new(e),
+        })
+}
+
+fn set_allocation_tracking(e: &Env, commitment_id: &String, tracking: &AllocationTracking) {
+    e.storage().persistent().set(
+        &DataKey::AllocationTracking(commitment_id.clone()),
+        tracking,
+    );
+}
+
+/// Apply `delta` to both the global and the per-asset total value locked.
+fn adjust_tvl(e: &Env, asset: &Address, delta: i128) {
+    let current_tvl = e
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey::TotalValueLocked)
+        .unwrap_or(0);
+    e.storage()
+        .instance()
+        .set(&DataKey::TotalValueLocked, &(current_tvl + delta));
+    let asset_tvl = e
+        .storage()
+        .instance()
+        .get::<_, i128>(&DataKey::TotalValueLockedByAsset(asset.clone()))
+        .unwrap_or(0);
+    e.storage().instance().set(
+        &DataKey::TotalValueLockedByAsset(asset.clone()),
+        &(asset_tvl + delta),
+    );
+}
+
 fn get_total_commitments(e: &Env) -> u64 {
     e.storage()
         .instance()
@@ -496,7 +437,7 @@ fn require_asset_supported(e: &Env, asset_address: &Address) {
         .instance()
         .get::<_, Vec<Address>>(&DataKey::SupportedAssets)
         .unwrap_or(Vec::new(e));
-        if supported.len() > 0 {
+        if !supported.is_empty() {
         let mut found = false;
         for a in supported.iter() {
             if a == *asset_address {
@@ -510,6 +451,19 @@ fn require_asset_supported(e: &Env, asset_address: &Address) {
     }
 }
 
+/// Require that the caller is an authorized enforcer contract.
+fn require_enforcer(e: &Env, caller: &Address, context: &str) {
+    caller.require_auth();
+    let authorized = e
+        .storage()
+        .instance()
+        .get::<_, bool>(&DataKey::AuthorizedEnforcer(caller.clone()))
+        .unwrap_or(false);
+    if !authorized {
+        fail(e, CommitmentError::Unauthorized, context);
+    }
+}
+
 /// Require that the caller is the admin stored in this contract.
 fn require_admin(e: &Env, caller: &Address) {
     caller.require_auth();
@@ -594,37 +548,6 @@ impl CommitmentCoreContract {
     }
 
     /// Initialize the core commitment contract
-    pub fn initialize(e: Env, admin: Address, _nft_contract: Address) {
-        if is_initialized(&e) {
-            panic_already_initialized();
-        }
-        
-        set_admin(&e, &admin);
-        set_initialized(&e);
-    }
-
-    /// Add an authorized allocation contract
-    pub fn add_authorized_allocator(e: Env, allocator: Address) {
-        let admin = get_admin(&e);
-        admin.require_auth();
-        
-        set_authorized_allocator(&e, &allocator, true);
-    }
-
-    /// Remove an authorized allocation contract
-    pub fn remove_authorized_allocator(e: Env, allocator: Address) {
-        let admin = get_admin(&e);
-        admin.require_auth();
-        
-        set_authorized_allocator(&e, &allocator, false);
-    }
-
-    /// Check if an address is an authorized allocator
-    pub fn is_authorized_allocator(e: Env, allocator: Address) -> bool {
-        is_authorized_allocator(&e, &allocator)
-    pub fn initialize(_e: Env, _admin: Address, _nft_contract: Address) {
-        // TODO: Store admin and NFT contract address
-        // TODO: Initialize storage
     pub fn initialize(e: Env, admin: Address, nft_contract: Address) {
         // Check if already initialized
         if e.storage().instance().has(&DataKey::Admin) {
@@ -656,6 +579,39 @@ impl CommitmentCoreContract {
         write_version(&e, CURRENT_VERSION);
     }
 
+    /// Get current on-chain version (0 if legacy/uninitialized).
+    pub fn get_version(e: Env) -> u32 {
+        read_version(&e)
+    }
+
+    /// Upgrade contract WASM (admin-only).
+    pub fn upgrade(e: Env, caller: Address, new_wasm_hash: BytesN<32>) {
+        require_admin(&e, &caller);
+        require_valid_wasm_hash(&e, &new_wasm_hash);
+        e.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    /// Add an authorized allocation contract
+    pub fn add_authorized_allocator(e: Env, allocator: Address) {
+        let admin = get_admin(&e);
+        admin.require_auth();
+
+        set_authorized_allocator(&e, &allocator, true);
+    }
+
+    /// Remove an authorized allocation contract
+    pub fn remove_authorized_allocator(e: Env, allocator: Address) {
+        let admin = get_admin(&e);
+        admin.require_auth();
+
+        set_authorized_allocator(&e, &allocator, false);
+    }
+
+    /// Check if an address is an authorized allocator
+    pub fn is_authorized_allocator(e: Env, allocator: Address) -> bool {
+        is_authorized_allocator(&e, &allocator)
+    }
+
     /// Create a new commitment
     ///
     /// # Reentrancy Protection
@@ -827,12 +783,9 @@ impl CommitmentCoreContract {
             &nft_contract,
             &owner,
             &commitment_id,
-            rules.duration_days,
-            rules.max_loss_percent,
-            &rules.commitment_type,
+            &rules,
             amount_locked,
             &asset_address,
-            rules.early_exit_penalty,
         );
 
         // Update commitment with NFT token ID
@@ -856,8 +809,6 @@ impl CommitmentCoreContract {
     }
 
     /// Get commitment details
-    pub fn get_commitment(e: Env, commitment_id: String) -> Option<Commitment> {
-        get_commitment(&e, &commitment_id)
     pub fn get_commitment(e: Env, commitment_id: String) -> Commitment {
         read_commitment(&e, &commitment_id)
             .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "get_commitment"))
@@ -1042,11 +993,7 @@ impl CommitmentCoreContract {
         let duration_violated = current_time >= commitment.expires_at;
 
         // Calculate time remaining (0 if expired)
-        let time_remaining = if current_time < commitment.expires_at {
-            commitment.expires_at - current_time
-        } else {
-            0
-        };
+        let time_remaining = commitment.expires_at.saturating_sub(current_time);
 
         let has_violations = loss_violated || duration_violated;
 
@@ -1084,9 +1031,9 @@ impl CommitmentCoreContract {
             fail(&e, CommitmentError::NotExpired, "settle");
         }
 
-        // Verify commitment is active
-        let active_status = String::from_str(&e, "active");
-        if commitment.status != active_status {
+        // Verify commitment is active, or violated and waiting for payout
+        let violated = commitment.status == String::from_str(&e, "violated");
+        if commitment.status != String::from_str(&e, "active") && !violated {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::NotActive, "settle");
         }
@@ -1122,12 +1069,6 @@ impl CommitmentCoreContract {
             .set(&DataKey::TotalValueLockedByAsset(asset), &(asset_tvl - settlement_amount));
 
         // INTERACTIONS: External calls (token transfer, NFT settlement)
-        // Transfer assets back to owner
-        let contract_address = e.current_contract_address();
-        let token_client = token::Client::new(&e, &commitment.asset_address);
-        token_client.transfer(&contract_address, &commitment.owner, &settlement_amount);
-
-        // Call NFT contract to mark NFT as settled
         let nft_contract = e
             .storage()
             .instance()
@@ -1137,9 +1078,26 @@ impl CommitmentCoreContract {
                 fail(&e, CommitmentError::NotInitialized, "settle")
             });
 
-        let mut args = Vec::new(&e);
-        args.push_back(commitment.nft_token_id.into_val(&e));
-        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
+        // Transfer assets to the current NFT holder
+        let recipient = nft_holder(&e, &nft_contract, commitment.nft_token_id, &commitment.owner);
+        let contract_address = e.current_contract_address();
+        let token_client = token::Client::new(&e, &commitment.asset_address);
+        token_client.transfer(&contract_address, &recipient, &settlement_amount);
+
+        // Call NFT contract to mark NFT as settled (a violated NFT is already inactive)
+        if violated {
+            set_nft_lifecycle_state(
+                &e,
+                &nft_contract,
+                commitment.nft_token_id,
+                NftLifecycleState::Settled,
+            );
+        } else {
+            let mut args = Vec::new(&e);
+            args.push_back(e.current_contract_address().into_val(&e));
+            args.push_back(commitment.nft_token_id.into_val(&e));
+            e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
+        }
 
         // Clear reentrancy guard
         set_reentrancy_guard(&e, false);
@@ -1151,6 +1109,9 @@ impl CommitmentCoreContract {
         );
     }
 
+    /// Exit an active or violated commitment before maturity, paying the early exit penalty
+    ///
+    /// Only the current NFT holder may exit and receives the remaining value.
     pub fn early_exit(e: Env, commitment_id: String, caller: Address) {
         // Reentrancy protection
         require_no_reentrancy(&e);
@@ -1163,16 +1124,32 @@ impl CommitmentCoreContract {
             fail(&e, CommitmentError::CommitmentNotFound, "early_exit")
         });
 
-        // Verify caller is owner
+        let nft_contract = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::NftContract)
+            .unwrap_or_else(|| {
+                set_reentrancy_guard(&e, false);
+                fail(&e, CommitmentError::NotInitialized, "early_exit")
+            });
+
+        // Verify caller holds the commitment NFT
         caller.require_auth();
-        if commitment.owner != caller {
+        let holder = nft_holder(
+            &e,
+            &nft_contract,
+            commitment.nft_token_id,
+            &commitment.owner,
+        );
+        if holder != caller {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::Unauthorized, "early_exit");
         }
 
-        // Verify commitment is active
-        let active_status = String::from_str(&e, "active");
-        if commitment.status != active_status {
+        // Verify commitment is active, or violated and waiting for payout
+        if commitment.status != String::from_str(&e, "active")
+            && commitment.status != String::from_str(&e, "violated")
+        {
             set_reentrancy_guard(&e, false);
             fail(&e, CommitmentError::NotActive, "early_exit");
         }
@@ -1225,28 +1202,21 @@ impl CommitmentCoreContract {
             .set(&DataKey::TotalValueLockedByAsset(asset), &(asset_tvl - original_current_value));
 
         // INTERACTIONS: External calls (token transfer)
-        // Transfer remaining amount (after penalty) to owner
+        // Transfer remaining amount (after penalty) to the NFT holder
         let contract_address = e.current_contract_address();
         let token_client = token::Client::new(&e, &commitment.asset_address);
 
         if returned_amount > 0 {
-            token_client.transfer(&contract_address, &commitment.owner, &returned_amount);
+            token_client.transfer(&contract_address, &holder, &returned_amount);
         }
 
-        // Call NFT contract to update NFT status (mark as inactive/early_exited)
-        let nft_contract = e
-            .storage()
-            .instance()
-            .get::<_, Address>(&DataKey::NftContract)
-            .unwrap_or_else(|| {
-                set_reentrancy_guard(&e, false);
-                fail(&e, CommitmentError::NotInitialized, "early_exit")
-            });
-
-        // Call settle on NFT to mark it as inactive
-        let mut args = Vec::new(&e);
-        args.push_back(commitment.nft_token_id.into_val(&e));
-        e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
+        // Mark the NFT as early-exited (settle would reject an unexpired NFT)
+        set_nft_lifecycle_state(
+            &e,
+            &nft_contract,
+            commitment.nft_token_id,
+            NftLifecycleState::EarlyExited,
+        );
 
         // Clear reentrancy guard
         set_reentrancy_guard(&e, false);
@@ -1262,87 +1232,87 @@ impl CommitmentCoreContract {
         );
     }
 
-    /// Allocate liquidity to a target pool
-    /// 
+    /// Allocate liquidity to a target pool (called by an authorized allocation strategy)
+    ///
     /// # Arguments
-    /// * `caller` - The address of the allocation contract calling this function (must be authorized)
+    /// * `caller` - The allocation contract calling this function (must be authorized by the admin)
     /// * `commitment_id` - The ID of the commitment
     /// * `target_pool` - The address of the target pool to allocate to
     /// * `amount` - The amount to allocate
-    /// 
-    /// # Errors
-    /// * `Unauthorized` - If caller is not an authorized allocation contract
-    /// * `InactiveCommitment` - If commitment is not active
-    /// * `InsufficientBalance` - If commitment doesn't have enough balance
-    /// * `TransferFailed` - If asset transfer fails
-    /// * `InvalidAmount` - If amount is invalid (<= 0)
-    /// 
-    /// # Note
-    /// The allocation contract should pass its own address as the `caller` parameter.
-    /// This address must be authorized by the admin before calling this function.
+    ///
+    /// # Reentrancy Protection
+    /// Uses checks-effects-interactions pattern with reentrancy guard.
     pub fn allocate(e: Env, caller: Address, commitment_id: String, target_pool: Address, amount: i128) {
-        // Verify caller is authorized allocation contract
+        // Verify caller is an authorized allocation contract
+        caller.require_auth();
         if !is_authorized_allocator(&e, &caller) {
-            panic_unauthorized();
+            fail(&e, CommitmentError::Unauthorized, "allocate");
         }
 
-        // Verify commitment exists and is active
-        let commitment = match get_commitment(&e, &commitment_id) {
-            Some(c) => c,
-            None => panic_inactive_commitment(),
-        };
+        // Reentrancy protection
+        require_no_reentrancy(&e);
+        set_reentrancy_guard(&e, true);
+        EmergencyControl::require_not_emergency(&e);
 
-        // Check if commitment is active
+        // Rate limit allocations per target pool address
+        let fn_symbol = symbol_short!("alloc");
+        RateLimiter::check(&e, &target_pool, &fn_symbol);
+
+        // CHECKS: Validate inputs and commitment
+        if amount <= 0 {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::InvalidAmount, "allocate");
+        }
+
+        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::CommitmentNotFound, "allocate")
+        });
+
+        // Verify commitment is active
         let active_status = String::from_str(&e, "active");
         if commitment.status != active_status {
-            panic_inactive_commitment();
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::NotActive, "allocate");
         }
 
         // Verify sufficient balance
-        let balance = get_commitment_balance(&e, &commitment_id);
-        if balance < amount {
-            panic_insufficient_balance();
+        if commitment.current_value < amount {
+            set_reentrancy_guard(&e, false);
+            fail(&e, CommitmentError::InsufficientBalance, "allocate");
         }
 
-        // Transfer assets to target pool
-        let contract_address = e.current_contract_address();
-        transfer_asset(&e, &commitment.asset_address, &contract_address, &target_pool, amount);
-
-        // Update commitment balance
-        let new_balance = balance - amount;
-        set_commitment_balance(&e, &commitment_id, new_balance);
+        // EFFECTS: Update commitment value before external call
+        let mut updated_commitment = commitment;
+        let asset = updated_commitment.asset_address.clone();
+        updated_commitment.current_value -= amount;
+        set_commitment(&e, &updated_commitment);
+        adjust_tvl(&e, &asset, -amount);
 
         // Record allocation
-        let mut tracking = get_allocation_tracking(&e, &commitment_id);
         let timestamp = e.ledger().timestamp();
-        
-        let allocation = Allocation {
+        let mut tracking = get_allocation_tracking(&e, &commitment_id);
+        tracking.allocations.push_back(Allocation {
             commitment_id: commitment_id.clone(),
             target_pool: target_pool.clone(),
             amount,
             timestamp,
-        };
-        
-        tracking.allocations.push_back(allocation.clone());
+        });
         tracking.total_allocated += amount;
         set_allocation_tracking(&e, &commitment_id, &tracking);
 
+        // INTERACTIONS: External call (token transfer)
+        let contract_address = e.current_contract_address();
+        let token_client = token::Client::new(&e, &asset);
+        token_client.transfer(&contract_address, &target_pool, &amount);
+
+        // Clear reentrancy guard
+        set_reentrancy_guard(&e, false);
+
         // Emit allocation event
         e.events().publish(
-            (symbol_short!("alloc"), symbol_short!("cmt_id")),
-            commitment_id,
-        );
-        e.events().publish(
-            (symbol_short!("alloc"), symbol_short!("pool")),
-            target_pool,
-        );
-        e.events().publish(
-            (symbol_short!("alloc"), symbol_short!("amount")),
-            amount,
-        );
-        e.events().publish(
-            (symbol_short!("alloc"), symbol_short!("time")),
-            timestamp,
+            (symbol_short!("Alloc"), commitment_id, target_pool),
+            (amount, timestamp),
         );
     }
 
@@ -1351,129 +1321,55 @@ impl CommitmentCoreContract {
         get_allocation_tracking(&e, &commitment_id)
     }
 
-    /// Deallocate liquidity from a pool (optional functionality)
-    /// This would be called when liquidity is returned from a pool
-    /// 
+    /// Deallocate liquidity from a pool, returning it to the commitment.
+    ///
     /// # Arguments
-    /// * `caller` - The address of the allocation contract calling this function (must be authorized)
+    /// * `caller` - The allocation contract calling this function (must be authorized by the admin)
     /// * `commitment_id` - The ID of the commitment
     /// * `target_pool` - The address of the pool to deallocate from
     /// * `amount` - The amount to deallocate
     pub fn deallocate(e: Env, caller: Address, commitment_id: String, target_pool: Address, amount: i128) {
-        // Verify caller is authorized
+        caller.require_auth();
         if !is_authorized_allocator(&e, &caller) {
-            panic_unauthorized();
+            fail(&e, CommitmentError::Unauthorized, "deallocate");
         }
 
-        // Get commitment
-        let commitment = match get_commitment(&e, &commitment_id) {
-            Some(c) => c,
-            None => panic_inactive_commitment(),
-        };
-
-        // Transfer assets back from pool to commitment contract
-        let contract_address = e.current_contract_address();
-        transfer_asset(&e, &commitment.asset_address, &target_pool, &contract_address, amount);
-
-        // Update commitment balance
-        let balance = get_commitment_balance(&e, &commitment_id);
-        set_commitment_balance(&e, &commitment_id, balance + amount);
-
-        // Update allocation tracking
-        let mut tracking = get_allocation_tracking(&e, &commitment_id);
-        tracking.total_allocated -= amount;
-        if tracking.total_allocated < 0 {
-            tracking.total_allocated = 0;
-        }
-        set_allocation_tracking(&e, &commitment_id, &tracking);
-
-        // Emit deallocation event
-        e.events().publish(
-            (symbol_short!("dealloc"), symbol_short!("cmt_id")),
-            commitment_id,
-        );
-        e.events().publish(
-            (symbol_short!("dealloc"), symbol_short!("pool")),
-            target_pool,
-        );
-        e.events().publish(
-            (symbol_short!("dealloc"), symbol_short!("amount")),
-            amount,
-        );
-    /// Allocate liquidity (called by allocation strategy)
-    ///
-    /// # Reentrancy Protection
-    /// Uses checks-effects-interactions pattern with reentrancy guard.
-    pub fn allocate(e: Env, commitment_id: String, target_pool: Address, amount: i128) {
-        // Reentrancy protection
         require_no_reentrancy(&e);
         set_reentrancy_guard(&e, true);
-        EmergencyControl::require_not_emergency(&e);
 
-        // Rate limit allocations per target pool address
-        let fn_symbol = symbol_short!("alloc");
-        RateLimiter::check(&e, &target_pool, &fn_symbol);
-
-        // CHECKS: Validate inputs and commitment
         if amount <= 0 {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::InvalidAmount, "allocate");
+            fail(&e, CommitmentError::InvalidAmount, "deallocate");
         }
 
-        let commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
+        let mut commitment = read_commitment(&e, &commitment_id).unwrap_or_else(|| {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::CommitmentNotFound, "allocate")
+            fail(&e, CommitmentError::CommitmentNotFound, "deallocate")
         });
 
-        // Verify commitment is active
-        let active_status = String::from_str(&e, "active");
-        if commitment.status != active_status {
-            set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::NotActive, "allocate");
-        }
-
-        // Verify sufficient balance
-        if commitment.current_value < amount {
+        let mut tracking = get_allocation_tracking(&e, &commitment_id);
+        if amount > tracking.total_allocated {
             set_reentrancy_guard(&e, false);
-            fail(&e, CommitmentError::InsufficientBalance, "allocate");
+            fail(&e, CommitmentError::InvalidAmount, "deallocate");
         }
 
-        // EFFECTS: Update commitment value before external call
-        let mut updated_commitment = commitment;
-        let asset = updated_commitment.asset_address.clone();
-        updated_commitment.current_value = updated_commitment.current_value - amount;
-        set_commitment(&e, &updated_commitment);
-
-        // Decrease total value locked and per-asset TVL
-        let current_tvl = e
-            .storage()
-            .instance()
-            .get::<_, i128>(&DataKey::TotalValueLocked)
-            .unwrap_or(0);
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalValueLocked, &(current_tvl - amount));
-        let asset_tvl = e
-            .storage()
-            .instance()
-            .get::<_, i128>(&DataKey::TotalValueLockedByAsset(asset.clone()))
-            .unwrap_or(0);
-        e.storage()
-            .instance()
-            .set(&DataKey::TotalValueLockedByAsset(asset), &(asset_tvl - amount));
+        // EFFECTS
+        let asset = commitment.asset_address.clone();
+        commitment.current_value += amount;
+        set_commitment(&e, &commitment);
+        adjust_tvl(&e, &asset, amount);
+        tracking.total_allocated -= amount;
+        set_allocation_tracking(&e, &commitment_id, &tracking);
 
-        // INTERACTIONS: External call (token transfer)
-        // Transfer assets to target pool
+        // INTERACTIONS: pull assets back from the pool
         let contract_address = e.current_contract_address();
-        let token_client = token::Client::new(&e, &updated_commitment.asset_address);
-        token_client.transfer(&contract_address, &target_pool, &amount);
+        let token_client = token::Client::new(&e, &asset);
+        token_client.transfer(&target_pool, &contract_address, &amount);
 
-        // Clear reentrancy guard
         set_reentrancy_guard(&e, false);
 
-        // Emit allocation event
         e.events().publish(
-            (symbol_short!("Alloc"), commitment_id, target_pool),
+            (symbol_short!("Dealloc"), commitment_id, target_pool),
             (amount, e.ledger().timestamp()),
         );
     }
@@ -1574,6 +1470,146 @@ impl CommitmentCoreContract {
             .unwrap_or(0)
     }
 
+    // ========================================================================
+    // Enforcement
+    // ========================================================================
+
+    /// Authorize or deauthorize an enforcer contract (admin only)
+    pub fn set_enforcer(e: Env, caller: Address, enforcer: Address, authorized: bool) {
+        require_admin(&e, &caller);
+        if authorized {
+            e.storage()
+                .instance()
+                .set(&DataKey::AuthorizedEnforcer(enforcer.clone()), &true);
+        } else {
+            e.storage()
+                .instance()
+                .remove(&DataKey::AuthorizedEnforcer(enforcer.clone()));
+        }
+        e.events().publish(
+            (Symbol::new(&e, "EnforcerSet"), enforcer),
+            (authorized, e.ledger().timestamp()),
+        );
+    }
+
+    /// Check if an address is an authorized enforcer
+    pub fn is_enforcer(e: Env, enforcer: Address) -> bool {
+        e.storage()
+            .instance()
+            .get::<_, bool>(&DataKey::AuthorizedEnforcer(enforcer))
+            .unwrap_or(false)
+    }
+
+    /// Mark an active commitment as violated (authorized enforcer only)
+    pub fn mark_violated(e: Env, caller: Address, commitment_id: String, reason: String) {
+        require_enforcer(&e, &caller, "mark_violated");
+
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "mark_violated"));
+        if commitment.status != String::from_str(&e, "active") {
+            fail(&e, CommitmentError::NotActive, "mark_violated");
+        }
+
+        commitment.status = String::from_str(&e, "violated");
+        set_commitment(&e, &commitment);
+
+        if let Some(nft_contract) = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::NftContract)
+        {
+            set_nft_lifecycle_state(
+                &e,
+                &nft_contract,
+                commitment.nft_token_id,
+                NftLifecycleState::Violated,
+            );
+        }
+
+        e.events().publish(
+            (symbol_short!("Violated"), commitment_id, caller),
+            (reason, e.ledger().timestamp()),
+        );
+    }
+
+    /// Reinstate a violated commitment (authorized enforcer only)
+    ///
+    /// Used when the attestation behind `mark_violated` is revoked or
+    /// overturned in a dispute. The NFT returns to the Active state.
+    pub fn clear_violation(e: Env, caller: Address, commitment_id: String, reason: String) {
+        require_enforcer(&e, &caller, "clear_violation");
+
+        let mut commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "clear_violation"));
+        if commitment.status != String::from_str(&e, "violated") {
+            fail(&e, CommitmentError::InvalidStatus, "clear_violation");
+        }
+
+        commitment.status = String::from_str(&e, "active");
+        set_commitment(&e, &commitment);
+
+        if let Some(nft_contract) = e
+            .storage()
+            .instance()
+            .get::<_, Address>(&DataKey::NftContract)
+        {
+            set_nft_lifecycle_state(
+                &e,
+                &nft_contract,
+                commitment.nft_token_id,
+                NftLifecycleState::Active,
+            );
+        }
+
+        e.events().publish(
+            (Symbol::new(&e, "ViolationCleared"), commitment_id, caller),
+            (reason, e.ledger().timestamp()),
+        );
+    }
+
+    /// Flag an active commitment for manual review (authorized enforcer only)
+    pub fn flag_for_review(e: Env, caller: Address, commitment_id: String, reason: String) {
+        require_enforcer(&e, &caller, "flag_for_review");
+
+        let commitment = read_commitment(&e, &commitment_id)
+            .unwrap_or_else(|| fail(&e, CommitmentError::CommitmentNotFound, "flag_for_review"));
+        if commitment.status != String::from_str(&e, "active") {
+            fail(&e, CommitmentError::NotActive, "flag_for_review");
+        }
+
+        e.storage()
+            .instance()
+            .set(&DataKey::ReviewFlag(commitment_id.clone()), &reason);
+
+        e.events().publish(
+            (symbol_short!("Review"), commitment_id, caller),
+            (reason, e.ledger().timestamp()),
+        );
+    }
+
+    /// Get the review reason if the commitment is flagged for review
+    pub fn get_review_flag(e: Env, commitment_id: String) -> Option<String> {
+        e.storage()
+            .instance()
+            .get::<_, String>(&DataKey::ReviewFlag(commitment_id))
+    }
+
+    /// Clear a review flag (admin or authorized enforcer)
+    pub fn clear_review_flag(e: Env, caller: Address, commitment_id: String) {
+        if Self::is_enforcer(e.clone(), caller.clone()) {
+            require_enforcer(&e, &caller, "clear_review_flag");
+        } else {
+            require_admin(&e, &caller);
+        }
+        e.storage()
+            .instance()
+            .remove(&DataKey::ReviewFlag(commitment_id.clone()));
+        e.events().publish(
+            (Symbol::new(&e, "ReviewCleared"), commitment_id),
+            (e.ledger().timestamp(),),
+        );
+    }
+
     // ========================================================================
     // Emergency Functions (Issue #62)
     // ========================================================================
@@ -1651,6 +1687,7 @@ impl CommitmentCoreContract {
             .get::<_, Address>(&DataKey::NftContract)
             .unwrap_or_else(|| panic!("NFT contract not initialized"));
         let mut args = Vec::new(&e);
+        args.push_back(e.current_contract_address().into_val(&e));
         args.push_back(commitment.nft_token_id.into_val(&e));
         e.invoke_contract::<()>(&nft_contract, &Symbol::new(&e, "settle"), args);
 
@@ -1779,7 +1816,7 @@ impl CommitmentCoreContract {
             .instance()
             .get::<_, Vec<Address>>(&DataKey::SupportedAssets)
             .unwrap_or(Vec::new(&e));
-        if supported.len() == 0 {
+        if supported.is_empty() {
             return true;
         }
         for a in supported.iter() {
@@ -8,6 +8,7 @@ use soroban_sdk::{
 };
 
 // Helper function to create a test commitment
+#[allow(clippy::too_many_arguments)]
 fn create_test_commitment(
     e: &Env,
     commitment_id: &str,
@@ -63,42 +64,9 @@ fn setup_contract(e: &Env) -> Address {
     contract_id
 }
 
-fn create_test_commitment(e: &Env, contract_id: &Address) -> (String, Commitment) {
-    let commitment_id = String::from_str(e, "test_commitment_1");
-    let owner = Address::generate(e);
-    let asset_address = Address::generate(e);
-    
-    let rules = CommitmentRules {
-        duration_days: 365,
-        max_loss_percent: 20,
-        commitment_type: String::from_str(e, "balanced"),
-        early_exit_penalty: 10,
-        min_fee_threshold: 1000,
-    };
-    
-    let commitment = Commitment {
-        commitment_id: commitment_id.clone(),
-        owner: owner.clone(),
-        nft_token_id: 1,
-        rules: rules.clone(),
-        amount: 1000000, // 1000 tokens (assuming 1000 scaling)
-        asset_address: asset_address.clone(),
-        created_at: 1000,
-        expires_at: 1000 + (365 * 86400), // 365 days later
-        current_value: 1000000,
-        status: String::from_str(e, "active"),
-    };
-    
-    // Note: In a real test, we would need to actually store this commitment
-    // For now, this is a helper function structure
-    
-    (commitment_id, commitment)
-}
-
 #[test]
 fn test_initialize() {
     let e = create_test_env();
-    let e = Env::default();
     let contract_id = e.register_contract(None, CommitmentCoreContract);
 
     let admin = Address::generate(&e);
@@ -113,6 +81,8 @@ fn test_initialize() {
 #[test]
 fn test_create_commitment_valid() {
     let e = Env::default();
+    let admin = Address::generate(&e);
+    let nft_contract = Address::generate(&e);
     let contract_id = e.register_contract(None, CommitmentCoreContract);
     
     let client = CommitmentCoreContractClient::new(&e, &contract_id);
@@ -122,7 +92,7 @@ fn test_create_commitment_valid() {
 }
 
 #[test]
-#[should_panic(expected = "AlreadyInitialized")]
+#[should_panic(expected = "Contract already initialized")]
 fn test_initialize_twice() {
     let e = create_test_env();
     let admin = Address::generate(&e);
@@ -145,7 +115,7 @@ fn test_add_authorized_allocator() {
     client.initialize(&admin, &nft_contract);
     
     let allocator = Address::generate(&e);
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     
     // Verify allocator is authorized
@@ -166,12 +136,12 @@ fn test_remove_authorized_allocator() {
     let allocator = Address::generate(&e);
     
     // Add allocator
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     assert!(client.is_authorized_allocator(&allocator));
     
     // Remove allocator
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.remove_authorized_allocator(&allocator);
     assert!(!client.is_authorized_allocator(&allocator));
 }
@@ -196,7 +166,7 @@ fn test_allocate_unauthorized_caller() {
 }
 
 #[test]
-#[should_panic(expected = "InactiveCommitment")]
+#[should_panic(expected = "Commitment not found")]
 fn test_allocate_inactive_commitment() {
     let e = create_test_env();
     let admin = Address::generate(&e);
@@ -207,7 +177,7 @@ fn test_allocate_inactive_commitment() {
     client.initialize(&admin, &nft_contract);
     
     let allocator = Address::generate(&e);
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     
     // Try to allocate with non-existent commitment - should panic
@@ -218,7 +188,7 @@ fn test_allocate_inactive_commitment() {
 }
 
 #[test]
-#[should_panic(expected = "InsufficientBalance")]
+#[should_panic(expected = "Insufficient balance")]
 fn test_allocate_insufficient_balance() {
     let e = create_test_env();
     let admin = Address::generate(&e);
@@ -229,22 +199,21 @@ fn test_allocate_insufficient_balance() {
     client.initialize(&admin, &nft_contract);
     
     let allocator = Address::generate(&e);
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     
-    // Note: This test requires a commitment with a known balance
-    // In a full implementation, we would create a commitment first
-    // and set its balance, then try to allocate more than available
+    // Store a commitment with a known balance, then allocate more than available
+    let owner = Address::generate(&e);
+    let commitment = create_test_commitment(&e, "test_commitment", &owner, 1000, 1000, 10, 30, 0);
+    store_commitment(&e, &contract_id, &commitment);
     let commitment_id = String::from_str(&e, "test_commitment");
     let target_pool = Address::generate(&e);
     
-    // This will panic with InactiveCommitment first, but the test structure
-    // demonstrates the insufficient balance check would work once commitment exists
-    // client.allocate(&allocator, &commitment_id, &target_pool, &999999999);
+    client.allocate(&allocator, &commitment_id, &target_pool, &999999999);
 }
 
 #[test]
-#[should_panic(expected = "InvalidAmount")]
+#[should_panic(expected = "Invalid amount")]
 fn test_allocate_invalid_amount() {
     let e = create_test_env();
     let admin = Address::generate(&e);
@@ -255,16 +224,14 @@ fn test_allocate_invalid_amount() {
     client.initialize(&admin, &nft_contract);
     
     let allocator = Address::generate(&e);
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     
     let commitment_id = String::from_str(&e, "test_commitment");
     let target_pool = Address::generate(&e);
     
-    // Try to allocate with zero or negative amount - should panic
-    // Note: This would panic in transfer_asset function
-    // client.allocate(&allocator, &commitment_id, &target_pool, &0);
-    // Or: client.allocate(&allocator, &commitment_id, &target_pool, &-100);
+    // Try to allocate with zero amount - should panic
+    client.allocate(&allocator, &commitment_id, &target_pool, &0);
 }
 
 #[test]
@@ -296,11 +263,11 @@ fn test_deallocate() {
     client.initialize(&admin, &nft_contract);
     
     let allocator = Address::generate(&e);
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     
-    let commitment_id = String::from_str(&e, "test_commitment");
-    let target_pool = Address::generate(&e);
+    let _commitment_id = String::from_str(&e, "test_commitment");
+    let _target_pool = Address::generate(&e);
     
     // Note: This test would require a real commitment and successful allocation first
     // The deallocation function will panic with InactiveCommitment if commitment doesn't exist
@@ -339,7 +306,7 @@ fn test_allocation_flow_integration() {
     
     // Setup authorized allocator
     let allocator = Address::generate(&e);
-    admin.mock_auth(&e, &admin, &admin, &[]);
+    e.mock_all_auths();
     client.add_authorized_allocator(&allocator);
     
     // Note: Full integration test would require:
@@ -353,16 +320,9 @@ fn test_allocation_flow_integration() {
     // This test structure shows the flow, but actual implementation
     // would need proper commitment and asset contract setup
 
-    let admin = Address::generate(&e);
-    let nft_contract = Address::generate(&e);
     let _owner = Address::generate(&e);
     let _asset_address = Address::generate(&e);
 
-    // Initialize the contract
-    e.as_contract(&contract_id, || {
-        CommitmentCoreContract::initialize(e.clone(), admin.clone(), nft_contract.clone());
-    });
-
     let _rules = CommitmentRules {
         duration_days: 30,
         max_loss_percent: 10,
@@ -888,7 +848,7 @@ fn test_create_commitment_event() {
     let e = Env::default();
     let contract_id = e.register_contract(None, CommitmentCoreContract);
     let client = CommitmentCoreContractClient::new(&e, &contract_id);
-    let owner = Address::generate(&e);
+    let _owner = Address::generate(&e);
     let admin = Address::generate(&e);
     let nft_contract = Address::generate(&e);
 
@@ -1048,17 +1008,21 @@ fn test_early_exit_event() {
 #[should_panic(expected = "Commitment not found")]
 fn test_allocate_event() {
     let e = Env::default();
+    e.mock_all_auths();
     let target_pool = Address::generate(&e);
-    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let contract_id = setup_contract(&e);
     let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    let allocator = Address::generate(&e);
+    client.add_authorized_allocator(&allocator);
 
     let commitment_id = String::from_str(&e, "test_id");
     // This will panic because commitment doesn't exist
     // The test verifies that the function properly validates preconditions
-    client.allocate(&commitment_id, &target_pool, &500);
+    client.allocate(&allocator, &commitment_id, &target_pool, &500);
 }
 
 /// Helper function to create a test commitment with custom penalty
+#[allow(clippy::too_many_arguments)]
 fn create_test_commitment_with_penalty(
     e: &Env,
     commitment_id: &str,
@@ -1202,47 +1166,6 @@ fn test_early_exit_already_settled() {
     });
 }
 
-#[test]
-#[should_panic(expected = "Commitment is not active")]
-fn test_early_exit_already_violated() {
-    let e = Env::default();
-    e.mock_all_auths();
-    
-    let contract_id = e.register_contract(None, CommitmentCoreContract);
-    let owner = Address::generate(&e);
-    let admin = Address::generate(&e);
-    let nft_contract = Address::generate(&e);
-    let commitment_id = "test_commitment_violated";
-    
-    e.as_contract(&contract_id, || {
-        CommitmentCoreContract::initialize(e.clone(), admin.clone(), nft_contract.clone());
-    });
-    
-    let mut commitment = create_test_commitment(
-        &e,
-        commitment_id,
-        &owner,
-        1000,
-        1000,
-        10,
-        30,
-        1000,
-    );
-    
-    // Mark as violated
-    commitment.status = String::from_str(&e, "violated");
-    store_commitment(&e, &contract_id, &commitment);
-    
-    // Try to exit violated commitment
-    e.as_contract(&contract_id, || {
-        CommitmentCoreContract::early_exit(
-            e.clone(),
-            String::from_str(&e, commitment_id),
-            owner.clone(),
-        );
-    });
-}
-
 #[test]
 #[should_panic(expected = "Commitment is not active")]
 fn test_early_exit_already_exited() {
@@ -1746,6 +1669,8 @@ fn test_settle_fails_before_maturity() {
     e.as_contract(&contract_id, || {
         CommitmentCoreContract::settle(e.clone(), String::from_str(&e, commitment_id));
     });
+}
+
 // ============================================================================
 // Multi-asset support tests
 // ============================================================================
@@ -1915,8 +1840,170 @@ fn test_create_commitment_requires_asset_supported_when_whitelist_set() {
         commitment_type: String::from_str(&e, "safe"),
         early_exit_penalty: 5,
         min_fee_threshold: 100,
+        grace_period_days: 0,
     };
 
     // Creating with disallowed asset should panic
     client.create_commitment(&owner, &1000, &disallowed_asset, &rules);
 }
+
+// ============================================================================
+// Enforcement Tests
+// ============================================================================
+
+/// Minimal NFT stand-in that accepts lifecycle updates from core.
+#[contract]
+pub struct MockNft;
+
+#[contractimpl]
+impl MockNft {
+    pub fn set_lifecycle_state(
+        _e: Env,
+        _caller: Address,
+        _token_id: u32,
+        _state: NftLifecycleState,
+    ) {
+    }
+}
+
+#[test]
+fn test_enforcer_marks_commitment_violated() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let admin = Address::generate(&e);
+    let enforcer = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    let nft_contract = e.register_contract(None, MockNft);
+    client.initialize(&admin, &nft_contract);
+
+    let commitment = create_test_commitment(&e, "enforced", &owner, 1000, 700, 10, 30, 0);
+    store_commitment(&e, &contract_id, &commitment);
+
+    client.set_enforcer(&admin, &enforcer, &true);
+    assert!(client.is_enforcer(&enforcer));
+
+    client.flag_for_review(
+        &enforcer,
+        &commitment.commitment_id,
+        &String::from_str(&e, "drawdown_breach"),
+    );
+    assert_eq!(
+        client.get_review_flag(&commitment.commitment_id),
+        Some(String::from_str(&e, "drawdown_breach"))
+    );
+
+    client.mark_violated(
+        &enforcer,
+        &commitment.commitment_id,
+        &String::from_str(&e, "violation"),
+    );
+    assert_eq!(
+        client.get_commitment(&commitment.commitment_id).status,
+        String::from_str(&e, "violated")
+    );
+}
+
+/// Violated commitment backed by a funded token, with `MockNft` as NFT contract
+fn setup_violated_commitment(e: &Env) -> (CommitmentCoreContractClient<'_>, Address, Commitment) {
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let admin = Address::generate(e);
+    let owner = Address::generate(e);
+    let client = CommitmentCoreContractClient::new(e, &contract_id);
+    client.initialize(&admin, &e.register_contract(None, MockNft));
+
+    let asset = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    token::StellarAssetClient::new(e, &asset).mint(&contract_id, &1000);
+
+    let mut commitment = create_test_commitment(e, "violated", &owner, 1000, 1000, 10, 30, 0);
+    commitment.asset_address = asset;
+    commitment.status = String::from_str(e, "violated");
+    store_commitment(e, &contract_id, &commitment);
+
+    (client, owner, commitment)
+}
+
+#[test]
+fn test_early_exit_violated_commitment() {
+    let e = Env::default();
+    let (client, owner, commitment) = setup_violated_commitment(&e);
+
+    client.early_exit(&commitment.commitment_id, &owner);
+
+    // 10% early exit penalty still applies
+    let token_client = token::Client::new(&e, &commitment.asset_address);
+    assert_eq!(token_client.balance(&owner), 900);
+    assert_eq!(
+        client.get_commitment(&commitment.commitment_id).status,
+        String::from_str(&e, "early_exit")
+    );
+}
+
+#[test]
+fn test_settle_violated_commitment_at_maturity() {
+    let e = Env::default();
+    let (client, owner, commitment) = setup_violated_commitment(&e);
+
+    e.ledger()
+        .with_mut(|li| li.timestamp = commitment.expires_at);
+    client.settle(&commitment.commitment_id);
+
+    let token_client = token::Client::new(&e, &commitment.asset_address);
+    assert_eq!(token_client.balance(&owner), 1000);
+    assert_eq!(
+        client.get_commitment(&commitment.commitment_id).status,
+        String::from_str(&e, "settled")
+    );
+}
+
+#[test]
+fn test_clear_violation_reinstates_commitment() {
+    let e = Env::default();
+    let (client, _owner, commitment) = setup_violated_commitment(&e);
+    let admin = client.get_admin();
+    let enforcer = Address::generate(&e);
+    client.set_enforcer(&admin, &enforcer, &true);
+
+    client.clear_violation(
+        &enforcer,
+        &commitment.commitment_id,
+        &String::from_str(&e, "attestation_revoked"),
+    );
+    assert_eq!(
+        client.get_commitment(&commitment.commitment_id).status,
+        String::from_str(&e, "active")
+    );
+
+    // Only violated commitments can be cleared
+    let result = client.try_clear_violation(
+        &enforcer,
+        &commitment.commitment_id,
+        &String::from_str(&e, "again"),
+    );
+    assert!(result.is_err());
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized")]
+fn test_mark_violated_requires_enforcer() {
+    let e = Env::default();
+    e.mock_all_auths();
+    let contract_id = e.register_contract(None, CommitmentCoreContract);
+    let admin = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let client = CommitmentCoreContractClient::new(&e, &contract_id);
+    client.initialize(&admin, &Address::generate(&e));
+
+    let commitment = create_test_commitment(&e, "unenforced", &owner, 1000, 700, 10, 30, 0);
+    store_commitment(&e, &contract_id, &commitment);
+
+    client.mark_violated(
+        &Address::generate(&e),
+        &commitment.commitment_id,
+        &String::from_str(&e, "violation"),
+    );
+}
@@ -2,7 +2,7 @@
 
 use soroban_sdk::{
     contract, contractimpl, contracttype, contracterror, symbol_short,
-    Address, Env, Vec, Symbol, token
+    Address, Bytes, BytesN, Env, IntoVal, String, TryIntoVal, Vec, Val, Symbol, token, vec
 };
 
 // ============================================================================
@@ -56,6 +56,30 @@ pub enum MarketplaceError {
     ReentrancyDetected = 20,
     /// Transfer failed
     TransferFailed = 21,
+    /// Listing has expired
+    ListingExpired = 22,
+    /// Payment token is not on the allowlist
+    PaymentTokenNotAllowed = 23,
+    /// Listing expiry is in the past
+    InvalidExpiration = 24,
+    /// Price oracle not configured or conversion failed
+    PriceConversionFailed = 25,
+    /// Seller does not own the NFT
+    NotTokenOwner = 26,
+    /// Marketplace is not approved to transfer the NFT
+    MarketplaceNotApproved = 27,
+    /// Listing can no longer be filled (owner changed or approval revoked)
+    ListingNotFillable = 28,
+    /// Revealed reserve does not match the committed hash
+    InvalidReserveReveal = 29,
+    /// Invalid auction options (reserve or bid increment)
+    InvalidAuctionOptions = 30,
+    /// Auction has not started yet
+    AuctionNotStarted = 31,
+    /// Invalid Dutch auction price schedule
+    InvalidPriceSchedule = 32,
+    /// Token doesn't satisfy the offer criteria (or its commitment data is unavailable)
+    CriteriaNotMet = 33,
 }
 
 // ============================================================================
@@ -68,9 +92,144 @@ pub enum MarketplaceError {
 pub struct Listing {
     pub token_id: u32,
     pub seller: Address,
+    /// Price in `payment_token`, or in `price_asset` when set
     pub price: i128,
     pub payment_token: Address,
     pub listed_at: u64,
+    /// Listing can't be bought after this timestamp (0 = never expires)
+    pub expires_at: u64,
+    /// Reference asset the price is denominated in, converted through the price oracle
+    pub price_asset: Option<Address>,
+}
+
+/// Whether a listing can currently be filled
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListingStatus {
+    /// Seller still owns the NFT and the marketplace may transfer it
+    Fillable,
+    /// Listing is past its expiry
+    Expired,
+    /// NFT has moved to another owner (or no longer exists)
+    OwnerChanged,
+    /// Seller revoked the marketplace's approval
+    NotApproved,
+}
+
+/// Oracle price (mirrors price_oracle::PriceData for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub updated_at: u64,
+    pub decimals: u32,
+}
+
+/// Commitment rules (mirrors commitment_core::CommitmentRules for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentRules {
+    pub duration_days: u32,
+    pub max_loss_percent: u32,
+    pub commitment_type: String,
+    pub early_exit_penalty: u32,
+    pub min_fee_threshold: i128,
+    pub grace_period_days: u32,
+}
+
+/// Live commitment (mirrors commitment_core::Commitment for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commitment {
+    pub commitment_id: String,
+    pub owner: Address,
+    pub nft_token_id: u32,
+    pub rules: CommitmentRules,
+    pub amount: i128,
+    pub asset_address: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub current_value: i128,
+    pub status: String,
+}
+
+/// NFT metadata (mirrors commitment_nft::CommitmentMetadata for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentMetadata {
+    pub commitment_id: String,
+    pub duration_days: u32,
+    pub max_loss_percent: u32,
+    pub commitment_type: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub initial_amount: i128,
+    pub asset_address: Address,
+}
+
+/// NFT data (mirrors commitment_nft::CommitmentNFT for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentNFT {
+    pub owner: Address,
+    pub token_id: u32,
+    pub metadata: CommitmentMetadata,
+    pub is_active: bool,
+    pub early_exit_penalty: u32,
+}
+
+/// Commitment requirements of a criteria-based offer; `None` matches anything
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OfferCriteria {
+    /// Commitment type, e.g. "balanced"
+    pub commitment_type: Option<String>,
+    /// Asset the commitment is denominated in
+    pub asset: Option<Address>,
+    /// Commitment must expire within this many seconds of the fill
+    pub max_seconds_to_expiry: Option<u64>,
+    /// Minimum attestation_engine compliance score (0-100)
+    pub min_compliance_score: Option<u32>,
+}
+
+/// Escrowed offer for up to `quantity` tokens matching `criteria`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CriteriaOffer {
+    pub offer_id: u64,
+    pub offerer: Address,
+    pub criteria: OfferCriteria,
+    pub price_per_unit: i128,
+    pub payment_token: Address,
+    pub quantity: u32,
+    pub filled: u32,
+    pub created_at: u64,
+}
+
+/// Listing enriched with live commitment data for buyers
+///
+/// Fields that depend on an unconfigured or failing contract are `None`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingDetails {
+    pub listing: Listing,
+    pub status: ListingStatus,
+    /// Amount a buyer pays now, in the listing's payment token
+    pub price: Option<i128>,
+    pub commitment_id: Option<String>,
+    pub commitment_type: Option<String>,
+    pub max_loss_percent: Option<u32>,
+    pub initial_amount: Option<i128>,
+    /// Early exit penalty in percent
+    pub early_exit_penalty: Option<u32>,
+    pub current_value: Option<i128>,
+    pub expires_at: Option<u64>,
+    pub remaining_seconds: Option<u64>,
+    pub compliance_score: Option<u32>,
+    /// Price versus current value in basis points (negative is a discount);
+    /// only set when the listing is paid in the commitment's asset and the
+    /// ratio fits in an i128
+    pub premium_bps: Option<i128>,
 }
 
 /// Offer information
@@ -84,6 +243,29 @@ pub struct Offer {
     pub created_at: u64,
 }
 
+/// Reserve price of an English auction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReservePrice {
+    /// No reserve
+    None,
+    /// Reserve visible to bidders
+    Public(i128),
+    /// sha256(reserve price as 16 big-endian bytes || 32-byte salt), revealed by the seller
+    Hidden(BytesN<32>),
+}
+
+/// Optional English auction settings
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionOptions {
+    pub reserve: ReservePrice,
+    /// Minimum raise over the current bid in basis points (0 = any higher bid)
+    pub min_increment_bps: u32,
+    /// Bids in the final N seconds push `ends_at` to N seconds after the bid (0 = off)
+    pub anti_snipe_seconds: u64,
+}
+
 /// Auction information
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -97,6 +279,41 @@ pub struct Auction {
     pub started_at: u64,
     pub ends_at: u64,
     pub ended: bool,
+    pub reserve: ReservePrice,
+    pub min_increment_bps: u32,
+    pub anti_snipe_seconds: u64,
+}
+
+/// How a Dutch auction price falls from `start_price` to `end_price`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PriceCurve {
+    /// Straight line over the auction
+    Linear,
+    /// Halves every N seconds (linear within each half-life), floored at `end_price`.
+    /// The curve must fall to `end_price` by `end_at`.
+    Exponential(u64),
+}
+
+/// Price schedule of a Dutch auction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchPriceSchedule {
+    pub start_price: i128,
+    pub end_price: i128,
+    pub start_at: u64,
+    pub end_at: u64,
+    pub curve: PriceCurve,
+}
+
+/// Dutch (descending price) auction
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchAuction {
+    pub token_id: u32,
+    pub seller: Address,
+    pub payment_token: Address,
+    pub schedule: DutchPriceSchedule,
 }
 
 /// Storage keys
@@ -122,6 +339,22 @@ pub enum DataKey {
     ActiveAuctions,
     /// Reentrancy guard
     ReentrancyGuard,
+    /// Payment tokens accepted for listings, offers and auctions (Vec<Address>)
+    PaymentTokens,
+    /// Price oracle used for reference-asset listings
+    PriceOracle,
+    /// commitment_core contract used for listing details
+    CoreContract,
+    /// attestation_engine contract used for compliance scores
+    AttestationEngine,
+    /// Criteria-based offer (offer_id -> CriteriaOffer)
+    CriteriaOffer(u64),
+    /// Next criteria-based offer id
+    CriteriaOfferCounter,
+    /// Open criteria-based offer ids
+    ActiveCriteriaOffers,
+    /// Dutch auction data (token_id -> DutchAuction)
+    DutchAuction(u32),
 }
 
 #[cfg(test)]
@@ -197,17 +430,127 @@ impl CommitmentMarketplace {
         Ok(())
     }
 
+    /// Add or remove a payment token from the allowlist (admin only)
+    pub fn set_payment_token(e: Env, payment_token: Address, allowed: bool) -> Result<(), MarketplaceError> {
+        let admin: Address = Self::get_admin(e.clone())?;
+        admin.require_auth();
+
+        let mut tokens = Self::get_payment_tokens(e.clone());
+        let index = tokens.iter().position(|t| t == payment_token);
+        match (allowed, index) {
+            (true, None) => tokens.push_back(payment_token.clone()),
+            (false, Some(index)) => {
+                tokens.remove(index as u32);
+            }
+            _ => {}
+        }
+        e.storage().instance().set(&DataKey::PaymentTokens, &tokens);
+
+        e.events().publish(
+            (Symbol::new(&e, "PaymentTokenSet"), payment_token),
+            allowed,
+        );
+
+        Ok(())
+    }
+
+    /// Payment tokens accepted by the marketplace
+    pub fn get_payment_tokens(e: Env) -> Vec<Address> {
+        e.storage()
+            .instance()
+            .get(&DataKey::PaymentTokens)
+            .unwrap_or(Vec::new(&e))
+    }
+
+    /// Check whether a payment token is accepted
+    pub fn is_payment_token_allowed(e: Env, payment_token: Address) -> bool {
+        Self::get_payment_tokens(e).contains(&payment_token)
+    }
+
+    /// Set the price oracle used to convert reference-asset listing prices (admin only)
+    pub fn set_price_oracle(e: Env, oracle: Address) -> Result<(), MarketplaceError> {
+        let admin: Address = Self::get_admin(e.clone())?;
+        admin.require_auth();
+
+        e.storage().instance().set(&DataKey::PriceOracle, &oracle);
+
+        e.events().publish(
+            (Symbol::new(&e, "OracleSet"),),
+            oracle,
+        );
+
+        Ok(())
+    }
+
+    /// Get the price oracle, if configured
+    pub fn get_price_oracle(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::PriceOracle)
+    }
+
+    /// Set the commitment_core contract used for listing details (admin only)
+    pub fn set_core_contract(e: Env, core: Address) -> Result<(), MarketplaceError> {
+        let admin: Address = Self::get_admin(e.clone())?;
+        admin.require_auth();
+
+        e.storage().instance().set(&DataKey::CoreContract, &core);
+
+        e.events().publish(
+            (Symbol::new(&e, "CoreSet"),),
+            core,
+        );
+
+        Ok(())
+    }
+
+    /// Get the commitment_core contract, if configured
+    pub fn get_core_contract(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::CoreContract)
+    }
+
+    /// Set the attestation_engine contract used for compliance scores (admin only)
+    pub fn set_attestation_engine(e: Env, engine: Address) -> Result<(), MarketplaceError> {
+        let admin: Address = Self::get_admin(e.clone())?;
+        admin.require_auth();
+
+        e.storage().instance().set(&DataKey::AttestationEngine, &engine);
+
+        e.events().publish(
+            (Symbol::new(&e, "AttestSet"),),
+            engine,
+        );
+
+        Ok(())
+    }
+
+    /// Get the attestation_engine contract, if configured
+    pub fn get_attestation_engine(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::AttestationEngine)
+    }
+
+    /// Get the NFT contract address
+    pub fn get_nft_contract(e: Env) -> Result<Address, MarketplaceError> {
+        e.storage()
+            .instance()
+            .get(&DataKey::NFTContract)
+            .ok_or(MarketplaceError::NotInitialized)
+    }
+
     // ========================================================================
     // Listing Management
     // ========================================================================
 
     /// List an NFT for sale
     ///
+    /// Fails while the token has an unexpired listing or a live auction.
+    ///
     /// # Arguments
-    /// * `seller` - The seller's address (must be NFT owner)
+    /// * `seller` - The seller's address (must be NFT owner and have approved the marketplace)
     /// * `token_id` - The NFT token ID to list
-    /// * `price` - The sale price
-    /// * `payment_token` - The token contract address for payment
+    /// * `price` - The sale price, in `payment_token` or in `price_asset` when set
+    /// * `payment_token` - The token contract address for payment (must be allowlisted)
+    /// * `expires_at` - Timestamp after which the listing can't be bought (0 = never)
+    /// * `price_asset` - Optional reference asset, converted to `payment_token`
+    ///   through the price oracle at buy time
     ///
     /// # Reentrancy Protection
     /// Protected with reentrancy guard as it makes external NFT contract calls
@@ -217,6 +560,8 @@ impl CommitmentMarketplace {
         token_id: u32,
         price: i128,
         payment_token: Address,
+        expires_at: u64,
+        price_asset: Option<Address>,
     ) -> Result<(), MarketplaceError> {
         // Reentrancy protection
         let guard: bool = e.storage()
@@ -236,14 +581,30 @@ impl CommitmentMarketplace {
             return Err(MarketplaceError::InvalidPrice);
         }
 
-        // Check if listing already exists
-        if e.storage().persistent().has(&DataKey::Listing(token_id)) {
+        if expires_at != 0 && expires_at <= e.ledger().timestamp() {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::InvalidExpiration);
+        }
+
+        if !Self::is_payment_token_allowed(e.clone(), payment_token.clone()) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::PaymentTokenNotAllowed);
+        }
+
+        if price_asset.is_some() && !e.storage().instance().has(&DataKey::PriceOracle) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::PriceConversionFailed);
+        }
+
+        // Check for a live listing or auction (expired listings may be replaced)
+        let existing: Option<Listing> = e.storage().persistent().get(&DataKey::Listing(token_id));
+        if has_live_listing_or_auction(&e, token_id) || has_live_dutch_auction(&e, token_id) {
             e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
             return Err(MarketplaceError::ListingExists);
         }
 
-        // Verify seller owns the NFT (external call - after checks)
-        let _nft_contract: Address = e.storage()
+        // Verify seller owns the NFT and approved the marketplace (external call - after checks)
+        let nft_contract: Address = e.storage()
             .instance()
             .get(&DataKey::NFTContract)
             .ok_or_else(|| {
@@ -251,9 +612,9 @@ impl CommitmentMarketplace {
                 MarketplaceError::NotInitialized
             })?;
 
-        // Note: This would require the NFT contract client
-        // For now, we assume the caller has verified ownership
-        // In production, you'd call: nft_contract.owner_of(&token_id)
+        check_seller_access(&e, &nft_contract, &seller, token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
 
         // EFFECTS
         let listing = Listing {
@@ -262,17 +623,21 @@ impl CommitmentMarketplace {
             price,
             payment_token: payment_token.clone(),
             listed_at: e.ledger().timestamp(),
+            expires_at,
+            price_asset,
         };
 
         e.storage().persistent().set(&DataKey::Listing(token_id), &listing);
 
-        // Add to active listings
-        let mut active_listings: Vec<u32> = e.storage()
-            .instance()
-            .get(&DataKey::ActiveListings)
-            .unwrap_or(Vec::new(&e));
-        active_listings.push_back(token_id);
-        e.storage().instance().set(&DataKey::ActiveListings, &active_listings);
+        // Add to active listings (a replaced expired listing is already there)
+        if existing.is_none() {
+            let mut active_listings: Vec<u32> = e.storage()
+                .instance()
+                .get(&DataKey::ActiveListings)
+                .unwrap_or(Vec::new(&e));
+            active_listings.push_back(token_id);
+            e.storage().instance().set(&DataKey::ActiveListings, &active_listings);
+        }
 
         // Clear reentrancy guard
         e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
@@ -318,18 +683,8 @@ impl CommitmentMarketplace {
         }
 
         // EFFECTS
-        // Remove listing
-        e.storage().persistent().remove(&DataKey::Listing(token_id));
-
-        // Remove from active listings
-        let mut active_listings: Vec<u32> = e.storage()
-            .instance()
-            .get(&DataKey::ActiveListings)
-            .unwrap_or(Vec::new(&e));
-        if let Some(index) = active_listings.iter().position(|id| id == token_id) {
-            active_listings.remove(index as u32);
-        }
-        e.storage().instance().set(&DataKey::ActiveListings, &active_listings);
+        // Remove listing and its active listings entry
+        remove_listing(&e, token_id);
 
         // Clear reentrancy guard
         e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
@@ -343,6 +698,60 @@ impl CommitmentMarketplace {
         Ok(())
     }
 
+    /// Update the price of a listing without cancelling it
+    ///
+    /// The new price uses the listing's existing denomination.
+    pub fn update_listing_price(
+        e: Env,
+        seller: Address,
+        token_id: u32,
+        new_price: i128,
+    ) -> Result<(), MarketplaceError> {
+        seller.require_auth();
+
+        if new_price <= 0 {
+            return Err(MarketplaceError::InvalidPrice);
+        }
+
+        let mut listing: Listing = e.storage()
+            .persistent()
+            .get(&DataKey::Listing(token_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        if listing.seller != seller {
+            return Err(MarketplaceError::NotSeller);
+        }
+
+        if is_listing_expired(&e, &listing) {
+            return Err(MarketplaceError::ListingExpired);
+        }
+
+        let old_price = listing.price;
+        listing.price = new_price;
+        e.storage().persistent().set(&DataKey::Listing(token_id), &listing);
+
+        e.events().publish(
+            (symbol_short!("ListPrice"), token_id),
+            (seller, old_price, new_price),
+        );
+
+        Ok(())
+    }
+
+    /// Amount of the listing's payment token a buyer would pay now
+    pub fn get_listing_price(e: Env, token_id: u32) -> Result<i128, MarketplaceError> {
+        let listing: Listing = e.storage()
+            .persistent()
+            .get(&DataKey::Listing(token_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        if is_listing_expired(&e, &listing) {
+            return Err(MarketplaceError::ListingExpired);
+        }
+
+        listing_payment_amount(&e, &listing)
+    }
+
     /// Buy an NFT
     ///
     /// # Arguments
@@ -378,6 +787,23 @@ impl CommitmentMarketplace {
             return Err(MarketplaceError::CannotBuyOwnListing);
         }
 
+        match listing_status(&e, &listing) {
+            ListingStatus::Fillable => {}
+            ListingStatus::Expired => {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                return Err(MarketplaceError::ListingExpired);
+            }
+            _ => {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                return Err(MarketplaceError::ListingNotFillable);
+            }
+        }
+
+        // Resolve the price in the payment token (oracle conversion for reference-asset listings)
+        let price = listing_payment_amount(&e, &listing).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
         let fee_basis_points: u32 = e.storage()
             .instance()
             .get(&DataKey::MarketplaceFee)
@@ -391,7 +817,7 @@ impl CommitmentMarketplace {
                 MarketplaceError::NotInitialized
             })?;
 
-        let _nft_contract: Address = e.storage()
+        let nft_contract: Address = e.storage()
             .instance()
             .get(&DataKey::NFTContract)
             .ok_or_else(|| {
@@ -399,39 +825,20 @@ impl CommitmentMarketplace {
                 MarketplaceError::NotInitialized
             })?;
 
-        // Calculate fee and seller proceeds
-        let marketplace_fee = (listing.price * fee_basis_points as i128) / 10000;
-        let seller_proceeds = listing.price - marketplace_fee;
+        // Calculate fee, royalty and seller proceeds
+        let split = sale_split(&e, token_id, price, fee_basis_points);
 
         // EFFECTS
         // Remove listing first (prevent reentrancy)
-        e.storage().persistent().remove(&DataKey::Listing(token_id));
-
-        // Remove from active listings
-        let mut active_listings: Vec<u32> = e.storage()
-            .instance()
-            .get(&DataKey::ActiveListings)
-            .unwrap_or(Vec::new(&e));
-        if let Some(index) = active_listings.iter().position(|id| id == token_id) {
-            active_listings.remove(index as u32);
-        }
-        e.storage().instance().set(&DataKey::ActiveListings, &active_listings);
+        remove_listing(&e, token_id);
+        e.storage().persistent().remove(&DataKey::DutchAuction(token_id));
 
         // INTERACTIONS - External calls AFTER state changes
-        // Transfer payment token from buyer to seller
-        let payment_token_client = token::Client::new(&e, &listing.payment_token);
-        payment_token_client.transfer(&buyer, &listing.seller, &seller_proceeds);
+        // Pay seller, marketplace fee and royalty from the buyer
+        pay_sale(&e, &listing.payment_token, &buyer, &listing.seller, &fee_recipient, token_id, &split);
 
-        // Transfer marketplace fee if applicable
-        if marketplace_fee > 0 {
-            payment_token_client.transfer(&buyer, &fee_recipient, &marketplace_fee);
-        }
-
-        // Transfer NFT from seller to buyer
-        // Note: In production, you'd use the NFT contract client:
-        // let nft_client = CommitmentNFTContractClient::new(&e, &nft_contract);
-        // nft_client.transfer(&listing.seller, &buyer, &token_id);
-        // For this implementation, we assume the transfer happens correctly
+        // Transfer NFT from seller to buyer using the marketplace's approval
+        transfer_nft(&e, &nft_contract, &listing.seller, &buyer, token_id);
 
         // Clear reentrancy guard
         e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
@@ -439,7 +846,7 @@ impl CommitmentMarketplace {
         // Emit event
         e.events().publish(
             (symbol_short!("NFTSold"), token_id),
-            (listing.seller, buyer, listing.price),
+            (listing.seller, buyer, price),
         );
 
         Ok(())
@@ -453,7 +860,99 @@ impl CommitmentMarketplace {
             .ok_or(MarketplaceError::ListingNotFound)
     }
 
-    /// Get all active listings
+    /// Whether a listing can currently be filled
+    pub fn get_listing_status(e: Env, token_id: u32) -> Result<ListingStatus, MarketplaceError> {
+        let listing: Listing = e.storage()
+            .persistent()
+            .get(&DataKey::Listing(token_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        Ok(listing_status(&e, &listing))
+    }
+
+    /// Listing with live commitment data: metadata, current value, time to
+    /// expiry, early exit penalty, compliance score and premium/discount
+    pub fn get_listing_details(e: Env, token_id: u32) -> Result<ListingDetails, MarketplaceError> {
+        let listing: Listing = e.storage()
+            .persistent()
+            .get(&DataKey::Listing(token_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        let status = listing_status(&e, &listing);
+        let price = listing_payment_amount(&e, &listing).ok();
+        let nft = read_commitment_nft(&e, token_id);
+        let commitment = nft
+            .as_ref()
+            .and_then(|nft| read_commitment(&e, &nft.metadata.commitment_id));
+        let compliance_score = nft
+            .as_ref()
+            .and_then(|nft| read_compliance_score(&e, &nft.metadata.commitment_id));
+
+        let expires_at = commitment
+            .as_ref()
+            .map(|c| c.expires_at)
+            .or(nft.as_ref().map(|nft| nft.metadata.expires_at));
+        let remaining_seconds = expires_at.map(|t| t.saturating_sub(e.ledger().timestamp()));
+        let early_exit_penalty = commitment
+            .as_ref()
+            .map(|c| c.rules.early_exit_penalty)
+            .or(nft.as_ref().map(|nft| nft.early_exit_penalty));
+        let current_value = commitment.as_ref().map(|c| c.current_value);
+
+        let premium_bps = match (&commitment, price) {
+            (Some(c), Some(price)) if c.asset_address == listing.payment_token && c.current_value > 0 => {
+                price
+                    .checked_sub(c.current_value)
+                    .and_then(|premium| premium.checked_mul(10000))
+                    .map(|premium| premium / c.current_value)
+            }
+            _ => None,
+        };
+
+        Ok(ListingDetails {
+            listing,
+            status,
+            price,
+            commitment_id: nft.as_ref().map(|nft| nft.metadata.commitment_id.clone()),
+            commitment_type: nft.as_ref().map(|nft| nft.metadata.commitment_type.clone()),
+            max_loss_percent: nft.as_ref().map(|nft| nft.metadata.max_loss_percent),
+            initial_amount: nft.as_ref().map(|nft| nft.metadata.initial_amount),
+            early_exit_penalty,
+            current_value,
+            expires_at,
+            remaining_seconds,
+            compliance_score,
+            premium_bps,
+        })
+    }
+
+    /// Remove a listing that can no longer be filled
+    ///
+    /// Callable by anyone, so listings are delisted once the seller moves the
+    /// NFT or revokes the marketplace's approval. Returns false if the listing
+    /// is still fillable.
+    pub fn delist_unfillable(e: Env, token_id: u32) -> Result<bool, MarketplaceError> {
+        let listing: Listing = e.storage()
+            .persistent()
+            .get(&DataKey::Listing(token_id))
+            .ok_or(MarketplaceError::ListingNotFound)?;
+
+        let status = listing_status(&e, &listing);
+        if status == ListingStatus::Fillable {
+            return Ok(false);
+        }
+
+        remove_listing(&e, token_id);
+
+        e.events().publish(
+            (symbol_short!("Delisted"), token_id),
+            (listing.seller, status),
+        );
+
+        Ok(true)
+    }
+
+    /// Get all fillable listings (expired and stale listings are skipped)
     pub fn get_all_listings(e: Env) -> Vec<Listing> {
         let active_listings: Vec<u32> = e.storage()
             .instance()
@@ -464,7 +963,9 @@ impl CommitmentMarketplace {
 
         for token_id in active_listings.iter() {
             if let Some(listing) = e.storage().persistent().get::<_, Listing>(&DataKey::Listing(token_id)) {
-                listings.push_back(listing);
+                if listing_status(&e, &listing) == ListingStatus::Fillable {
+                    listings.push_back(listing);
+                }
             }
         }
 
@@ -504,6 +1005,11 @@ impl CommitmentMarketplace {
             return Err(MarketplaceError::InvalidOfferAmount);
         }
 
+        if !Self::is_payment_token_allowed(e.clone(), payment_token.clone()) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::PaymentTokenNotAllowed);
+        }
+
         // EFFECTS
         let offer = Offer {
             token_id,
@@ -581,6 +1087,18 @@ impl CommitmentMarketplace {
 
         let offer = offers.get(offer_index as u32).unwrap();
 
+        let nft_contract: Address = e.storage()
+            .instance()
+            .get(&DataKey::NFTContract)
+            .ok_or_else(|| {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                MarketplaceError::NotInitialized
+            })?;
+
+        check_seller_access(&e, &nft_contract, &seller, token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
         let fee_basis_points: u32 = e.storage()
             .instance()
             .get(&DataKey::MarketplaceFee)
@@ -594,39 +1112,25 @@ impl CommitmentMarketplace {
                 MarketplaceError::NotInitialized
             })?;
 
-        // Calculate fee and seller proceeds
-        let marketplace_fee = (offer.amount * fee_basis_points as i128) / 10000;
-        let seller_proceeds = offer.amount - marketplace_fee;
+        // Calculate fee, royalty and seller proceeds
+        let split = sale_split(&e, token_id, offer.amount, fee_basis_points);
 
         // EFFECTS
         // Remove all offers for this token
         e.storage().persistent().remove(&DataKey::Offers(token_id));
 
-        // Remove listing if exists
+        // Remove listing and Dutch auction if they exist
         if e.storage().persistent().has(&DataKey::Listing(token_id)) {
-            e.storage().persistent().remove(&DataKey::Listing(token_id));
-
-            let mut active_listings: Vec<u32> = e.storage()
-                .instance()
-                .get(&DataKey::ActiveListings)
-                .unwrap_or(Vec::new(&e));
-            if let Some(index) = active_listings.iter().position(|id| id == token_id) {
-                active_listings.remove(index as u32);
-            }
-            e.storage().instance().set(&DataKey::ActiveListings, &active_listings);
+            remove_listing(&e, token_id);
         }
+        e.storage().persistent().remove(&DataKey::DutchAuction(token_id));
 
         // INTERACTIONS
         // Transfer payment
-        let payment_token_client = token::Client::new(&e, &offer.payment_token);
-        payment_token_client.transfer(&offerer, &seller, &seller_proceeds);
-
-        if marketplace_fee > 0 {
-            payment_token_client.transfer(&offerer, &fee_recipient, &marketplace_fee);
-        }
+        pay_sale(&e, &offer.payment_token, &offerer, &seller, &fee_recipient, token_id, &split);
 
-        // Transfer NFT
-        // Note: Use NFT contract client in production
+        // Transfer NFT using the marketplace's approval
+        transfer_nft(&e, &nft_contract, &seller, &offerer, token_id);
 
         // Clear reentrancy guard
         e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
@@ -677,13 +1181,276 @@ impl CommitmentMarketplace {
     }
 
     // ========================================================================
-    // Auction System
+    // Criteria-Based Offers
     // ========================================================================
 
-    /// Start an auction
+    /// Offer `price_per_unit` for each of up to `quantity` tokens matching `criteria`
+    ///
+    /// The full amount is escrowed up front. Any holder of a matching token can
+    /// fill the offer one token at a time; the criteria are checked against the
+    /// token's commitment data at fill time.
     ///
     /// # Reentrancy Protection
-    /// Protected with reentrancy guard
+    /// Critical - handles token transfers. Protected with reentrancy guard.
+    pub fn make_criteria_offer(
+        e: Env,
+        offerer: Address,
+        criteria: OfferCriteria,
+        price_per_unit: i128,
+        quantity: u32,
+        payment_token: Address,
+    ) -> Result<u64, MarketplaceError> {
+        // Reentrancy protection
+        let guard: bool = e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false);
+        if guard {
+            return Err(MarketplaceError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // CHECKS
+        offerer.require_auth();
+
+        let escrow = match price_per_unit.checked_mul(quantity as i128) {
+            Some(escrow) if escrow > 0 => escrow,
+            _ => {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                return Err(MarketplaceError::InvalidOfferAmount);
+            }
+        };
+
+        if !Self::is_payment_token_allowed(e.clone(), payment_token.clone()) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::PaymentTokenNotAllowed);
+        }
+
+        // EFFECTS
+        let offer_id: u64 = e.storage()
+            .instance()
+            .get(&DataKey::CriteriaOfferCounter)
+            .unwrap_or(0);
+        e.storage().instance().set(&DataKey::CriteriaOfferCounter, &(offer_id + 1));
+
+        let offer = CriteriaOffer {
+            offer_id,
+            offerer: offerer.clone(),
+            criteria,
+            price_per_unit,
+            payment_token: payment_token.clone(),
+            quantity,
+            filled: 0,
+            created_at: e.ledger().timestamp(),
+        };
+        e.storage().persistent().set(&DataKey::CriteriaOffer(offer_id), &offer);
+
+        let mut active_offers: Vec<u64> = e.storage()
+            .instance()
+            .get(&DataKey::ActiveCriteriaOffers)
+            .unwrap_or(Vec::new(&e));
+        active_offers.push_back(offer_id);
+        e.storage().instance().set(&DataKey::ActiveCriteriaOffers, &active_offers);
+
+        // INTERACTIONS
+        // Escrow the full amount
+        token::Client::new(&e, &payment_token).transfer(&offerer, &e.current_contract_address(), &escrow);
+
+        // Clear reentrancy guard
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+
+        e.events().publish(
+            (symbol_short!("CritOffer"), offer_id),
+            (offerer, price_per_unit, quantity, payment_token),
+        );
+
+        Ok(offer_id)
+    }
+
+    /// Sell `token_id` into a criteria-based offer
+    ///
+    /// Fills one unit: the seller receives `price_per_unit` (minus fees and
+    /// royalty) from escrow and the NFT goes to the offerer.
+    ///
+    /// # Reentrancy Protection
+    /// Critical - handles token transfers. Protected with reentrancy guard.
+    pub fn fill_criteria_offer(
+        e: Env,
+        seller: Address,
+        offer_id: u64,
+        token_id: u32,
+    ) -> Result<(), MarketplaceError> {
+        // Reentrancy protection
+        let guard: bool = e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false);
+        if guard {
+            return Err(MarketplaceError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // CHECKS
+        seller.require_auth();
+
+        let mut offer = Self::get_criteria_offer(e.clone(), offer_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
+        if offer.offerer == seller {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::CannotBuyOwnListing);
+        }
+
+        check_offer_criteria(&e, &offer.criteria, token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
+        let nft_contract: Address = e.storage()
+            .instance()
+            .get(&DataKey::NFTContract)
+            .ok_or_else(|| {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                MarketplaceError::NotInitialized
+            })?;
+
+        check_seller_access(&e, &nft_contract, &seller, token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
+        let fee_basis_points: u32 = e.storage()
+            .instance()
+            .get(&DataKey::MarketplaceFee)
+            .unwrap_or(0);
+
+        let fee_recipient: Address = e.storage()
+            .instance()
+            .get(&DataKey::FeeRecipient)
+            .ok_or_else(|| {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                MarketplaceError::NotInitialized
+            })?;
+
+        let split = sale_split(&e, token_id, offer.price_per_unit, fee_basis_points);
+
+        // EFFECTS
+        offer.filled += 1;
+        if offer.filled == offer.quantity {
+            remove_criteria_offer(&e, offer_id);
+        } else {
+            e.storage().persistent().set(&DataKey::CriteriaOffer(offer_id), &offer);
+        }
+
+        if e.storage().persistent().has(&DataKey::Listing(token_id)) {
+            remove_listing(&e, token_id);
+        }
+        e.storage().persistent().remove(&DataKey::DutchAuction(token_id));
+
+        // INTERACTIONS
+        // Pay from escrow
+        pay_sale(
+            &e,
+            &offer.payment_token,
+            &e.current_contract_address(),
+            &seller,
+            &fee_recipient,
+            token_id,
+            &split,
+        );
+
+        transfer_nft(&e, &nft_contract, &seller, &offer.offerer, token_id);
+
+        // Clear reentrancy guard
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+
+        e.events().publish(
+            (symbol_short!("CritFill"), offer_id),
+            (seller, token_id, offer.price_per_unit, offer.quantity - offer.filled),
+        );
+
+        Ok(())
+    }
+
+    /// Cancel a criteria-based offer and refund the unfilled escrow
+    ///
+    /// # Reentrancy Protection
+    /// Critical - handles token transfers. Protected with reentrancy guard.
+    pub fn cancel_criteria_offer(e: Env, offerer: Address, offer_id: u64) -> Result<(), MarketplaceError> {
+        // Reentrancy protection
+        let guard: bool = e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false);
+        if guard {
+            return Err(MarketplaceError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // CHECKS
+        offerer.require_auth();
+
+        let offer = Self::get_criteria_offer(e.clone(), offer_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
+        if offer.offerer != offerer {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::OfferNotFound);
+        }
+
+        // EFFECTS
+        remove_criteria_offer(&e, offer_id);
+
+        // INTERACTIONS
+        let refund = offer.price_per_unit * (offer.quantity - offer.filled) as i128;
+        token::Client::new(&e, &offer.payment_token).transfer(&e.current_contract_address(), &offerer, &refund);
+
+        // Clear reentrancy guard
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+
+        e.events().publish(
+            (symbol_short!("CritCanc"), offer_id),
+            (offerer, refund),
+        );
+
+        Ok(())
+    }
+
+    /// Get a criteria-based offer
+    pub fn get_criteria_offer(e: Env, offer_id: u64) -> Result<CriteriaOffer, MarketplaceError> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::CriteriaOffer(offer_id))
+            .ok_or(MarketplaceError::OfferNotFound)
+    }
+
+    /// Get all open criteria-based offers
+    pub fn get_criteria_offers(e: Env) -> Vec<CriteriaOffer> {
+        let active_offers: Vec<u64> = e.storage()
+            .instance()
+            .get(&DataKey::ActiveCriteriaOffers)
+            .unwrap_or(Vec::new(&e));
+
+        let mut offers = Vec::new(&e);
+        for offer_id in active_offers.iter() {
+            if let Some(offer) = e.storage().persistent().get(&DataKey::CriteriaOffer(offer_id)) {
+                offers.push_back(offer);
+            }
+        }
+        offers
+    }
+
+    /// Whether `token_id` currently satisfies a criteria-based offer
+    pub fn matches_criteria_offer(e: Env, offer_id: u64, token_id: u32) -> Result<bool, MarketplaceError> {
+        let offer = Self::get_criteria_offer(e.clone(), offer_id)?;
+        Ok(check_offer_criteria(&e, &offer.criteria, token_id).is_ok())
+    }
+
+    // ========================================================================
+    // Auction System
+    // ========================================================================
+
+    /// Start an auction with no reserve, bid increment or anti-sniping
     pub fn start_auction(
         e: Env,
         seller: Address,
@@ -691,6 +1458,39 @@ impl CommitmentMarketplace {
         starting_price: i128,
         duration_seconds: u64,
         payment_token: Address,
+    ) -> Result<(), MarketplaceError> {
+        let options = AuctionOptions {
+            reserve: ReservePrice::None,
+            min_increment_bps: 0,
+            anti_snipe_seconds: 0,
+        };
+        Self::start_auction_with_options(
+            e,
+            seller,
+            token_id,
+            starting_price,
+            duration_seconds,
+            payment_token,
+            options,
+        )
+    }
+
+    /// Start an auction with a reserve price, minimum bid increment and anti-sniping
+    ///
+    /// The seller must own the NFT and have approved the marketplace. A token
+    /// whose previous auction has ended can be auctioned again, but not while
+    /// it has an unexpired listing.
+    ///
+    /// # Reentrancy Protection
+    /// Protected with reentrancy guard
+    pub fn start_auction_with_options(
+        e: Env,
+        seller: Address,
+        token_id: u32,
+        starting_price: i128,
+        duration_seconds: u64,
+        payment_token: Address,
+        options: AuctionOptions,
     ) -> Result<(), MarketplaceError> {
         // Reentrancy protection
         let guard: bool = e.storage()
@@ -715,11 +1515,37 @@ impl CommitmentMarketplace {
             return Err(MarketplaceError::InvalidDuration);
         }
 
-        if e.storage().persistent().has(&DataKey::Auction(token_id)) {
+        if !Self::is_payment_token_allowed(e.clone(), payment_token.clone()) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::PaymentTokenNotAllowed);
+        }
+
+        let valid_reserve = match options.reserve {
+            ReservePrice::Public(reserve) => reserve > 0,
+            _ => true,
+        };
+        if !valid_reserve || options.min_increment_bps > 10000 {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::InvalidAuctionOptions);
+        }
+
+        if has_live_listing_or_auction(&e, token_id) || has_live_dutch_auction(&e, token_id) {
             e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
             return Err(MarketplaceError::ListingExists);
         }
 
+        let nft_contract: Address = e.storage()
+            .instance()
+            .get(&DataKey::NFTContract)
+            .ok_or_else(|| {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                MarketplaceError::NotInitialized
+            })?;
+
+        check_seller_access(&e, &nft_contract, &seller, token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
         // EFFECTS
         let started_at = e.ledger().timestamp();
         let ends_at = started_at + duration_seconds;
@@ -734,6 +1560,9 @@ impl CommitmentMarketplace {
             started_at,
             ends_at,
             ended: false,
+            reserve: options.reserve,
+            min_increment_bps: options.min_increment_bps,
+            anti_snipe_seconds: options.anti_snipe_seconds,
         };
 
         e.storage().persistent().set(&DataKey::Auction(token_id), &auction);
@@ -794,7 +1623,7 @@ impl CommitmentMarketplace {
             return Err(MarketplaceError::AuctionEnded);
         }
 
-        if bid_amount <= auction.current_bid {
+        if bid_amount < min_next_bid(&auction) {
             e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
             return Err(MarketplaceError::BidTooLow);
         }
@@ -811,6 +1640,13 @@ impl CommitmentMarketplace {
         auction.current_bid = bid_amount;
         auction.highest_bidder = Some(bidder.clone());
 
+        // Anti-sniping: late bids extend the auction
+        let extended = auction.anti_snipe_seconds > 0
+            && auction.ends_at - current_time < auction.anti_snipe_seconds;
+        if extended {
+            auction.ends_at = current_time + auction.anti_snipe_seconds;
+        }
+
         e.storage().persistent().set(&DataKey::Auction(token_id), &auction);
 
         // INTERACTIONS
@@ -832,6 +1668,67 @@ impl CommitmentMarketplace {
             (symbol_short!("BidPlaced"), token_id),
             (bidder, bid_amount),
         );
+        if extended {
+            e.events().publish(
+                (symbol_short!("AucExtend"), token_id),
+                auction.ends_at,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Lowest bid `place_bid` currently accepts
+    pub fn get_min_bid(e: Env, token_id: u32) -> Result<i128, MarketplaceError> {
+        let auction: Auction = e.storage()
+            .persistent()
+            .get(&DataKey::Auction(token_id))
+            .ok_or(MarketplaceError::AuctionNotFound)?;
+
+        Ok(min_next_bid(&auction))
+    }
+
+    /// Reveal a hidden reserve price so `end_auction` can check it
+    ///
+    /// Must be called before the auction's `ends_at`. A reserve still hidden
+    /// when the auction ends counts as not met: `end_auction` refunds the
+    /// highest bid and the NFT stays with the seller.
+    pub fn reveal_reserve(
+        e: Env,
+        seller: Address,
+        token_id: u32,
+        reserve_price: i128,
+        salt: BytesN<32>,
+    ) -> Result<(), MarketplaceError> {
+        seller.require_auth();
+
+        let mut auction: Auction = e.storage()
+            .persistent()
+            .get(&DataKey::Auction(token_id))
+            .ok_or(MarketplaceError::AuctionNotFound)?;
+
+        if auction.seller != seller {
+            return Err(MarketplaceError::NotSeller);
+        }
+        if auction.ended || e.ledger().timestamp() >= auction.ends_at {
+            return Err(MarketplaceError::AuctionEnded);
+        }
+
+        let commitment = match &auction.reserve {
+            ReservePrice::Hidden(commitment) => commitment.clone(),
+            _ => return Err(MarketplaceError::InvalidReserveReveal),
+        };
+        if reserve_price <= 0 || hash_reserve(&e, reserve_price, &salt) != commitment {
+            return Err(MarketplaceError::InvalidReserveReveal);
+        }
+
+        auction.reserve = ReservePrice::Public(reserve_price);
+        e.storage().persistent().set(&DataKey::Auction(token_id), &auction);
+
+        e.events().publish(
+            (symbol_short!("ResReveal"), token_id),
+            reserve_price,
+        );
 
         Ok(())
     }
@@ -898,33 +1795,69 @@ impl CommitmentMarketplace {
         }
         e.storage().instance().set(&DataKey::ActiveAuctions, &active_auctions);
 
-        // INTERACTIONS
-        if let Some(winner) = auction.highest_bidder {
-            // Calculate fees
-            let marketplace_fee = (auction.current_bid * fee_basis_points as i128) / 10000;
-            let seller_proceeds = auction.current_bid - marketplace_fee;
-
-            let payment_token_client = token::Client::new(&e, &auction.payment_token);
-
-            // Transfer payment from escrow to seller
-            payment_token_client.transfer(&e.current_contract_address(), &auction.seller, &seller_proceeds);
+        let nft_contract: Address = e.storage()
+            .instance()
+            .get(&DataKey::NFTContract)
+            .ok_or_else(|| {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                MarketplaceError::NotInitialized
+            })?;
 
-            // Transfer fee
-            if marketplace_fee > 0 {
-                payment_token_client.transfer(&e.current_contract_address(), &fee_recipient, &marketplace_fee);
+        // INTERACTIONS
+        if let Some(winner) = auction.highest_bidder.clone() {
+            let reserve_met = match auction.reserve {
+                ReservePrice::None => true,
+                ReservePrice::Public(reserve) => auction.current_bid >= reserve,
+                ReservePrice::Hidden(_) => false,
+            };
+            let deliverable = reserve_met
+                && check_seller_access(&e, &nft_contract, &auction.seller, token_id).is_ok();
+
+            if deliverable {
+                // Calculate fees and royalty, then pay out from escrow
+                let split = sale_split(&e, token_id, auction.current_bid, fee_basis_points);
+                pay_sale(
+                    &e,
+                    &auction.payment_token,
+                    &e.current_contract_address(),
+                    &auction.seller,
+                    &fee_recipient,
+                    token_id,
+                    &split,
+                );
+
+                // Transfer NFT to winner using the marketplace's approval
+                transfer_nft(&e, &nft_contract, &auction.seller, &winner, token_id);
+            } else {
+                // Reserve not met or NFT no longer deliverable - refund the
+                // winning bid; the NFT stays with the seller
+                token::Client::new(&e, &auction.payment_token).transfer(
+                    &e.current_contract_address(),
+                    &winner,
+                    &auction.current_bid,
+                );
             }
 
-            // Transfer NFT to winner
-            // Note: Use NFT contract client in production
-
             // Clear reentrancy guard
             e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
 
             // Emit event
-            e.events().publish(
-                (symbol_short!("AucEnd"), token_id),
-                (winner, auction.current_bid),
-            );
+            if deliverable {
+                e.events().publish(
+                    (symbol_short!("AucEnd"), token_id),
+                    (winner, auction.current_bid),
+                );
+            } else if !reserve_met {
+                e.events().publish(
+                    (symbol_short!("AucResv"), token_id),
+                    (auction.seller, winner, auction.current_bid),
+                );
+            } else {
+                e.events().publish(
+                    (symbol_short!("AucUndel"), token_id),
+                    (auction.seller, winner, auction.current_bid),
+                );
+            }
         } else {
             // No bids - return NFT to seller
 
@@ -940,6 +1873,204 @@ impl CommitmentMarketplace {
         Ok(())
     }
 
+    // ========================================================================
+    // Dutch Auctions
+    // ========================================================================
+
+    /// Start a Dutch auction whose price falls from `start_price` at `start_at`
+    /// to `end_price` at `end_at`
+    ///
+    /// The seller must own the NFT and have approved the marketplace. The
+    /// auction can't be bought before `start_at` or after `end_at`, and can't
+    /// start while the token has a live listing or English auction.
+    ///
+    /// # Reentrancy Protection
+    /// Protected with reentrancy guard as it makes external NFT contract calls
+    pub fn start_dutch_auction(
+        e: Env,
+        seller: Address,
+        token_id: u32,
+        payment_token: Address,
+        schedule: DutchPriceSchedule,
+    ) -> Result<(), MarketplaceError> {
+        // Reentrancy protection
+        let guard: bool = e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false);
+        if guard {
+            return Err(MarketplaceError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // CHECKS
+        seller.require_auth();
+
+        if schedule.end_price <= 0 || schedule.start_price <= schedule.end_price {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::InvalidPrice);
+        }
+        if schedule.end_at <= schedule.start_at
+            || schedule.end_at <= e.ledger().timestamp()
+            || !is_valid_price_curve(&schedule)
+        {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::InvalidPriceSchedule);
+        }
+
+        if !Self::is_payment_token_allowed(e.clone(), payment_token.clone()) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::PaymentTokenNotAllowed);
+        }
+
+        // A finished Dutch auction may be replaced
+        if has_live_dutch_auction(&e, token_id) || has_live_listing_or_auction(&e, token_id) {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::ListingExists);
+        }
+
+        let nft_contract = Self::get_nft_contract(e.clone()).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+        check_seller_access(&e, &nft_contract, &seller, token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
+        // EFFECTS
+        let auction = DutchAuction {
+            token_id,
+            seller: seller.clone(),
+            payment_token: payment_token.clone(),
+            schedule: schedule.clone(),
+        };
+        e.storage().persistent().set(&DataKey::DutchAuction(token_id), &auction);
+
+        // Clear reentrancy guard
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+
+        e.events().publish(
+            (symbol_short!("DutchStrt"), token_id),
+            (seller, schedule.start_price, schedule.end_price, schedule.end_at),
+        );
+
+        Ok(())
+    }
+
+    /// Current price of a Dutch auction
+    pub fn get_dutch_price(e: Env, token_id: u32) -> Result<i128, MarketplaceError> {
+        let auction = Self::get_dutch_auction(e.clone(), token_id)?;
+        dutch_price(&auction.schedule, e.ledger().timestamp()).ok_or(MarketplaceError::InvalidPriceSchedule)
+    }
+
+    /// Get Dutch auction details
+    pub fn get_dutch_auction(e: Env, token_id: u32) -> Result<DutchAuction, MarketplaceError> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::DutchAuction(token_id))
+            .ok_or(MarketplaceError::AuctionNotFound)
+    }
+
+    /// Buy a Dutch auction at its current price
+    ///
+    /// # Reentrancy Protection
+    /// Critical - handles token transfers. Protected with reentrancy guard.
+    pub fn buy_dutch(e: Env, buyer: Address, token_id: u32) -> Result<i128, MarketplaceError> {
+        // Reentrancy protection
+        let guard: bool = e.storage()
+            .instance()
+            .get(&DataKey::ReentrancyGuard)
+            .unwrap_or(false);
+        if guard {
+            return Err(MarketplaceError::ReentrancyDetected);
+        }
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+
+        // CHECKS
+        buyer.require_auth();
+
+        let auction = Self::get_dutch_auction(e.clone(), token_id).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+
+        if auction.seller == buyer {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::CannotBuyOwnListing);
+        }
+
+        let now = e.ledger().timestamp();
+        if now < auction.schedule.start_at {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::AuctionNotStarted);
+        }
+        if now > auction.schedule.end_at {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::AuctionEnded);
+        }
+
+        let nft_contract = Self::get_nft_contract(e.clone()).inspect_err(|_| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+        })?;
+        if check_seller_access(&e, &nft_contract, &auction.seller, token_id).is_err() {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            return Err(MarketplaceError::ListingNotFillable);
+        }
+
+        let fee_basis_points: u32 = e.storage()
+            .instance()
+            .get(&DataKey::MarketplaceFee)
+            .unwrap_or(0);
+
+        let fee_recipient: Address = e.storage()
+            .instance()
+            .get(&DataKey::FeeRecipient)
+            .ok_or_else(|| {
+                e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+                MarketplaceError::NotInitialized
+            })?;
+
+        let price = dutch_price(&auction.schedule, now).ok_or_else(|| {
+            e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+            MarketplaceError::InvalidPriceSchedule
+        })?;
+        let split = sale_split(&e, token_id, price, fee_basis_points);
+
+        // EFFECTS
+        e.storage().persistent().remove(&DataKey::DutchAuction(token_id));
+
+        // INTERACTIONS
+        pay_sale(&e, &auction.payment_token, &buyer, &auction.seller, &fee_recipient, token_id, &split);
+        transfer_nft(&e, &nft_contract, &auction.seller, &buyer, token_id);
+
+        // Clear reentrancy guard
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
+
+        e.events().publish(
+            (symbol_short!("DutchSold"), token_id),
+            (auction.seller, buyer, price),
+        );
+
+        Ok(price)
+    }
+
+    /// Cancel a Dutch auction (seller only)
+    pub fn cancel_dutch_auction(e: Env, seller: Address, token_id: u32) -> Result<(), MarketplaceError> {
+        seller.require_auth();
+
+        let auction = Self::get_dutch_auction(e.clone(), token_id)?;
+        if auction.seller != seller {
+            return Err(MarketplaceError::NotSeller);
+        }
+
+        e.storage().persistent().remove(&DataKey::DutchAuction(token_id));
+
+        e.events().publish(
+            (symbol_short!("DutchCncl"), token_id),
+            seller,
+        );
+
+        Ok(())
+    }
+
     /// Get auction details
     pub fn get_auction(e: Env, token_id: u32) -> Result<Auction, MarketplaceError> {
         e.storage()
@@ -967,5 +2098,441 @@ impl CommitmentMarketplace {
     }
 }
 
+// ============================================================================
+// Listing Helpers
+// ============================================================================
+
+/// Whether a listing is past its expiry
+fn is_listing_expired(e: &Env, listing: &Listing) -> bool {
+    listing.expires_at != 0 && e.ledger().timestamp() > listing.expires_at
+}
+
+/// Whether a listing can be filled right now
+fn listing_status(e: &Env, listing: &Listing) -> ListingStatus {
+    if is_listing_expired(e, listing) {
+        return ListingStatus::Expired;
+    }
+    let nft_contract: Address = match e.storage().instance().get(&DataKey::NFTContract) {
+        Some(nft_contract) => nft_contract,
+        None => return ListingStatus::OwnerChanged,
+    };
+    match check_seller_access(e, &nft_contract, &listing.seller, listing.token_id) {
+        Ok(()) => ListingStatus::Fillable,
+        Err(MarketplaceError::MarketplaceNotApproved) => ListingStatus::NotApproved,
+        Err(_) => ListingStatus::OwnerChanged,
+    }
+}
+
+/// Remove a listing and its active-listings entry
+fn remove_listing(e: &Env, token_id: u32) {
+    e.storage().persistent().remove(&DataKey::Listing(token_id));
+
+    let mut active_listings: Vec<u32> = e.storage()
+        .instance()
+        .get(&DataKey::ActiveListings)
+        .unwrap_or(Vec::new(e));
+    if let Some(index) = active_listings.iter().position(|id| id == token_id) {
+        active_listings.remove(index as u32);
+    }
+    e.storage().instance().set(&DataKey::ActiveListings, &active_listings);
+}
+
+/// Current owner of `token_id`, or None if the NFT contract call fails
+fn token_owner(e: &Env, nft_contract: &Address, token_id: u32) -> Option<Address> {
+    e.try_invoke_contract::<Address, soroban_sdk::Error>(
+        nft_contract,
+        &Symbol::new(e, "owner_of"),
+        vec![e, token_id.into_val(e)],
+    )
+    .ok()
+    .and_then(|owner| owner.ok())
+}
+
+/// Ensure `seller` owns `token_id` and the marketplace may transfer it
+fn check_seller_access(
+    e: &Env,
+    nft_contract: &Address,
+    seller: &Address,
+    token_id: u32,
+) -> Result<(), MarketplaceError> {
+    let owner = token_owner(e, nft_contract, token_id).ok_or(MarketplaceError::NFTContractError)?;
+    if owner != *seller {
+        return Err(MarketplaceError::NotTokenOwner);
+    }
+
+    let marketplace = e.current_contract_address();
+    let approved = e
+        .try_invoke_contract::<Option<Address>, soroban_sdk::Error>(
+            nft_contract,
+            &Symbol::new(e, "get_approved"),
+            vec![e, token_id.into_val(e)],
+        )
+        .ok()
+        .and_then(|approved| approved.ok())
+        .flatten();
+    if approved == Some(marketplace.clone()) {
+        return Ok(());
+    }
+
+    let operator = e
+        .try_invoke_contract::<bool, soroban_sdk::Error>(
+            nft_contract,
+            &Symbol::new(e, "is_approved_for_all"),
+            vec![e, seller.into_val(e), marketplace.into_val(e)],
+        )
+        .ok()
+        .and_then(|operator| operator.ok())
+        .unwrap_or(false);
+    if !operator {
+        return Err(MarketplaceError::MarketplaceNotApproved);
+    }
+
+    Ok(())
+}
+
+/// Move an NFT on the seller's behalf through the marketplace's approval
+fn transfer_nft(e: &Env, nft_contract: &Address, from: &Address, to: &Address, token_id: u32) {
+    let args: Vec<Val> = vec![
+        e,
+        e.current_contract_address().into_val(e),
+        from.into_val(e),
+        to.into_val(e),
+        token_id.into_val(e),
+    ];
+    e.invoke_contract::<()>(nft_contract, &Symbol::new(e, "transfer_from"), args);
+}
+
+/// Amount of the payment token due for a listing.
+///
+/// Reference-asset prices are converted with the oracle's prices for both
+/// assets; the two assets are assumed to share token decimals.
+fn listing_payment_amount(e: &Env, listing: &Listing) -> Result<i128, MarketplaceError> {
+    let price_asset = match &listing.price_asset {
+        Some(price_asset) => price_asset,
+        None => return Ok(listing.price),
+    };
+
+    let oracle: Address = e.storage()
+        .instance()
+        .get(&DataKey::PriceOracle)
+        .ok_or(MarketplaceError::PriceConversionFailed)?;
+    let reference = read_oracle_price(e, &oracle, price_asset)?;
+    let payment = read_oracle_price(e, &oracle, &listing.payment_token)?;
+    if reference.price <= 0 || payment.price <= 0 {
+        return Err(MarketplaceError::PriceConversionFailed);
+    }
+
+    // price * reference / payment, normalising the oracle decimals; rounded up
+    let numerator = listing.price
+        .checked_mul(reference.price)
+        .and_then(|v| v.checked_mul(10i128.checked_pow(payment.decimals)?))
+        .ok_or(MarketplaceError::PriceConversionFailed)?;
+    let denominator = payment.price
+        .checked_mul(10i128.checked_pow(reference.decimals).ok_or(MarketplaceError::PriceConversionFailed)?)
+        .ok_or(MarketplaceError::PriceConversionFailed)?;
+
+    Ok((numerator + denominator - 1) / denominator)
+}
+
+/// Fresh oracle price for an asset
+fn read_oracle_price(e: &Env, oracle: &Address, asset: &Address) -> Result<PriceData, MarketplaceError> {
+    let mut args = Vec::new(e);
+    args.push_back(asset.into_val(e));
+    args.push_back(Option::<u64>::None.into_val(e));
+    match e.try_invoke_contract::<PriceData, soroban_sdk::Error>(
+        oracle,
+        &Symbol::new(e, "get_price_valid"),
+        args,
+    ) {
+        Ok(Ok(price)) => Ok(price),
+        _ => Err(MarketplaceError::PriceConversionFailed),
+    }
+}
+
+/// Commitment NFT data from the NFT contract, if it exposes `get_metadata`
+fn read_commitment_nft(e: &Env, token_id: u32) -> Option<CommitmentNFT> {
+    let nft_contract: Address = e.storage().instance().get(&DataKey::NFTContract)?;
+    let mut args = Vec::new(e);
+    args.push_back(token_id.into_val(e));
+    match e.try_invoke_contract::<CommitmentNFT, soroban_sdk::Error>(
+        &nft_contract,
+        &Symbol::new(e, "get_metadata"),
+        args,
+    ) {
+        Ok(Ok(nft)) => Some(nft),
+        _ => None,
+    }
+}
+
+/// Live commitment from commitment_core, if configured and it knows the id
+fn read_commitment(e: &Env, commitment_id: &String) -> Option<Commitment> {
+    let core: Address = e.storage().instance().get(&DataKey::CoreContract)?;
+    let mut args = Vec::new(e);
+    args.push_back(commitment_id.into_val(e));
+    match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+        &core,
+        &Symbol::new(e, "get_commitment"),
+        args,
+    ) {
+        Ok(Ok(val)) => val.try_into_val(e).ok(),
+        _ => None,
+    }
+}
+
+/// Compliance score (0-100) from attestation_engine, if configured
+fn read_compliance_score(e: &Env, commitment_id: &String) -> Option<u32> {
+    let engine: Address = e.storage().instance().get(&DataKey::AttestationEngine)?;
+    let mut args = Vec::new(e);
+    args.push_back(commitment_id.into_val(e));
+    match e.try_invoke_contract::<u32, soroban_sdk::Error>(
+        &engine,
+        &Symbol::new(e, "calculate_compliance_score"),
+        args,
+    ) {
+        Ok(Ok(score)) => Some(score),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Criteria Offer Helpers
+// ============================================================================
+
+/// Check `token_id`'s commitment against offer criteria
+///
+/// The commitment must be active and unexpired. Expiry comes from
+/// commitment_core when configured, otherwise from the NFT metadata.
+fn check_offer_criteria(e: &Env, criteria: &OfferCriteria, token_id: u32) -> Result<(), MarketplaceError> {
+    let nft = read_commitment_nft(e, token_id).ok_or(MarketplaceError::CriteriaNotMet)?;
+    if !nft.is_active {
+        return Err(MarketplaceError::CriteriaNotMet);
+    }
+
+    if let Some(commitment_type) = &criteria.commitment_type {
+        if nft.metadata.commitment_type != *commitment_type {
+            return Err(MarketplaceError::CriteriaNotMet);
+        }
+    }
+
+    if let Some(asset) = &criteria.asset {
+        if nft.metadata.asset_address != *asset {
+            return Err(MarketplaceError::CriteriaNotMet);
+        }
+    }
+
+    let expires_at = if e.storage().instance().has(&DataKey::CoreContract) {
+        read_commitment(e, &nft.metadata.commitment_id)
+            .ok_or(MarketplaceError::CriteriaNotMet)?
+            .expires_at
+    } else {
+        nft.metadata.expires_at
+    };
+    let now = e.ledger().timestamp();
+    if expires_at <= now {
+        return Err(MarketplaceError::CriteriaNotMet);
+    }
+    if let Some(max_seconds) = criteria.max_seconds_to_expiry {
+        if expires_at - now > max_seconds {
+            return Err(MarketplaceError::CriteriaNotMet);
+        }
+    }
+
+    if let Some(min_score) = criteria.min_compliance_score {
+        let score = read_compliance_score(e, &nft.metadata.commitment_id)
+            .ok_or(MarketplaceError::CriteriaNotMet)?;
+        if score < min_score {
+            return Err(MarketplaceError::CriteriaNotMet);
+        }
+    }
+
+    Ok(())
+}
+
+/// Remove a criteria-based offer and its active-offers entry
+fn remove_criteria_offer(e: &Env, offer_id: u64) {
+    e.storage().persistent().remove(&DataKey::CriteriaOffer(offer_id));
+
+    let mut active_offers: Vec<u64> = e.storage()
+        .instance()
+        .get(&DataKey::ActiveCriteriaOffers)
+        .unwrap_or(Vec::new(e));
+    if let Some(index) = active_offers.iter().position(|id| id == offer_id) {
+        active_offers.remove(index as u32);
+    }
+    e.storage().instance().set(&DataKey::ActiveCriteriaOffers, &active_offers);
+}
+
+// ============================================================================
+// Auction Helpers
+// ============================================================================
+
+/// Lowest acceptable next bid: above the starting price for the first bid,
+/// then at least `min_increment_bps` over the current bid
+fn min_next_bid(auction: &Auction) -> i128 {
+    if auction.highest_bidder.is_none() {
+        return auction.current_bid + 1;
+    }
+    let increment = auction.current_bid * auction.min_increment_bps as i128 / 10000;
+    auction.current_bid + increment.max(1)
+}
+
+/// Whether the token has an unexpired listing or an English auction that
+/// has not been ended
+fn has_live_listing_or_auction(e: &Env, token_id: u32) -> bool {
+    let listing: Option<Listing> = e.storage().persistent().get(&DataKey::Listing(token_id));
+    if listing.is_some_and(|listing| !is_listing_expired(e, &listing)) {
+        return true;
+    }
+    let auction: Option<Auction> = e.storage().persistent().get(&DataKey::Auction(token_id));
+    auction.is_some_and(|auction| !auction.ended)
+}
+
+/// Whether the token has a Dutch auction that has not reached `end_at` and
+/// whose seller still owns the token
+fn has_live_dutch_auction(e: &Env, token_id: u32) -> bool {
+    let auction: Option<DutchAuction> = e.storage().persistent().get(&DataKey::DutchAuction(token_id));
+    let auction = match auction {
+        Some(auction) if e.ledger().timestamp() <= auction.schedule.end_at => auction,
+        _ => return false,
+    };
+    let nft_contract: Option<Address> = e.storage().instance().get(&DataKey::NFTContract);
+    nft_contract
+        .and_then(|nft_contract| token_owner(e, &nft_contract, token_id))
+        .is_some_and(|owner| owner == auction.seller)
+}
+
+/// Dutch auction price at `now`, clamped to the schedule's start and end.
+/// None if the curve overflows.
+fn dutch_price(schedule: &DutchPriceSchedule, now: u64) -> Option<i128> {
+    if now <= schedule.start_at {
+        return Some(schedule.start_price);
+    }
+    if now >= schedule.end_at {
+        return Some(schedule.end_price);
+    }
+
+    let price = curve_price(schedule, now - schedule.start_at)?;
+    Some(price.max(schedule.end_price))
+}
+
+/// Unclamped curve price `elapsed` seconds after `start_at`, None on overflow
+fn curve_price(schedule: &DutchPriceSchedule, elapsed: u64) -> Option<i128> {
+    let elapsed = elapsed as i128;
+    match schedule.curve {
+        PriceCurve::Linear => {
+            let duration = (schedule.end_at - schedule.start_at) as i128;
+            let drop = (schedule.start_price - schedule.end_price).checked_mul(elapsed)? / duration;
+            Some(schedule.start_price - drop)
+        }
+        PriceCurve::Exponential(half_life) => {
+            let half_life = half_life as i128;
+            let halvings = elapsed / half_life;
+            if halvings >= 127 {
+                return Some(0);
+            }
+            let base = schedule.start_price >> halvings;
+            let drop = (base / 2).checked_mul(elapsed % half_life)? / half_life;
+            Some(base - drop)
+        }
+    }
+}
+
+/// Whether the curve can be evaluated over the whole schedule without
+/// overflowing and reaches `end_price` by `end_at` (requires `end_at > start_at`)
+fn is_valid_price_curve(schedule: &DutchPriceSchedule) -> bool {
+    // The linear product peaks at `end_at`, which the final check evaluates
+    let fits = match schedule.curve {
+        PriceCurve::Linear => true,
+        PriceCurve::Exponential(half_life) => {
+            half_life > 0 && (schedule.start_price / 2).checked_mul(half_life as i128).is_some()
+        }
+    };
+    fits && curve_price(schedule, schedule.end_at - schedule.start_at)
+        .is_some_and(|price| price <= schedule.end_price)
+}
+
+/// Commitment for a hidden reserve: sha256(reserve big-endian || salt)
+fn hash_reserve(e: &Env, reserve_price: i128, salt: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &reserve_price.to_be_bytes());
+    data.append(&Bytes::from_array(e, &salt.to_array()));
+    e.crypto().sha256(&data).into()
+}
+
+// ============================================================================
+// Sale Settlement Helpers
+// ============================================================================
+
+/// How the proceeds of a sale are divided
+struct SaleSplit {
+    marketplace_fee: i128,
+    royalty: Option<(Address, i128)>,
+    seller_proceeds: i128,
+}
+
+/// Ask the NFT contract for the royalty owed on `price`.
+///
+/// NFT contracts that don't implement `royalty_info` (or fail) owe no royalty.
+fn query_royalty(e: &Env, token_id: u32, price: i128) -> Option<(Address, i128)> {
+    let nft_contract: Address = e.storage().instance().get(&DataKey::NFTContract)?;
+    let mut args = Vec::new(e);
+    args.push_back(token_id.into_val(e));
+    args.push_back(price.into_val(e));
+    match e.try_invoke_contract::<(Address, i128), soroban_sdk::Error>(
+        &nft_contract,
+        &Symbol::new(e, "royalty_info"),
+        args,
+    ) {
+        Ok(Ok((receiver, amount))) if amount > 0 => Some((receiver, amount)),
+        _ => None,
+    }
+}
+
+/// Split `price` into marketplace fee, royalty and seller proceeds.
+///
+/// The royalty is capped so that fee plus royalty never exceed the price.
+fn sale_split(e: &Env, token_id: u32, price: i128, fee_basis_points: u32) -> SaleSplit {
+    let marketplace_fee = (price * fee_basis_points as i128) / 10000;
+    let royalty = query_royalty(e, token_id, price)
+        .map(|(receiver, amount)| (receiver, amount.min(price - marketplace_fee)));
+    let royalty_amount = royalty.as_ref().map(|(_, amount)| *amount).unwrap_or(0);
+
+    SaleSplit {
+        marketplace_fee,
+        royalty,
+        seller_proceeds: price - marketplace_fee - royalty_amount,
+    }
+}
+
+/// Pay out a sale from `payer` to the seller, fee recipient and royalty receiver.
+fn pay_sale(
+    e: &Env,
+    payment_token: &Address,
+    payer: &Address,
+    seller: &Address,
+    fee_recipient: &Address,
+    token_id: u32,
+    split: &SaleSplit,
+) {
+    let payment_token_client = token::Client::new(e, payment_token);
+
+    if split.seller_proceeds > 0 {
+        payment_token_client.transfer(payer, seller, &split.seller_proceeds);
+    }
+
+    if split.marketplace_fee > 0 {
+        payment_token_client.transfer(payer, fee_recipient, &split.marketplace_fee);
+    }
+
+    if let Some((receiver, amount)) = &split.royalty {
+        if *amount > 0 {
+            payment_token_client.transfer(payer, receiver, amount);
+            e.events().publish(
+                (symbol_short!("Royalty"), token_id),
+                (receiver.clone(), *amount),
+            );
+        }
+    }
+}
+
 #[cfg(all(test, feature = "benchmark"))]
 mod benchmarks;
\ No newline at end of file
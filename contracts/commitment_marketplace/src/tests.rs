@@ -4,18 +4,92 @@ extern crate std;
 
 use crate::*;
 use soroban_sdk::{
-    symbol_short,
+    contract, contractimpl, contracttype, symbol_short,
     testutils::{Address as _, Events, Ledger},
-    Address, Env, vec, IntoVal,
+    Address, Bytes, BytesN, Env, String, vec, IntoVal,
 };
 
 // ============================================================================
 // Test Setup Helpers
 // ============================================================================
 
+#[contracttype]
+enum MockNftKey {
+    Owner(u32),
+    Operator(Address, Address),
+    Royalty,
+    Metadata(u32),
+}
+
+/// Minimal NFT contract: ownership, operator approvals and optional royalty
+#[contract]
+struct MockNft;
+
+#[contractimpl]
+impl MockNft {
+    pub fn mint(e: Env, to: Address, token_id: u32) {
+        e.storage().persistent().set(&MockNftKey::Owner(token_id), &to);
+    }
+
+    pub fn owner_of(e: Env, token_id: u32) -> Address {
+        e.storage().persistent().get(&MockNftKey::Owner(token_id)).unwrap()
+    }
+
+    pub fn get_approved(_e: Env, _token_id: u32) -> Option<Address> {
+        None
+    }
+
+    pub fn set_approval_for_all(e: Env, owner: Address, operator: Address, approved: bool) {
+        e.storage()
+            .persistent()
+            .set(&MockNftKey::Operator(owner, operator), &approved);
+    }
+
+    pub fn is_approved_for_all(e: Env, owner: Address, operator: Address) -> bool {
+        e.storage()
+            .persistent()
+            .get(&MockNftKey::Operator(owner, operator))
+            .unwrap_or(false)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, token_id: u32) {
+        from.require_auth();
+        assert_eq!(Self::owner_of(e.clone(), token_id), from);
+        e.storage().persistent().set(&MockNftKey::Owner(token_id), &to);
+    }
+
+    pub fn transfer_from(e: Env, spender: Address, from: Address, to: Address, token_id: u32) {
+        spender.require_auth();
+        assert_eq!(Self::owner_of(e.clone(), token_id), from);
+        assert!(Self::is_approved_for_all(e.clone(), from, spender));
+        e.storage().persistent().set(&MockNftKey::Owner(token_id), &to);
+    }
+
+    pub fn set_royalty(e: Env, receiver: Address, basis_points: u32) {
+        e.storage()
+            .instance()
+            .set(&MockNftKey::Royalty, &(receiver, basis_points));
+    }
+
+    /// Fails when no royalty is configured, like an NFT without royalty support
+    pub fn royalty_info(e: Env, _token_id: u32, sale_price: i128) -> (Address, i128) {
+        let (receiver, bps): (Address, u32) =
+            e.storage().instance().get(&MockNftKey::Royalty).unwrap();
+        (receiver, sale_price * bps as i128 / 10000)
+    }
+
+    pub fn set_metadata(e: Env, token_id: u32, nft: CommitmentNFT) {
+        e.storage().persistent().set(&MockNftKey::Metadata(token_id), &nft);
+    }
+
+    pub fn get_metadata(e: Env, token_id: u32) -> CommitmentNFT {
+        e.storage().persistent().get(&MockNftKey::Metadata(token_id)).unwrap()
+    }
+}
+
 fn setup_marketplace(e: &Env) -> (Address, Address, CommitmentMarketplaceClient<'_>) {
     let admin = Address::generate(e);
-    let nft_contract = Address::generate(e);
+    let nft_contract = e.register(MockNft, ());
     let fee_recipient = Address::generate(e);
 
     // Use register instead of register_contract
@@ -27,10 +101,32 @@ fn setup_marketplace(e: &Env) -> (Address, Address, CommitmentMarketplaceClient<
     (admin, fee_recipient, client)
 }
 
-fn setup_test_token(e: &Env) -> Address {
+fn setup_test_token(e: &Env, client: &CommitmentMarketplaceClient) -> Address {
     // In a real implementation, you'd deploy a token contract
     // For testing, we'll use a generated address
-    Address::generate(e)
+    let token = Address::generate(e);
+    client.set_payment_token(&token, &true);
+    token
+}
+
+/// Mint `token_id` to `owner` and approve the marketplace as operator
+fn mint_test_nft(e: &Env, client: &CommitmentMarketplaceClient, owner: &Address, token_id: u32) {
+    let nft = MockNftClient::new(e, &client.get_nft_contract());
+    nft.mint(owner, &token_id);
+    nft.set_approval_for_all(owner, &client.address, &true);
+}
+
+fn setup_funded_token(
+    e: &Env,
+    client: &CommitmentMarketplaceClient,
+    holder: &Address,
+    amount: i128,
+) -> Address {
+    let token_admin = Address::generate(e);
+    let token = e.register_stellar_asset_contract_v2(token_admin).address();
+    token::StellarAssetClient::new(e, &token).mint(holder, &amount);
+    client.set_payment_token(&token, &true);
+    token
 }
 
 // ============================================================================
@@ -97,9 +193,9 @@ fn test_list_nft_zero_price_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
-    client.list_nft(&seller, &1, &0, &payment_token);
+    client.list_nft(&seller, &1, &0, &payment_token, &0, &None);
 }
 
 #[test]
@@ -111,10 +207,11 @@ fn test_list_nft_twice_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
-    client.list_nft(&seller, &1, &1000, &payment_token);
-    client.list_nft(&seller, &1, &2000, &payment_token); // Should fail
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
+    client.list_nft(&seller, &1, &2000, &payment_token, &0, &None); // Should fail
 }
 
 #[test]
@@ -125,10 +222,11 @@ fn test_cancel_listing() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
 
-    client.list_nft(&seller, &token_id, &1000, &payment_token);
+    mint_test_nft(&e, &client, &seller, token_id);
+    client.list_nft(&seller, &token_id, &1000, &payment_token, &0, &None);
     client.cancel_listing(&seller, &token_id);
 
     // Verify event
@@ -152,7 +250,8 @@ fn test_get_listing_after_cancel_panics() {
     let seller = Address::generate(&e);
     let token_id = 1u32;
 
-    client.list_nft(&seller, &token_id, &1000, &setup_test_token(&e));
+    mint_test_nft(&e, &client, &seller, token_id);
+    client.list_nft(&seller, &token_id, &1000, &setup_test_token(&e, &client), &0, &None);
     client.cancel_listing(&seller, &token_id);
 
     // This will panic as expected
@@ -181,9 +280,10 @@ fn test_cancel_listing_not_seller_fails() {
 
     let seller = Address::generate(&e);
     let not_seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
-    client.list_nft(&seller, &1, &1000, &payment_token);
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
     client.cancel_listing(&not_seller, &1); // Should fail
 }
 
@@ -195,18 +295,269 @@ fn test_get_all_listings() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     // List 3 NFTs
-    client.list_nft(&seller, &1, &1000, &payment_token);
-    client.list_nft(&seller, &2, &2000, &payment_token);
-    client.list_nft(&seller, &3, &3000, &payment_token);
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
+    mint_test_nft(&e, &client, &seller, 2);
+    client.list_nft(&seller, &2, &2000, &payment_token, &0, &None);
+    mint_test_nft(&e, &client, &seller, 3);
+    client.list_nft(&seller, &3, &3000, &payment_token, &0, &None);
 
     let listings = client.get_all_listings();
     assert_eq!(listings.len(), 3);
 }
 
 // ============================================================================
+// Listing Expiry, Price Update and Payment Token Tests
+// ============================================================================
+
+#[contract]
+struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(e: Env, asset: Address, price: i128, decimals: u32) {
+        let data = PriceData {
+            price,
+            updated_at: e.ledger().timestamp(),
+            decimals,
+        };
+        e.storage().instance().set(&asset, &data);
+    }
+
+    pub fn get_price_valid(e: Env, asset: Address, _max_staleness_override: Option<u64>) -> PriceData {
+        e.storage().instance().get(&asset).unwrap()
+    }
+}
+
+#[test]
+fn test_expired_listing_cannot_be_bought() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+    let expires_at = e.ledger().timestamp() + 100;
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &expires_at, &None);
+    assert_eq!(client.get_listing(&1).expires_at, expires_at);
+
+    e.ledger().with_mut(|li| li.timestamp = expires_at + 1);
+
+    assert_eq!(client.try_buy_nft(&buyer, &1), Err(Ok(MarketplaceError::ListingExpired)));
+    assert_eq!(client.get_all_listings().len(), 0);
+
+    // An expired listing can be replaced by a fresh one
+    client.list_nft(&seller, &1, &900, &payment_token, &0, &None);
+    assert_eq!(client.get_all_listings().len(), 1);
+    assert_eq!(client.get_listing(&1).price, 900);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #24)")] // InvalidExpiration
+fn test_list_nft_past_expiry_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    client.list_nft(&seller, &1, &1000, &payment_token, &1000, &None);
+}
+
+#[test]
+fn test_update_listing_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
+    client.update_listing_price(&seller, &1, &1500);
+
+    assert_eq!(client.get_listing(&1).price, 1500);
+    assert_eq!(client.get_listing_price(&1), 1500);
+    assert_eq!(
+        client.try_update_listing_price(&stranger, &1, &10),
+        Err(Ok(MarketplaceError::NotSeller))
+    );
+    assert_eq!(
+        client.try_update_listing_price(&seller, &1, &0),
+        Err(Ok(MarketplaceError::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_payment_token_allowlist() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let unknown_token = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    assert_eq!(client.get_payment_tokens(), vec![&e, payment_token.clone()]);
+    assert_eq!(
+        client.try_list_nft(&seller, &1, &1000, &unknown_token, &0, &None),
+        Err(Ok(MarketplaceError::PaymentTokenNotAllowed))
+    );
+    assert_eq!(
+        client.try_make_offer(&seller, &1, &1000, &unknown_token),
+        Err(Ok(MarketplaceError::PaymentTokenNotAllowed))
+    );
+
+    client.set_payment_token(&payment_token, &false);
+    assert!(!client.is_payment_token_allowed(&payment_token));
+    assert_eq!(
+        client.try_start_auction(&seller, &1, &1000, &3600, &payment_token),
+        Err(Ok(MarketplaceError::PaymentTokenNotAllowed))
+    );
+}
+
+#[test]
+fn test_buy_nft_with_oracle_denominated_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, fee_recipient, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let usd = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &buyer, 4_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    let oracle_id = e.register(MockPriceOracle, ());
+    let oracle = MockPriceOracleClient::new(&e, &oracle_id);
+    oracle.set_price(&usd, &1_000_000, &6); // 1.0
+    oracle.set_price(&payment_token, &2_500_000, &7); // 0.25
+    client.set_price_oracle(&oracle_id);
+
+    // Priced at 1000 USD units, which is 4000 payment token units
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &Some(usd));
+    assert_eq!(client.get_listing_price(&1), 4_000);
+
+    client.buy_nft(&buyer, &1);
+
+    assert_eq!(token.balance(&fee_recipient), 100);
+    assert_eq!(token.balance(&seller), 3_900);
+    assert_eq!(token.balance(&buyer), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")] // PriceConversionFailed
+fn test_list_nft_with_price_asset_requires_oracle() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let usd = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &Some(usd));
+}
+
+// ============================================================================
+// Listing Fillability Tests
+// ============================================================================
+
+#[test]
+fn test_list_nft_requires_ownership_and_approval() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+
+    nft.mint(&seller, &1);
+    assert_eq!(
+        client.try_list_nft(&seller, &1, &1000, &payment_token, &0, &None),
+        Err(Ok(MarketplaceError::MarketplaceNotApproved))
+    );
+    assert_eq!(
+        client.try_list_nft(&stranger, &1, &1000, &payment_token, &0, &None),
+        Err(Ok(MarketplaceError::NotTokenOwner))
+    );
+    assert_eq!(
+        client.try_list_nft(&seller, &2, &1000, &payment_token, &0, &None),
+        Err(Ok(MarketplaceError::NFTContractError))
+    );
+}
+
+#[test]
+fn test_listing_unfillable_after_owner_transfers_nft() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
+    assert_eq!(client.get_listing_status(&1), ListingStatus::Fillable);
+    assert!(!client.delist_unfillable(&1));
+
+    // Seller moves the NFT elsewhere after listing
+    nft.transfer(&seller, &other, &1);
+
+    assert_eq!(client.get_listing_status(&1), ListingStatus::OwnerChanged);
+    assert_eq!(client.get_all_listings().len(), 0);
+    assert_eq!(client.try_buy_nft(&buyer, &1), Err(Ok(MarketplaceError::ListingNotFillable)));
+
+    assert!(client.delist_unfillable(&1));
+    assert_eq!(client.try_get_listing(&1), Err(Ok(MarketplaceError::ListingNotFound)));
+}
+
+#[test]
+fn test_listing_unfillable_after_approval_revoked() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
+    nft.set_approval_for_all(&seller, &client.address, &false);
+
+    assert_eq!(client.get_listing_status(&1), ListingStatus::NotApproved);
+    assert!(client.delist_unfillable(&1));
+    assert_eq!(client.get_all_listings().len(), 0);
+}
+
+// ============================================================================
+// Buy Tests// ============================================================================
 // Buy Tests (Note: These are simplified - real tests need token contract)
 // ============================================================================
 
@@ -219,12 +570,13 @@ fn test_buy_nft_flow() {
 
     let seller = Address::generate(&e);
     let _buyer = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
     let price = 1000_0000000i128;
 
     // List NFT
-    client.list_nft(&seller, &token_id, &price, &payment_token);
+    mint_test_nft(&e, &client, &seller, token_id);
+    client.list_nft(&seller, &token_id, &price, &payment_token, &0, &None);
 
     // Note: In a real test, you'd need to:
     // 1. Deploy a test token contract
@@ -251,9 +603,10 @@ fn test_buy_own_listing_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
-    client.list_nft(&seller, &1, &1000, &payment_token);
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
     client.buy_nft(&seller, &1); // Seller trying to buy their own listing
 }
 
@@ -270,7 +623,7 @@ fn test_make_offer_zero_amount_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let offerer = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     client.make_offer(&offerer, &1, &0, &payment_token);
 }
@@ -284,7 +637,7 @@ fn test_make_duplicate_offer_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let offerer = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     client.make_offer(&offerer, &1, &500, &payment_token);
     client.make_offer(&offerer, &1, &600, &payment_token); // Should fail
@@ -299,7 +652,7 @@ fn test_multiple_offers_same_token() {
 
     let offerer1 = Address::generate(&e);
     let offerer2 = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
 
     client.make_offer(&offerer1, &token_id, &500, &payment_token);
@@ -317,7 +670,7 @@ fn test_cancel_offer() {
     let (_, _, client) = setup_marketplace(&e);
 
     let offerer = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
 
     client.make_offer(&offerer, &token_id, &500, &payment_token);
@@ -352,7 +705,7 @@ fn test_start_auction_zero_price_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     client.start_auction(&seller, &1, &0, &86400, &payment_token);
 }
@@ -366,7 +719,7 @@ fn test_start_auction_zero_duration_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     client.start_auction(&seller, &1, &1000, &0, &payment_token);
 }
@@ -380,11 +733,12 @@ fn test_place_bid() {
 
     let seller = Address::generate(&e);
     let _bidder = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
     let starting_price = 1000_0000000i128;
     let _bid_amount = 1200_0000000i128;
 
+    mint_test_nft(&e, &client, &seller, token_id);
     client.start_auction(&seller, &token_id, &starting_price, &86400, &payment_token);
 
     // Note: In real test, setup token contract and balances
@@ -404,9 +758,10 @@ fn test_place_bid_too_low_fails() {
 
     let seller = Address::generate(&e);
     let bidder = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
 
+    mint_test_nft(&e, &client, &seller, token_id);
     client.start_auction(&seller, &token_id, &1000, &86400, &payment_token);
     client.place_bid(&bidder, &token_id, &500); // Lower than starting price
 }
@@ -421,10 +776,11 @@ fn test_place_bid_after_auction_ends_fails() {
 
     let seller = Address::generate(&e);
     let bidder = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
     let token_id = 1u32;
     let duration = 86400u64; // 1 day
 
+    mint_test_nft(&e, &client, &seller, token_id);
     client.start_auction(&seller, &token_id, &1000, &duration, &payment_token);
 
     // Fast forward time past auction end
@@ -444,8 +800,9 @@ fn test_end_auction_before_time_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
+    mint_test_nft(&e, &client, &seller, 1);
     client.start_auction(&seller, &1, &1000, &86400, &payment_token);
     client.end_auction(&1); // Try to end immediately
 }
@@ -459,8 +816,9 @@ fn test_end_auction_twice_fails() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
+    mint_test_nft(&e, &client, &seller, 1);
     client.start_auction(&seller, &1, &1000, &86400, &payment_token);
 
     e.ledger().with_mut(|li| {
@@ -479,11 +837,14 @@ fn test_get_all_auctions() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     // Start 3 auctions
+    mint_test_nft(&e, &client, &seller, 1);
     client.start_auction(&seller, &1, &1000, &86400, &payment_token);
+    mint_test_nft(&e, &client, &seller, 2);
     client.start_auction(&seller, &2, &2000, &86400, &payment_token);
+    mint_test_nft(&e, &client, &seller, 3);
     client.start_auction(&seller, &3, &3000, &86400, &payment_token);
 
     let auctions = client.get_all_auctions();
@@ -491,43 +852,1130 @@ fn test_get_all_auctions() {
 }
 
 // ============================================================================
-// Edge Cases and Integration Tests
+// Auction Reserve, Increment and Anti-Sniping Tests
 // ============================================================================
 
+fn auction_options(reserve: ReservePrice, min_increment_bps: u32, anti_snipe_seconds: u64) -> AuctionOptions {
+    AuctionOptions {
+        reserve,
+        min_increment_bps,
+        anti_snipe_seconds,
+    }
+}
+
+fn reserve_hash(e: &Env, reserve_price: i128, salt: &BytesN<32>) -> BytesN<32> {
+    let mut data = Bytes::from_array(e, &reserve_price.to_be_bytes());
+    data.append(&Bytes::from_array(e, &salt.to_array()));
+    e.crypto().sha256(&data).into()
+}
+
 #[test]
-fn test_list_then_start_auction_same_token() {
+fn test_min_bid_increment() {
     let e = Env::default();
     e.mock_all_auths();
 
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
-    let token_id = 1u32;
+    let bidder_1 = Address::generate(&e);
+    let bidder_2 = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder_1, 2_000);
+    token::StellarAssetClient::new(&e, &payment_token).mint(&bidder_2, &2_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction_with_options(
+        &seller,
+        &1,
+        &1_000,
+        &3600,
+        &payment_token,
+        &auction_options(ReservePrice::None, 500, 0), // 5%
+    );
 
-    // List NFT
-    client.list_nft(&seller, &token_id, &1000, &payment_token);
+    client.place_bid(&bidder_1, &1, &1_100);
+    assert_eq!(client.get_min_bid(&1), 1_155);
+    assert_eq!(
+        client.try_place_bid(&bidder_2, &1, &1_154),
+        Err(Ok(MarketplaceError::BidTooLow))
+    );
 
-    // Cancel listing
-    client.cancel_listing(&seller, &token_id);
+    client.place_bid(&bidder_2, &1, &1_155);
+    assert_eq!(token.balance(&bidder_1), 2_000);
+    assert_eq!(client.get_auction(&1).highest_bidder, Some(bidder_2));
+}
 
-    // Now start auction (should work)
-    client.start_auction(&seller, &token_id, &1000, &86400, &payment_token);
+#[test]
+fn test_late_bid_extends_auction() {
+    let e = Env::default();
+    e.mock_all_auths();
 
-    let auction = client.get_auction(&token_id);
-    assert_eq!(auction.token_id, token_id);
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder, 5_000);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction_with_options(
+        &seller,
+        &1,
+        &1_000,
+        &3600,
+        &payment_token,
+        &auction_options(ReservePrice::None, 0, 600),
+    );
+
+    // Early bids leave the end time alone
+    client.place_bid(&bidder, &1, &1_100);
+    assert_eq!(client.get_auction(&1).ends_at, 3600);
+
+    // A bid in the final 10 minutes pushes the end out
+    e.ledger().with_mut(|li| li.timestamp = 3300);
+    client.place_bid(&bidder, &1, &1_200);
+    assert_eq!(client.get_auction(&1).ends_at, 3900);
+
+    // Bidding stays open past the original end
+    e.ledger().with_mut(|li| li.timestamp = 3700);
+    client.place_bid(&bidder, &1, &1_300);
+    assert_eq!(client.get_auction(&1).ends_at, 4300);
+    assert_eq!(
+        client.try_end_auction(&1),
+        Err(Ok(MarketplaceError::AuctionNotEnded))
+    );
 }
 
 #[test]
-fn test_reentrancy_protection() {
+fn test_public_reserve_not_met_refunds_bidder() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (_, _, _client) = setup_marketplace(&e);
+    let (_, _, client) = setup_marketplace(&e);
 
-    // The reentrancy guard prevents nested calls
-    // This is tested implicitly in the token transfer flows
-    // In production, you'd test with malicious contracts
+    let seller = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder, 2_000);
+    let token = token::Client::new(&e, &payment_token);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction_with_options(
+        &seller,
+        &1,
+        &1_000,
+        &3600,
+        &payment_token,
+        &auction_options(ReservePrice::Public(5_000), 0, 0),
+    );
+    client.place_bid(&bidder, &1, &2_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 3601);
+    client.end_auction(&1);
+
+    assert_eq!(token.balance(&bidder), 2_000);
+    assert_eq!(token.balance(&seller), 0);
+    assert_eq!(nft.owner_of(&1), seller);
+    assert!(client.get_auction(&1).ended);
+}
+
+#[test]
+fn test_hidden_reserve_revealed_and_met() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, fee_recipient, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder, 4_000);
+    let token = token::Client::new(&e, &payment_token);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+    let salt = BytesN::from_array(&e, &[7u8; 32]);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction_with_options(
+        &seller,
+        &1,
+        &1_000,
+        &3600,
+        &payment_token,
+        &auction_options(ReservePrice::Hidden(reserve_hash(&e, 3_000, &salt)), 0, 0),
+    );
+    client.place_bid(&bidder, &1, &4_000);
+
+    assert_eq!(
+        client.try_reveal_reserve(&seller, &1, &2_000, &salt),
+        Err(Ok(MarketplaceError::InvalidReserveReveal))
+    );
+    client.reveal_reserve(&seller, &1, &3_000, &salt);
+    assert_eq!(client.get_auction(&1).reserve, ReservePrice::Public(3_000));
+
+    e.ledger().with_mut(|li| li.timestamp = 3601);
+    client.end_auction(&1);
+
+    assert_eq!(nft.owner_of(&1), bidder);
+    assert_eq!(token.balance(&fee_recipient), 100);
+    assert_eq!(token.balance(&seller), 3_900);
+}
+
+#[test]
+fn test_unrevealed_hidden_reserve_is_not_met() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder, 4_000);
+    let token = token::Client::new(&e, &payment_token);
+    let salt = BytesN::from_array(&e, &[7u8; 32]);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction_with_options(
+        &seller,
+        &1,
+        &1_000,
+        &3600,
+        &payment_token,
+        &auction_options(ReservePrice::Hidden(reserve_hash(&e, 3_000, &salt)), 0, 0),
+    );
+    client.place_bid(&bidder, &1, &4_000);
+
+    // Too late to reveal once the auction is over
+    e.ledger().with_mut(|li| li.timestamp = 3601);
+    assert_eq!(
+        client.try_reveal_reserve(&seller, &1, &3_000, &salt),
+        Err(Ok(MarketplaceError::AuctionEnded))
+    );
+    client.end_auction(&1);
+
+    assert_eq!(token.balance(&bidder), 4_000);
+    assert_eq!(token.balance(&seller), 0);
+}
+
+#[test]
+fn test_ended_auction_can_be_restarted() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder, 4_000);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction(&seller, &1, &1_000, &3600, &payment_token);
+    assert_eq!(
+        client.try_start_auction(&seller, &1, &1_000, &3600, &payment_token),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 3601);
+    client.end_auction(&1);
+
+    client.start_auction(&seller, &1, &1_000, &3600, &payment_token);
+    let auction = client.get_auction(&1);
+    assert!(!auction.ended);
+    assert_eq!(auction.ends_at, 3601 + 3600);
+
+    client.place_bid(&bidder, &1, &2_000);
+    e.ledger().with_mut(|li| li.timestamp = 3601 + 3601);
+    client.end_auction(&1);
+    assert_eq!(nft.owner_of(&1), bidder);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")] // InvalidAuctionOptions
+fn test_start_auction_invalid_increment_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction_with_options(
+        &seller,
+        &1,
+        &1_000,
+        &3600,
+        &payment_token,
+        &auction_options(ReservePrice::None, 10_001, 0),
+    );
+}
+
+// ============================================================================
+// Listing Details Tests
+// ============================================================================
+
+/// Minimal commitment_core: stores commitments by id
+#[contract]
+struct MockCore;
+
+#[contractimpl]
+impl MockCore {
+    pub fn set_commitment(e: Env, commitment: Commitment) {
+        e.storage()
+            .persistent()
+            .set(&commitment.commitment_id.clone(), &commitment);
+    }
+
+    pub fn get_commitment(e: Env, commitment_id: String) -> Commitment {
+        e.storage().persistent().get(&commitment_id).unwrap()
+    }
+}
+
+/// Minimal attestation_engine: stores compliance scores by commitment id
+#[contract]
+struct MockAttestation;
+
+#[contractimpl]
+impl MockAttestation {
+    pub fn set_score(e: Env, commitment_id: String, score: u32) {
+        e.storage().persistent().set(&commitment_id, &score);
+    }
+
+    pub fn calculate_compliance_score(e: Env, commitment_id: String) -> u32 {
+        e.storage().persistent().get(&commitment_id).unwrap()
+    }
+}
+
+/// Register mock core and attestation contracts with the marketplace
+fn setup_commitment_contracts(e: &Env, client: &CommitmentMarketplaceClient) {
+    client.set_core_contract(&e.register(MockCore, ()));
+    client.set_attestation_engine(&e.register(MockAttestation, ()));
+}
+
+fn test_commitment(
+    e: &Env,
+    token_id: u32,
+    asset: &Address,
+    commitment_type: &str,
+    current_value: i128,
+    expires_at: u64,
+) -> Commitment {
+    Commitment {
+        commitment_id: String::from_str(e, &std::format!("commitment_{}", token_id)),
+        owner: Address::generate(e),
+        nft_token_id: token_id,
+        rules: CommitmentRules {
+            duration_days: 30,
+            max_loss_percent: 10,
+            commitment_type: String::from_str(e, commitment_type),
+            early_exit_penalty: 5,
+            min_fee_threshold: 0,
+            grace_period_days: 0,
+        },
+        amount: 10_000,
+        asset_address: asset.clone(),
+        created_at: 0,
+        expires_at,
+        current_value,
+        status: String::from_str(e, "active"),
+    }
+}
+
+/// Publish `commitment` through the mock NFT, core and attestation contracts
+fn register_commitment(
+    e: &Env,
+    client: &CommitmentMarketplaceClient,
+    commitment: &Commitment,
+    compliance_score: u32,
+) {
+    let nft = CommitmentNFT {
+        owner: commitment.owner.clone(),
+        token_id: commitment.nft_token_id,
+        metadata: CommitmentMetadata {
+            commitment_id: commitment.commitment_id.clone(),
+            duration_days: commitment.rules.duration_days,
+            max_loss_percent: commitment.rules.max_loss_percent,
+            commitment_type: commitment.rules.commitment_type.clone(),
+            created_at: commitment.created_at,
+            expires_at: commitment.expires_at,
+            initial_amount: commitment.amount,
+            asset_address: commitment.asset_address.clone(),
+        },
+        is_active: true,
+        early_exit_penalty: commitment.rules.early_exit_penalty,
+    };
+    MockNftClient::new(e, &client.get_nft_contract()).set_metadata(&commitment.nft_token_id, &nft);
+    MockCoreClient::new(e, &client.get_core_contract().unwrap()).set_commitment(commitment);
+    MockAttestationClient::new(e, &client.get_attestation_engine().unwrap())
+        .set_score(&commitment.commitment_id, &compliance_score);
+}
+
+#[test]
+fn test_listing_details_with_commitment_data() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+    setup_commitment_contracts(&e, &client);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    let commitment = test_commitment(&e, 1, &payment_token, "balanced", 10_000, 87_400);
+    register_commitment(&e, &client, &commitment, 85);
+
+    client.list_nft(&seller, &1, &9_000, &payment_token, &0, &None);
+
+    let details = client.get_listing_details(&1);
+    assert_eq!(details.status, ListingStatus::Fillable);
+    assert_eq!(details.price, Some(9_000));
+    assert_eq!(details.current_value, Some(10_000));
+    assert_eq!(details.expires_at, Some(87_400));
+    assert_eq!(details.remaining_seconds, Some(86_400));
+    assert_eq!(details.early_exit_penalty, Some(5));
+    assert_eq!(details.compliance_score, Some(85));
+    assert_eq!(details.premium_bps, Some(-1_000)); // 10% discount
+    assert_eq!(details.commitment_type, Some(String::from_str(&e, "balanced")));
+    assert_eq!(details.initial_amount, Some(10_000));
+}
+
+#[test]
+fn test_listing_details_premium_overflow_is_none() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+    setup_commitment_contracts(&e, &client);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    let commitment = test_commitment(&e, 1, &payment_token, "balanced", 10_000, 87_400);
+    register_commitment(&e, &client, &commitment, 85);
+
+    client.list_nft(&seller, &1, &i128::MAX, &payment_token, &0, &None);
+
+    let details = client.get_listing_details(&1);
+    assert_eq!(details.price, Some(i128::MAX));
+    assert_eq!(details.premium_bps, None);
+}
+
+#[test]
+fn test_listing_details_without_commitment_data() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &9_000, &payment_token, &0, &None);
+
+    // No metadata, core or attestation engine: only the listing is known
+    let details = client.get_listing_details(&1);
+    assert_eq!(details.price, Some(9_000));
+    assert_eq!(details.commitment_id, None);
+    assert_eq!(details.current_value, None);
+    assert_eq!(details.compliance_score, None);
+    assert_eq!(details.premium_bps, None);
+
+    assert_eq!(
+        client.try_get_listing_details(&2),
+        Err(Ok(MarketplaceError::ListingNotFound))
+    );
+}
+
+// ============================================================================
+// Criteria Offer Tests
+// ============================================================================
+
+const DAY: u64 = 86_400;
+
+/// "Any balanced commitment in `asset` expiring within 30 days with compliance >= 80"
+fn balanced_criteria(e: &Env, asset: &Address) -> OfferCriteria {
+    OfferCriteria {
+        commitment_type: Some(String::from_str(e, "balanced")),
+        asset: Some(asset.clone()),
+        max_seconds_to_expiry: Some(30 * DAY),
+        min_compliance_score: Some(80),
+    }
+}
+
+#[test]
+fn test_criteria_offer_partial_fills() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, fee_recipient, client) = setup_marketplace(&e);
+    setup_commitment_contracts(&e, &client);
+
+    let offerer = Address::generate(&e);
+    let seller_1 = Address::generate(&e);
+    let seller_2 = Address::generate(&e);
+    let usdc = setup_funded_token(&e, &client, &offerer, 5_000);
+    let token = token::Client::new(&e, &usdc);
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+
+    for (seller, token_id) in [(&seller_1, 1), (&seller_2, 2)] {
+        mint_test_nft(&e, &client, seller, token_id);
+        let commitment = test_commitment(&e, token_id, &usdc, "balanced", 1_000, 10 * DAY);
+        register_commitment(&e, &client, &commitment, 90);
+    }
+
+    let offer_id = client.make_criteria_offer(&offerer, &balanced_criteria(&e, &usdc), &1_000, &3, &usdc);
+    assert_eq!(token.balance(&offerer), 2_000);
+    assert_eq!(token.balance(&client.address), 3_000);
+
+    client.fill_criteria_offer(&seller_1, &offer_id, &1);
+    assert_eq!(client.get_criteria_offer(&offer_id).filled, 1);
+
+    client.fill_criteria_offer(&seller_2, &offer_id, &2);
+    assert_eq!(client.get_criteria_offer(&offer_id).filled, 2);
+
+    assert_eq!(nft.owner_of(&1), offerer);
+    assert_eq!(nft.owner_of(&2), offerer);
+    assert_eq!(token.balance(&seller_1), 975);
+    assert_eq!(token.balance(&seller_2), 975);
+    assert_eq!(token.balance(&fee_recipient), 50); // 2.5% of each fill
+    assert_eq!(token.balance(&client.address), 1_000);
+
+    // Cancelling refunds the unfilled unit
+    client.cancel_criteria_offer(&offerer, &offer_id);
+    assert_eq!(token.balance(&offerer), 3_000);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_criteria_offers().len(), 0);
+}
+
+#[test]
+fn test_criteria_offer_closes_when_fully_filled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+    setup_commitment_contracts(&e, &client);
+
+    let offerer = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let usdc = setup_funded_token(&e, &client, &offerer, 1_000);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    let commitment = test_commitment(&e, 1, &usdc, "balanced", 1_000, 10 * DAY);
+    register_commitment(&e, &client, &commitment, 90);
+
+    let offer_id = client.make_criteria_offer(&offerer, &balanced_criteria(&e, &usdc), &1_000, &1, &usdc);
+    assert_eq!(client.get_criteria_offers().len(), 1);
+
+    client.fill_criteria_offer(&seller, &offer_id, &1);
+
+    assert_eq!(client.get_criteria_offers().len(), 0);
+    assert_eq!(
+        client.try_get_criteria_offer(&offer_id),
+        Err(Ok(MarketplaceError::OfferNotFound))
+    );
+}
+
+#[test]
+fn test_criteria_offer_rejects_non_matching_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+    setup_commitment_contracts(&e, &client);
+
+    let offerer = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let usdc = setup_funded_token(&e, &client, &offerer, 1_000);
+
+    let commitments = [
+        test_commitment(&e, 1, &usdc, "balanced", 1_000, 10 * DAY),
+        test_commitment(&e, 2, &usdc, "aggressive", 1_000, 10 * DAY), // wrong type
+        test_commitment(&e, 3, &usdc, "balanced", 1_000, 60 * DAY),   // expires too late
+        test_commitment(&e, 4, &usdc, "balanced", 1_000, 10 * DAY),   // low compliance
+    ];
+    for commitment in commitments.iter() {
+        mint_test_nft(&e, &client, &seller, commitment.nft_token_id);
+        let score = if commitment.nft_token_id == 4 { 70 } else { 90 };
+        register_commitment(&e, &client, commitment, score);
+    }
+
+    let offer_id = client.make_criteria_offer(&offerer, &balanced_criteria(&e, &usdc), &1_000, &1, &usdc);
+
+    assert!(client.matches_criteria_offer(&offer_id, &1));
+    for token_id in 2..=4 {
+        assert!(!client.matches_criteria_offer(&offer_id, &token_id));
+        assert_eq!(
+            client.try_fill_criteria_offer(&seller, &offer_id, &token_id),
+            Err(Ok(MarketplaceError::CriteriaNotMet))
+        );
+    }
+
+    // The match is evaluated at fill time, against live core data
+    let mut expired = commitments[0].clone();
+    expired.expires_at = e.ledger().timestamp();
+    MockCoreClient::new(&e, &client.get_core_contract().unwrap()).set_commitment(&expired);
+    assert_eq!(
+        client.try_fill_criteria_offer(&seller, &offer_id, &1),
+        Err(Ok(MarketplaceError::CriteriaNotMet))
+    );
+}
+
+#[test]
+fn test_make_criteria_offer_invalid_amount_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let offerer = Address::generate(&e);
+    let usdc = setup_funded_token(&e, &client, &offerer, 1_000);
+    let criteria = balanced_criteria(&e, &usdc);
+
+    assert_eq!(
+        client.try_make_criteria_offer(&offerer, &criteria, &1_000, &0, &usdc),
+        Err(Ok(MarketplaceError::InvalidOfferAmount))
+    );
+    assert_eq!(
+        client.try_make_criteria_offer(&offerer, &criteria, &0, &1, &usdc),
+        Err(Ok(MarketplaceError::InvalidOfferAmount))
+    );
+}
+
+// ============================================================================
+// Dutch Auction Tests
+// ============================================================================
+
+fn dutch_schedule(start_price: i128, end_price: i128, start_at: u64, end_at: u64, curve: PriceCurve) -> DutchPriceSchedule {
+    DutchPriceSchedule {
+        start_price,
+        end_price,
+        start_at,
+        end_at,
+        curve,
+    }
+}
+
+#[test]
+fn test_dutch_auction_linear_price_and_buy() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, fee_recipient, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &buyer, 10_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(10_000, 2_000, 1000, 2000, PriceCurve::Linear),
+    );
+    assert_eq!(client.get_dutch_price(&1), 10_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1250);
+    assert_eq!(client.get_dutch_price(&1), 8_000);
+
+    assert_eq!(client.buy_dutch(&buyer, &1), 8_000);
+
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+    assert_eq!(nft.owner_of(&1), buyer);
+    assert_eq!(token.balance(&fee_recipient), 200); // 2.5%
+    assert_eq!(token.balance(&seller), 7_800);
+    assert_eq!(token.balance(&buyer), 2_000);
+    assert_eq!(
+        client.try_get_dutch_auction(&1),
+        Err(Ok(MarketplaceError::AuctionNotFound))
+    );
+}
+
+#[test]
+fn test_dutch_auction_exponential_price() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(8_000, 1_500, 1000, 2000, PriceCurve::Exponential(100)),
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1100);
+    assert_eq!(client.get_dutch_price(&1), 4_000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1150);
+    assert_eq!(client.get_dutch_price(&1), 3_000);
+
+    // Floored at end_price
+    e.ledger().with_mut(|li| li.timestamp = 1300);
+    assert_eq!(client.get_dutch_price(&1), 1_500);
+}
+
+#[test]
+fn test_dutch_auction_exponential_reaches_end_price_at_end_at() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(1_000, 125, 1000, 1300, PriceCurve::Exponential(100)),
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 1299);
+    assert_eq!(client.get_dutch_price(&1), 127);
+
+    e.ledger().with_mut(|li| li.timestamp = 1300);
+    assert_eq!(client.get_dutch_price(&1), 125);
+}
+
+#[test]
+fn test_dutch_auction_excludes_other_live_sales() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+    let schedule = dutch_schedule(5_000, 1_000, 1000, 2000, PriceCurve::Linear);
+
+    // Live fixed-price listing
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &1000, &payment_token, &0, &None);
+    assert_eq!(
+        client.try_start_dutch_auction(&seller, &1, &payment_token, &schedule),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+
+    // Live English auction
+    mint_test_nft(&e, &client, &seller, 2);
+    client.start_auction(&seller, &2, &1_000, &3600, &payment_token);
+    assert_eq!(
+        client.try_start_dutch_auction(&seller, &2, &payment_token, &schedule),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+
+    // A live Dutch auction blocks the other sale kinds
+    mint_test_nft(&e, &client, &seller, 3);
+    client.start_dutch_auction(&seller, &3, &payment_token, &schedule);
+    assert_eq!(
+        client.try_list_nft(&seller, &3, &1000, &payment_token, &0, &None),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+    assert_eq!(
+        client.try_start_auction(&seller, &3, &1_000, &3600, &payment_token),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+
+    // Once the Dutch window closes the token can be listed again
+    e.ledger().with_mut(|li| li.timestamp = 2001);
+    client.list_nft(&seller, &3, &1000, &payment_token, &0, &None);
+}
+
+#[test]
+fn test_buy_dutch_outside_window_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &buyer, 10_000);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(5_000, 1_000, 1500, 2000, PriceCurve::Linear),
+    );
+
+    assert_eq!(
+        client.try_buy_dutch(&buyer, &1),
+        Err(Ok(MarketplaceError::AuctionNotStarted))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 2001);
+    assert_eq!(
+        client.try_buy_dutch(&buyer, &1),
+        Err(Ok(MarketplaceError::AuctionEnded))
+    );
+
+    // An ended auction can be restarted
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(5_000, 1_000, 2001, 3000, PriceCurve::Linear),
+    );
+    assert_eq!(client.buy_dutch(&buyer, &1), 5_000);
+}
+
+#[test]
+fn test_start_dutch_auction_invalid_schedule_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    assert_eq!(
+        client.try_start_dutch_auction(
+            &seller,
+            &1,
+            &payment_token,
+            &dutch_schedule(1_000, 2_000, 1000, 2000, PriceCurve::Linear),
+        ),
+        Err(Ok(MarketplaceError::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_start_dutch_auction(
+            &seller,
+            &1,
+            &payment_token,
+            &dutch_schedule(2_000, 1_000, 2000, 2000, PriceCurve::Linear),
+        ),
+        Err(Ok(MarketplaceError::InvalidPriceSchedule))
+    );
+    assert_eq!(
+        client.try_start_dutch_auction(
+            &seller,
+            &1,
+            &payment_token,
+            &dutch_schedule(2_000, 1_000, 1000, 2000, PriceCurve::Exponential(0)),
+        ),
+        Err(Ok(MarketplaceError::InvalidPriceSchedule))
+    );
+    // Three half-lives only fall to 125, above end_price
+    assert_eq!(
+        client.try_start_dutch_auction(
+            &seller,
+            &1,
+            &payment_token,
+            &dutch_schedule(1_000, 100, 1000, 1300, PriceCurve::Exponential(100)),
+        ),
+        Err(Ok(MarketplaceError::InvalidPriceSchedule))
+    );
+    // Curves whose price math would overflow
+    assert_eq!(
+        client.try_start_dutch_auction(
+            &seller,
+            &1,
+            &payment_token,
+            &dutch_schedule(i128::MAX, 1, 1000, 2000, PriceCurve::Linear),
+        ),
+        Err(Ok(MarketplaceError::InvalidPriceSchedule))
+    );
+    assert_eq!(
+        client.try_start_dutch_auction(
+            &seller,
+            &1,
+            &payment_token,
+            &dutch_schedule(i128::MAX, 1, 1000, u64::MAX, PriceCurve::Exponential(u64::MAX / 2)),
+        ),
+        Err(Ok(MarketplaceError::InvalidPriceSchedule))
+    );
+}
+
+#[test]
+fn test_accept_offer_removes_dutch_auction() {
+    let e = Env::default();
+    e.mock_all_auths_allowing_non_root_auth();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let offerer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &offerer, 2_000);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(5_000, 1_000, 1000, 2000, PriceCurve::Linear),
+    );
+    client.make_offer(&offerer, &1, &2_000, &payment_token);
+    client.accept_offer(&seller, &1, &offerer);
+
+    assert_eq!(
+        client.try_get_dutch_auction(&1),
+        Err(Ok(MarketplaceError::AuctionNotFound))
+    );
+
+    // The new owner can sell right away
+    MockNftClient::new(&e, &client.get_nft_contract()).set_approval_for_all(&offerer, &client.address, &true);
+    client.list_nft(&offerer, &1, &3_000, &payment_token, &0, &None);
+}
+
+#[test]
+fn test_dutch_auction_not_live_after_owner_change() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let new_owner = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_dutch_auction(
+        &seller,
+        &1,
+        &payment_token,
+        &dutch_schedule(5_000, 1_000, 1000, 2000, PriceCurve::Linear),
+    );
+
+    // The seller moves the NFT outside the marketplace
+    let nft = MockNftClient::new(&e, &client.get_nft_contract());
+    nft.transfer(&seller, &new_owner, &1);
+    nft.set_approval_for_all(&new_owner, &client.address, &true);
+
+    client.list_nft(&new_owner, &1, &3_000, &payment_token, &0, &None);
+}
+
+// ============================================================================
+// Edge Cases and Integration Tests
+// ============================================================================
+
+#[test]
+fn test_list_then_start_auction_same_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+    let token_id = 1u32;
+
+    // List NFT
+    mint_test_nft(&e, &client, &seller, token_id);
+    client.list_nft(&seller, &token_id, &1000, &payment_token, &0, &None);
+
+    // Cancel listing
+    client.cancel_listing(&seller, &token_id);
+
+    // Now start auction (should work)
+    client.start_auction(&seller, &token_id, &1000, &86400, &payment_token);
+
+    let auction = client.get_auction(&token_id);
+    assert_eq!(auction.token_id, token_id);
+}
+
+#[test]
+fn test_listing_and_auction_exclude_each_other() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let (_, _, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let payment_token = setup_test_token(&e, &client);
+
+    // A live English auction blocks listing
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction(&seller, &1, &1_000, &3600, &payment_token);
+    assert_eq!(
+        client.try_list_nft(&seller, &1, &1000, &payment_token, &0, &None),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+
+    // An unexpired listing blocks auctioning
+    mint_test_nft(&e, &client, &seller, 2);
+    client.list_nft(&seller, &2, &1000, &payment_token, &2000, &None);
+    assert_eq!(
+        client.try_start_auction(&seller, &2, &1_000, &3600, &payment_token),
+        Err(Ok(MarketplaceError::ListingExists))
+    );
+
+    // Once the listing expires the token can be auctioned
+    e.ledger().with_mut(|li| li.timestamp = 2001);
+    client.start_auction(&seller, &2, &1_000, &3600, &payment_token);
+}
+
+#[test]
+fn test_reentrancy_protection() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (_, _, _client) = setup_marketplace(&e);
+
+    // The reentrancy guard prevents nested calls
+    // This is tested implicitly in the token transfer flows
+    // In production, you'd test with malicious contracts
+}
+
+// ============================================================================
+// Royalty Tests
+// ============================================================================
+
+fn setup_royalty_marketplace(
+    e: &Env,
+    royalty_bps: u32,
+) -> (Address, Address, CommitmentMarketplaceClient<'_>) {
+    let admin = Address::generate(e);
+    let fee_recipient = Address::generate(e);
+    let creator = Address::generate(e);
+
+    let nft_id = e.register(MockNft, ());
+    MockNftClient::new(e, &nft_id).set_royalty(&creator, &royalty_bps);
+
+    let marketplace_id = e.register(CommitmentMarketplace, ());
+    let client = CommitmentMarketplaceClient::new(e, &marketplace_id);
+    client.initialize(&admin, &nft_id, &250, &fee_recipient); // 2.5% fee
+
+    (fee_recipient, creator, client)
+}
+
+#[test]
+fn test_buy_nft_pays_royalty() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (fee_recipient, creator, client) = setup_royalty_marketplace(&e, 500); // 5%
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &buyer, 10_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &10_000, &payment_token, &0, &None);
+    client.buy_nft(&buyer, &1);
+
+    assert_eq!(token.balance(&fee_recipient), 250);
+    assert_eq!(token.balance(&creator), 500);
+    assert_eq!(token.balance(&seller), 9_250);
+    assert_eq!(token.balance(&buyer), 0);
+    assert_eq!(MockNftClient::new(&e, &client.get_nft_contract()).owner_of(&1), buyer);
+}
+
+#[test]
+fn test_buy_nft_without_royalty_support() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    // The default setup's NFT has no royalty configured, so royalty_info fails
+    let (_, fee_recipient, client) = setup_marketplace(&e);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &buyer, 10_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &10_000, &payment_token, &0, &None);
+    client.buy_nft(&buyer, &1);
+
+    assert_eq!(token.balance(&fee_recipient), 250);
+    assert_eq!(token.balance(&seller), 9_750);
+}
+
+#[test]
+fn test_royalty_capped_at_price_after_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    // A misbehaving NFT contract asking for 200% of the price
+    let (fee_recipient, creator, client) = setup_royalty_marketplace(&e, 20_000);
+
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &buyer, 10_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.list_nft(&seller, &1, &10_000, &payment_token, &0, &None);
+    client.buy_nft(&buyer, &1);
+
+    assert_eq!(token.balance(&fee_recipient), 250);
+    assert_eq!(token.balance(&creator), 9_750);
+    assert_eq!(token.balance(&seller), 0);
+}
+
+#[test]
+fn test_accept_offer_pays_royalty() {
+    let e = Env::default();
+    // Offers are not escrowed, so the offerer's transfer is authorized
+    // outside the seller's root invocation
+    e.mock_all_auths_allowing_non_root_auth();
+
+    let (fee_recipient, creator, client) = setup_royalty_marketplace(&e, 1000); // 10%
+
+    let seller = Address::generate(&e);
+    let offerer = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &offerer, 2_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.make_offer(&offerer, &1, &2_000, &payment_token);
+    client.accept_offer(&seller, &1, &offerer);
+
+    assert_eq!(token.balance(&fee_recipient), 50);
+    assert_eq!(token.balance(&creator), 200);
+    assert_eq!(token.balance(&seller), 1_750);
+    assert_eq!(MockNftClient::new(&e, &client.get_nft_contract()).owner_of(&1), offerer);
+}
+
+#[test]
+fn test_end_auction_pays_royalty() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (fee_recipient, creator, client) = setup_royalty_marketplace(&e, 500); // 5%
+
+    let seller = Address::generate(&e);
+    let bidder = Address::generate(&e);
+    let payment_token = setup_funded_token(&e, &client, &bidder, 4_000);
+    let token = token::Client::new(&e, &payment_token);
+
+    mint_test_nft(&e, &client, &seller, 1);
+    client.start_auction(&seller, &1, &1_000, &3600, &payment_token);
+    client.place_bid(&bidder, &1, &4_000);
+
+    e.ledger().with_mut(|li| li.timestamp += 3601);
+    client.end_auction(&1);
+
+    assert_eq!(token.balance(&fee_recipient), 100);
+    assert_eq!(token.balance(&creator), 200);
+    assert_eq!(token.balance(&seller), 3_700);
 }
 
 // ============================================================================
@@ -542,13 +1990,14 @@ fn test_gas_listing_operations() {
     let (_, _, client) = setup_marketplace(&e);
 
     let seller = Address::generate(&e);
-    let payment_token = setup_test_token(&e);
+    let payment_token = setup_test_token(&e, &client);
 
     // Measure operations for optimization
     let start = e.ledger().sequence();
 
     for i in 0..10 {
-        client.list_nft(&seller, &i, &1000, &payment_token);
+        mint_test_nft(&e, &client, &seller, i);
+        client.list_nft(&seller, &i, &1000, &payment_token, &0, &None);
     }
 
     let end = e.ledger().sequence();
@@ -40,6 +40,7 @@ impl BenchmarkMetrics {
 }
 
 fn setup_test_env(e: &Env) -> Address {
+    e.mock_all_auths();
     let admin = Address::generate(e);
     let contract_id = e.register_contract(None, CommitmentNFTContract);
 
@@ -80,6 +81,7 @@ fn benchmark_mint() {
         let start = e.ledger().sequence();
         let _ = CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -106,6 +108,7 @@ fn benchmark_get_metadata() {
     let token_id = e.as_contract(&contract_id, || {
         CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -139,6 +142,7 @@ fn benchmark_owner_of() {
     let token_id = e.as_contract(&contract_id, || {
         CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -172,6 +176,7 @@ fn benchmark_balance_of() {
     e.as_contract(&contract_id, || {
         CommitmentNFTContract::mint(
             e.clone(),
+            CommitmentNFTContract::get_admin(e.clone()).unwrap(),
             owner.clone(),
             String::from_str(&e, "commitment_1"),
             30,
@@ -209,6 +214,7 @@ fn benchmark_batch_mint() {
         for i in 0..10 {
             let _ = CommitmentNFTContract::mint(
                 e.clone(),
+                CommitmentNFTContract::get_admin(e.clone()).unwrap(),
                 owner.clone(),
                 String::from_str(&e, &format!("commitment_{}", i)),
                 30,
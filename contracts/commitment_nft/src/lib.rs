@@ -1,10 +1,26 @@
 #![no_std]
-use shared_utils::EmergencyControl;
+use shared_utils::{
+    fee_from_bps, BatchError, BatchMode, BatchProcessor, BatchResultVoid, EmergencyControl,
+};
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, Address, BytesN, Env, String, Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Bytes, BytesN, Env, IntoVal, String, Symbol, TryIntoVal, Val, Vec,
 };
 
-pub const CURRENT_VERSION: u32 = 1;
+pub const CURRENT_VERSION: u32 = 2;
+
+/// Maximum number of entries returned by paginated queries
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Maximum royalty on secondary sales (10%)
+pub const MAX_ROYALTY_BPS: u32 = 1000;
+
+/// Collection name reported by `name()`
+pub const COLLECTION_NAME: &str = "Commitment NFT";
+/// Collection symbol reported by `symbol()`
+pub const COLLECTION_SYMBOL: &str = "COMMIT";
+
+/// Maximum length in bytes of a token URI and of each string it is built from
+pub const MAX_URI_LEN: u32 = 1024;
 
 // ============================================================================
 // Error Types
@@ -49,6 +65,14 @@ pub enum ContractError {
     InvalidVersion = 16,
     /// Migration already applied
     AlreadyMigrated = 17,
+    /// Approval expiration ledger is in the past
+    InvalidExpirationLedger = 18,
+    /// Lifecycle state change is not allowed from the current state
+    InvalidLifecycleTransition = 19,
+    /// Royalty basis points exceed MAX_ROYALTY_BPS
+    InvalidRoyalty = 20,
+    /// Token URI or base URI longer than MAX_URI_LEN
+    UriTooLong = 21,
 }
 
 // ============================================================================
@@ -89,6 +113,75 @@ pub struct TransferParams {
     pub token_id: u32,
 }
 
+// Import Commitment types from commitment_core (define locally for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentRules {
+    pub duration_days: u32,
+    pub max_loss_percent: u32,
+    pub commitment_type: String, // "safe", "balanced", "aggressive"
+    pub early_exit_penalty: u32,
+    pub min_fee_threshold: i128,
+    pub grace_period_days: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Commitment {
+    pub commitment_id: String,
+    pub owner: Address,
+    pub nft_token_id: u32,
+    pub rules: CommitmentRules,
+    pub amount: i128,
+    pub asset_address: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub current_value: i128,
+    pub status: String, // "active", "settled", "violated", "early_exit"
+}
+
+/// Lifecycle of a commitment NFT, mirrored from commitment_core
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LifecycleState {
+    Active,
+    Settled,
+    EarlyExited,
+    Violated,
+}
+
+/// Transfer restriction applied to every NFT of a commitment type
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TransferPolicy {
+    /// Freely transferable (default)
+    Free,
+    /// Non-transferable until the NFT is settled
+    SoulboundWhileActive,
+    /// Transferable only to allowlisted recipients
+    AllowlistOnly,
+    /// Transferable only by, to or from the marketplace contract
+    MarketplaceOnly,
+}
+
+/// Royalty paid on secondary sales
+///
+/// A `receiver` of None pays the token's original owner (creator).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoyaltyConfig {
+    pub receiver: Option<Address>,
+    pub basis_points: u32,
+}
+
+/// Approval for a single token, valid through `expiration_ledger`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenApproval {
+    pub spender: Address,
+    pub expiration_ledger: u32,
+}
+
 /// Storage keys for the contract
 #[contracttype]
 pub enum DataKey {
@@ -100,13 +193,21 @@ pub enum DataKey {
     NFT(u32),
     /// Owner balance count (Address -> u32)
     OwnerBalance(Address),
-    /// Owner tokens list (Address -> Vec<u32>)
+    /// Legacy owner tokens list (Address -> Vec<u32>); read only by `migrate`
     OwnerTokens(Address),
-    /// List of all token IDs (Vec<u32>)
+    /// Legacy list of all token IDs (Vec<u32>); read only by `migrate`
     TokenIds,
+    /// Owner enumeration ((owner, index) -> token_id)
+    OwnerTokenByIndex(Address, u32),
+    /// Position of a token in its owner's enumeration (token_id -> index)
+    OwnerTokenIndex(u32),
+    /// Global enumeration (index -> token_id)
+    TokenByIndex(u32),
+    /// Position of a token in the global enumeration (token_id -> index)
+    TokenIndex(u32),
     /// Authorized commitment_core contract address (for settlement)
     CoreContract,
-    /// Authorized minter addresses (from upstream)
+    /// Authorized minter flag (Address -> bool)
     AuthorizedMinter(Address),
     /// Active status (token_id -> bool)
     ActiveStatus(u32),
@@ -114,6 +215,28 @@ pub enum DataKey {
     ReentrancyGuard,
     /// Contract version
     Version,
+    /// Single-token approval (token_id -> TokenApproval)
+    Approval(u32),
+    /// Operator approval for all of an owner's tokens ((owner, operator) -> bool)
+    Operator(Address, Address),
+    /// Transfer policy per commitment type (commitment_type -> TransferPolicy)
+    TransferPolicy(String),
+    /// Recipients allowed under TransferPolicy::AllowlistOnly (Address -> bool)
+    TransferAllowlist(Address),
+    /// Marketplace contract for TransferPolicy::MarketplaceOnly
+    MarketplaceContract,
+    /// Base URI overriding the on-chain token_uri JSON
+    BaseUri,
+    /// Lifecycle state (token_id -> LifecycleState); absent means derived from is_active
+    LifecycleState(u32),
+    /// Number of burned tokens
+    BurnedCount,
+    /// Collection-wide royalty (RoyaltyConfig)
+    DefaultRoyalty,
+    /// Per-token royalty override (token_id -> RoyaltyConfig)
+    TokenRoyalty(u32),
+    /// Original owner of a token, the default royalty receiver (token_id -> Address)
+    Creator(u32),
 }
 
 // Events
@@ -144,10 +267,6 @@ impl CommitmentNFTContract {
         // Initialize token counter to 0
         e.storage().instance().set(&DataKey::TokenCounter, &0u32);
 
-        // Initialize empty token IDs vector
-        let token_ids: Vec<u32> = Vec::new(&e);
-        e.storage().instance().set(&DataKey::TokenIds, &token_ids);
-
         e.storage()
             .instance()
             .set(&DataKey::Version, &CURRENT_VERSION);
@@ -192,6 +311,35 @@ impl CommitmentNFTContract {
             .ok_or(ContractError::NotInitialized)
     }
 
+    /// Authorize an address to mint NFTs (admin-only).
+    pub fn add_minter(e: Env, caller: Address, minter: Address) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .instance()
+            .set(&DataKey::AuthorizedMinter(minter.clone()), &true);
+
+        e.events()
+            .publish((Symbol::new(&e, "MinterAdded"),), (minter,));
+        Ok(())
+    }
+
+    /// Revoke an address's permission to mint NFTs (admin-only).
+    pub fn remove_minter(e: Env, caller: Address, minter: Address) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .instance()
+            .remove(&DataKey::AuthorizedMinter(minter.clone()));
+
+        e.events()
+            .publish((Symbol::new(&e, "MinterRemoved"),), (minter,));
+        Ok(())
+    }
+
+    /// Check whether an address may mint NFTs (the admin is always authorized).
+    pub fn is_minter(e: Env, address: Address) -> bool {
+        is_authorized_minter(&e, &address)
+    }
+
     /// Get the admin address
     pub fn get_admin(e: Env) -> Result<Address, ContractError> {
         e.storage()
@@ -248,14 +396,45 @@ impl CommitmentNFTContract {
         if !e.storage().instance().has(&DataKey::TokenCounter) {
             e.storage().instance().set(&DataKey::TokenCounter, &0u32);
         }
-        if !e.storage().instance().has(&DataKey::TokenIds) {
-            let token_ids: Vec<u32> = Vec::new(&e);
-            e.storage().instance().set(&DataKey::TokenIds, &token_ids);
-        }
         if !e.storage().instance().has(&DataKey::ReentrancyGuard) {
             e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
         }
 
+        // v1 -> v2: rebuild index-based enumerations from the legacy lists
+        if let Some(token_ids) = e
+            .storage()
+            .instance()
+            .get::<_, Vec<u32>>(&DataKey::TokenIds)
+        {
+            let mut index = 0u32;
+            for token_id in token_ids.iter() {
+                if let Some(nft) = e
+                    .storage()
+                    .persistent()
+                    .get::<_, CommitmentNFT>(&DataKey::NFT(token_id))
+                {
+                    e.storage()
+                        .persistent()
+                        .remove(&DataKey::OwnerTokens(nft.owner.clone()));
+                    e.storage()
+                        .persistent()
+                        .remove(&DataKey::OwnerBalance(nft.owner.clone()));
+                }
+            }
+            for token_id in token_ids.iter() {
+                if let Some(nft) = e
+                    .storage()
+                    .persistent()
+                    .get::<_, CommitmentNFT>(&DataKey::NFT(token_id))
+                {
+                    add_owner_token(&e, &nft.owner, token_id);
+                    index_token(&e, token_id, index);
+                    index += 1;
+                }
+            }
+            e.storage().instance().remove(&DataKey::TokenIds);
+        }
+
         e.storage()
             .instance()
             .set(&DataKey::Version, &CURRENT_VERSION);
@@ -284,8 +463,10 @@ impl CommitmentNFTContract {
     /// # Reentrancy Protection
     /// Uses checks-effects-interactions pattern. This function only writes to storage
     /// and doesn't make external calls, but still protected for consistency.
+    #[allow(clippy::too_many_arguments)]
     pub fn mint(
         e: Env,
+        caller: Address,
         owner: Address,
         commitment_id: String,
         duration_days: u32,
@@ -316,6 +497,15 @@ impl CommitmentNFTContract {
             return Err(ContractError::NotInitialized);
         }
 
+        // CHECKS: Only the admin or an authorized minter may mint
+        caller.require_auth();
+        if !is_authorized_minter(&e, &caller) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(ContractError::NotAuthorized);
+        }
+
         // Validate inputs
         if duration_days == 0 {
             e.storage()
@@ -382,37 +572,13 @@ impl CommitmentNFTContract {
 
         // Store NFT data
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
-
-        // Update owner balance
-        let current_balance: u32 = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerBalance(owner.clone()))
-            .unwrap_or(0);
-        e.storage().persistent().set(
-            &DataKey::OwnerBalance(owner.clone()),
-            &(current_balance + 1),
-        );
-
-        // Update owner tokens list
-        let mut owner_tokens: Vec<u32> = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerTokens(owner.clone()))
-            .unwrap_or(Vec::new(&e));
-        owner_tokens.push_back(token_id);
         e.storage()
             .persistent()
-            .set(&DataKey::OwnerTokens(owner.clone()), &owner_tokens);
+            .set(&DataKey::Creator(token_id), &owner);
 
-        // Add token_id to the list of all tokens
-        let mut token_ids: Vec<u32> = e
-            .storage()
-            .instance()
-            .get(&DataKey::TokenIds)
-            .unwrap_or(Vec::new(&e));
-        token_ids.push_back(token_id);
-        e.storage().instance().set(&DataKey::TokenIds, &token_ids);
+        // Update owner and global enumerations (the counter already includes this token)
+        add_owner_token(&e, &owner, token_id);
+        index_token(&e, token_id, circulating_supply(&e) - 1);
 
         // Clear reentrancy guard
         e.storage()
@@ -462,200 +628,624 @@ impl CommitmentNFTContract {
         to: Address,
         token_id: u32,
     ) -> Result<(), ContractError> {
-        // Reentrancy protection
+        // CHECKS: Require authorization from the sender
+        from.require_auth();
+        transfer_token(&e, &from, &from, &to, token_id)
+    }
+
+    /// Transfer NFT on behalf of its owner
+    ///
+    /// `spender` must be the owner, the approved address for `token_id`, or an
+    /// operator approved for all of the owner's tokens.
+    pub fn transfer_from(
+        e: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+    ) -> Result<(), ContractError> {
+        spender.require_auth();
+
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        if nft.owner != from {
+            return Err(ContractError::NotOwner);
+        }
+        if spender != from
+            && read_approved(&e, token_id) != Some(spender.clone())
+            && !read_operator(&e, &from, &spender)
+        {
+            return Err(ContractError::NotAuthorized);
+        }
+
+        transfer_token(&e, &spender, &from, &to, token_id)
+    }
+
+    /// Transfer many NFTs in one call
+    ///
+    /// Every item is validated (existence, ownership, transfer policy, no
+    /// duplicate token_id) against the pre-batch state before anything is
+    /// written. In `Atomic` mode any invalid item fails the whole batch with no
+    /// state change; in `BestEffort` mode valid items are applied and invalid
+    /// ones are reported in `errors` with their index and ContractError code.
+    pub fn batch_transfer(
+        e: Env,
+        transfers: Vec<TransferParams>,
+        mode: BatchMode,
+    ) -> Result<BatchResultVoid, ContractError> {
         let guard: bool = e
             .storage()
             .instance()
             .get(&DataKey::ReentrancyGuard)
             .unwrap_or(false);
-
         if guard {
             return Err(ContractError::ReentrancyDetected);
         }
-        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
         EmergencyControl::require_not_emergency(&e);
 
-        // CHECKS: Require authorization from the sender
-        from.require_auth();
+        let contract_name = String::from_str(&e, "commitment_nft");
+        if let Err(error_code) =
+            BatchProcessor::enforce_batch_limits(&e, transfers.len(), Some(contract_name))
+        {
+            let mut errors = Vec::new(&e);
+            errors.push_back(BatchError {
+                index: 0,
+                error_code,
+                context: String::from_str(&e, "batch_size_validation"),
+            });
+            return Ok(BatchResultVoid::failure(&e, errors));
+        }
 
-        // Get the NFT
-        let mut nft: CommitmentNFT = e
+        // Each distinct sender authorizes once
+        let mut senders: Vec<Address> = Vec::new(&e);
+        for params in transfers.iter() {
+            if !senders.contains(&params.from) {
+                params.from.require_auth();
+                senders.push_back(params.from.clone());
+            }
+        }
+
+        // CHECKS: validate every item against the pre-batch state
+        let mut errors: Vec<BatchError> = Vec::new(&e);
+        let mut valid: Vec<(CommitmentNFT, TransferParams)> = Vec::new(&e);
+        let mut seen: Vec<u32> = Vec::new(&e);
+        for (index, params) in transfers.iter().enumerate() {
+            let result = match e
+                .storage()
+                .persistent()
+                .get::<_, CommitmentNFT>(&DataKey::NFT(params.token_id))
+            {
+                None => Err((ContractError::TokenNotFound, "token_not_found")),
+                Some(_) if seen.contains(params.token_id) => {
+                    Err((ContractError::InvalidTokenId, "duplicate_token_id"))
+                }
+                Some(nft) if nft.owner != params.from => {
+                    Err((ContractError::NotOwner, "not_owner"))
+                }
+                Some(nft) if !transfer_permitted(&e, &nft, &params.from, &params.from, &params.to) => {
+                    Err((ContractError::TransferNotAllowed, "transfer_not_allowed"))
+                }
+                Some(nft) => Ok(nft),
+            };
+            seen.push_back(params.token_id);
+
+            match result {
+                Ok(nft) => valid.push_back((nft, params)),
+                Err((error, context)) => {
+                    errors.push_back(BatchError {
+                        index: index as u32,
+                        error_code: error as u32,
+                        context: String::from_str(&e, context),
+                    });
+                    if mode == BatchMode::Atomic {
+                        return Ok(BatchResultVoid::failure(&e, errors));
+                    }
+                }
+            }
+        }
+
+        // EFFECTS: apply all valid transfers in one pass
+        e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+        for (nft, params) in valid.iter() {
+            apply_transfer(&e, nft, &params.from, &params.to);
+        }
+        e.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &false);
+
+        Ok(BatchResultVoid::partial(valid.len(), errors))
+    }
+
+    /// Approve `spender` to transfer `token_id` until `expiration_ledger`
+    ///
+    /// A single approval is kept per token; it is cleared on transfer.
+    /// Passing an `expiration_ledger` of 0 revokes the current approval.
+    pub fn approve(
+        e: Env,
+        owner: Address,
+        spender: Address,
+        token_id: u32,
+        expiration_ledger: u32,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
+
+        let nft: CommitmentNFT = e
             .storage()
             .persistent()
             .get(&DataKey::NFT(token_id))
-            .ok_or_else(|| {
-                e.storage()
-                    .instance()
-                    .set(&DataKey::ReentrancyGuard, &false);
-                ContractError::TokenNotFound
-            })?;
+            .ok_or(ContractError::TokenNotFound)?;
+        if nft.owner != owner {
+            return Err(ContractError::NotOwner);
+        }
 
-        // Verify ownership
-        if nft.owner != from {
+        if expiration_ledger == 0 {
+            e.storage().persistent().remove(&DataKey::Approval(token_id));
+        } else {
+            if expiration_ledger < e.ledger().sequence() {
+                return Err(ContractError::InvalidExpirationLedger);
+            }
+            let approval = TokenApproval {
+                spender: spender.clone(),
+                expiration_ledger,
+            };
             e.storage()
-                .instance()
-                .set(&DataKey::ReentrancyGuard, &false);
-            return Err(ContractError::NotOwner);
+                .persistent()
+                .set(&DataKey::Approval(token_id), &approval);
         }
 
-        // Check if NFT is still active (active NFTs may have transfer restrictions)
-        // For now, we allow transfers regardless of active status
-        // Uncomment below to restrict transfers of active NFTs:
-        // if nft.is_active {
-        //     e.storage().instance().set(&DataKey::ReentrancyGuard, &false);
-        //     return Err(ContractError::TransferNotAllowed);
-        // }
+        e.events().publish(
+            (symbol_short!("Approve"), owner, token_id),
+            (spender, expiration_ledger),
+        );
 
-        // EFFECTS: Update state
-        // Update owner
-        nft.owner = to.clone();
-        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        Ok(())
+    }
 
-        // OPTIMIZATION: Batch read balances before updating
-        let (from_balance, to_balance) = {
-            let from_bal = e
-                .storage()
-                .persistent()
-                .get(&DataKey::OwnerBalance(from.clone()))
-                .unwrap_or(0u32);
-            let to_bal = e
-                .storage()
-                .persistent()
-                .get(&DataKey::OwnerBalance(to.clone()))
-                .unwrap_or(0u32);
-            (from_bal, to_bal)
-        };
+    /// Approve or revoke `operator` to transfer all of `owner`'s tokens
+    pub fn set_approval_for_all(
+        e: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), ContractError> {
+        owner.require_auth();
 
-        // Update balance counts
-        if from_balance > 0 {
-            e.storage()
-                .persistent()
-                .set(&DataKey::OwnerBalance(from.clone()), &(from_balance - 1));
+        let key = DataKey::Operator(owner.clone(), operator.clone());
+        if approved {
+            e.storage().persistent().set(&key, &true);
+        } else {
+            e.storage().persistent().remove(&key);
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::OwnerBalance(to.clone()), &(to_balance + 1));
 
-        // Update owner tokens lists
-        let mut from_tokens: Vec<u32> = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerTokens(from.clone()))
-            .unwrap_or(Vec::new(&e));
-        if let Some(index) = from_tokens.iter().position(|id| id == token_id) {
-            from_tokens.remove(index as u32);
+        e.events().publish(
+            (Symbol::new(&e, "ApprovalForAll"), owner, operator),
+            approved,
+        );
+
+        Ok(())
+    }
+
+    /// Get the address approved for `token_id`, if the approval is still live
+    pub fn get_approved(e: Env, token_id: u32) -> Result<Option<Address>, ContractError> {
+        if !e.storage().persistent().has(&DataKey::NFT(token_id)) {
+            return Err(ContractError::TokenNotFound);
         }
-        e.storage()
-            .persistent()
-            .set(&DataKey::OwnerTokens(from.clone()), &from_tokens);
+        Ok(read_approved(&e, token_id))
+    }
 
-        let mut to_tokens: Vec<u32> = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerTokens(to.clone()))
-            .unwrap_or(Vec::new(&e));
-        to_tokens.push_back(token_id);
-        e.storage()
-            .persistent()
-            .set(&DataKey::OwnerTokens(to.clone()), &to_tokens);
+    /// Check whether `operator` may transfer all of `owner`'s tokens
+    pub fn is_approved_for_all(e: Env, owner: Address, operator: Address) -> bool {
+        read_operator(&e, &owner, &operator)
+    }
 
-        // Clear reentrancy guard
+    // ========================================================================
+    // Transfer Restrictions
+    // ========================================================================
+
+    /// Set the transfer policy for all NFTs of `commitment_type` (admin-only).
+    pub fn set_transfer_policy(
+        e: Env,
+        caller: Address,
+        commitment_type: String,
+        policy: TransferPolicy,
+    ) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        if !Self::is_valid_commitment_type(&e, &commitment_type) {
+            return Err(ContractError::InvalidCommitmentType);
+        }
         e.storage()
             .instance()
-            .set(&DataKey::ReentrancyGuard, &false);
+            .set(&DataKey::TransferPolicy(commitment_type.clone()), &policy);
 
-        // Emit transfer event
         e.events().publish(
-            (symbol_short!("Transfer"), from, to),
-            (token_id, e.ledger().timestamp()),
+            (Symbol::new(&e, "TransferPolicySet"), commitment_type),
+            policy,
         );
-
         Ok(())
     }
 
-    /// Check if NFT is active
-    pub fn is_active(e: Env, token_id: u32) -> Result<bool, ContractError> {
+    /// Get the transfer policy that applies to `token_id`
+    pub fn get_transfer_policy(e: Env, token_id: u32) -> Result<TransferPolicy, ContractError> {
         let nft: CommitmentNFT = e
             .storage()
             .persistent()
             .get(&DataKey::NFT(token_id))
             .ok_or(ContractError::TokenNotFound)?;
+        Ok(read_transfer_policy(&e, &nft.metadata.commitment_type))
+    }
 
-        Ok(nft.is_active)
+    /// Add or remove a recipient from the transfer allowlist (admin-only).
+    pub fn set_transfer_allowlisted(
+        e: Env,
+        caller: Address,
+        address: Address,
+        allowed: bool,
+    ) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        let key = DataKey::TransferAllowlist(address);
+        if allowed {
+            e.storage().instance().set(&key, &true);
+        } else {
+            e.storage().instance().remove(&key);
+        }
+        Ok(())
     }
 
-    /// Get total supply of NFTs minted
-    pub fn total_supply(e: Env) -> u32 {
+    /// Check whether `address` is on the transfer allowlist
+    pub fn is_transfer_allowlisted(e: Env, address: Address) -> bool {
         e.storage()
             .instance()
-            .get(&DataKey::TokenCounter)
-            .unwrap_or(0)
+            .get(&DataKey::TransferAllowlist(address))
+            .unwrap_or(false)
     }
 
-    /// Get NFT count for a specific owner
-    pub fn balance_of(e: Env, owner: Address) -> u32 {
+    /// Set the marketplace contract used by `TransferPolicy::MarketplaceOnly` (admin-only).
+    pub fn set_marketplace_contract(
+        e: Env,
+        caller: Address,
+        marketplace: Address,
+    ) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
         e.storage()
-            .persistent()
-            .get(&DataKey::OwnerBalance(owner))
-            .unwrap_or(0)
-    }
-
-    /// Get all NFTs metadata (for frontend)
-    pub fn get_all_metadata(e: Env) -> Vec<CommitmentNFT> {
-        let token_ids: Vec<u32> = e
-            .storage()
             .instance()
-            .get(&DataKey::TokenIds)
-            .unwrap_or(Vec::new(&e));
-
-        let mut nfts: Vec<CommitmentNFT> = Vec::new(&e);
-
-        for token_id in token_ids.iter() {
-            if let Some(nft) = e
-                .storage()
-                .persistent()
-                .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
-            {
-                nfts.push_back(nft);
-            }
-        }
-
-        nfts
+            .set(&DataKey::MarketplaceContract, &marketplace);
+        Ok(())
     }
 
-    /// Get all NFTs owned by a specific address
-    pub fn get_nfts_by_owner(e: Env, owner: Address) -> Vec<CommitmentNFT> {
-        let token_ids: Vec<u32> = e
-            .storage()
-            .persistent()
-            .get(&DataKey::OwnerTokens(owner))
-            .unwrap_or(Vec::new(&e));
-
-        let mut owned_nfts: Vec<CommitmentNFT> = Vec::new(&e);
-
-        for token_id in token_ids.iter() {
-            if let Some(nft) = e
-                .storage()
-                .persistent()
-                .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
-            {
-                owned_nfts.push_back(nft);
-            }
-        }
-
-        owned_nfts
+    /// Get the marketplace contract, if configured
+    pub fn get_marketplace_contract(e: Env) -> Option<Address> {
+        e.storage().instance().get(&DataKey::MarketplaceContract)
     }
 
     // ========================================================================
-    // Settlement (Issue #5 - Main Implementation)
+    // Royalties
+    // ========================================================================
+
+    /// Set the collection-wide royalty (admin-only).
+    ///
+    /// A `receiver` of None pays each token's creator.
+    pub fn set_default_royalty(
+        e: Env,
+        caller: Address,
+        receiver: Option<Address>,
+        basis_points: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        if basis_points > MAX_ROYALTY_BPS {
+            return Err(ContractError::InvalidRoyalty);
+        }
+        let config = RoyaltyConfig {
+            receiver,
+            basis_points,
+        };
+        e.storage().instance().set(&DataKey::DefaultRoyalty, &config);
+
+        e.events()
+            .publish((Symbol::new(&e, "DefaultRoyaltySet"),), config);
+        Ok(())
+    }
+
+    /// Override the royalty for a single token (admin-only).
+    pub fn set_token_royalty(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        receiver: Address,
+        basis_points: u32,
+    ) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        if !e.storage().persistent().has(&DataKey::NFT(token_id)) {
+            return Err(ContractError::TokenNotFound);
+        }
+        if basis_points > MAX_ROYALTY_BPS {
+            return Err(ContractError::InvalidRoyalty);
+        }
+        let config = RoyaltyConfig {
+            receiver: Some(receiver),
+            basis_points,
+        };
+        e.storage()
+            .persistent()
+            .set(&DataKey::TokenRoyalty(token_id), &config);
+
+        e.events()
+            .publish((Symbol::new(&e, "TokenRoyaltySet"), token_id), config);
+        Ok(())
+    }
+
+    /// Remove a token's royalty override so the collection default applies (admin-only).
+    pub fn remove_token_royalty(e: Env, caller: Address, token_id: u32) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        e.storage()
+            .persistent()
+            .remove(&DataKey::TokenRoyalty(token_id));
+        Ok(())
+    }
+
+    /// Royalty owed on a sale of `token_id` for `sale_price` (EIP-2981 style)
+    ///
+    /// Returns the receiver and amount; the amount is 0 when no royalty is configured.
+    pub fn royalty_info(
+        e: Env,
+        token_id: u32,
+        sale_price: i128,
+    ) -> Result<(Address, i128), ContractError> {
+        if !e.storage().persistent().has(&DataKey::NFT(token_id)) {
+            return Err(ContractError::TokenNotFound);
+        }
+        if sale_price < 0 {
+            return Err(ContractError::InvalidAmount);
+        }
+
+        let config: Option<RoyaltyConfig> = e
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenRoyalty(token_id))
+            .or_else(|| e.storage().instance().get(&DataKey::DefaultRoyalty));
+        let (receiver, basis_points) = match config {
+            Some(config) => (config.receiver, config.basis_points),
+            None => (None, 0),
+        };
+
+        let receiver = match receiver {
+            Some(receiver) => receiver,
+            None => match e.storage().persistent().get(&DataKey::Creator(token_id)) {
+                Some(creator) => creator,
+                None => Self::get_admin(e.clone())?,
+            },
+        };
+
+        Ok((receiver, fee_from_bps(sale_price, basis_points)))
+    }
+
+    // ========================================================================
+    // Token Metadata
+    // ========================================================================
+
+    /// Collection name
+    pub fn name(e: Env) -> String {
+        String::from_str(&e, COLLECTION_NAME)
+    }
+
+    /// Collection symbol
+    pub fn symbol(e: Env) -> String {
+        String::from_str(&e, COLLECTION_SYMBOL)
+    }
+
+    /// Set a base URI overriding the on-chain JSON (admin-only).
+    ///
+    /// When set, `token_uri` returns `base_uri` followed by the token_id.
+    /// An empty string restores on-chain rendering.
+    pub fn set_base_uri(e: Env, caller: Address, base_uri: String) -> Result<(), ContractError> {
+        require_admin(&e, &caller)?;
+        if base_uri.len() > MAX_URI_LEN {
+            return Err(ContractError::UriTooLong);
+        }
+        if base_uri.is_empty() {
+            e.storage().instance().remove(&DataKey::BaseUri);
+        } else {
+            e.storage().instance().set(&DataKey::BaseUri, &base_uri);
+        }
+
+        e.events()
+            .publish((Symbol::new(&e, "BaseUriSet"),), (base_uri,));
+        Ok(())
+    }
+
+    /// Get the base URI override, if any
+    pub fn get_base_uri(e: Env) -> Option<String> {
+        e.storage().instance().get(&DataKey::BaseUri)
+    }
+
+    /// Token URI for `token_id`
+    ///
+    /// Without a base URI this is a self-contained JSON document built from the
+    /// NFT metadata, with status and current value pulled live from
+    /// commitment_core when a core contract is registered. Fails with
+    /// `UriTooLong` if the result would exceed MAX_URI_LEN bytes.
+    pub fn token_uri(e: Env, token_id: u32) -> Result<String, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+
+        let mut out = Bytes::new(&e);
+        if let Some(base_uri) = e.storage().instance().get::<_, String>(&DataKey::BaseUri) {
+            out.append(&string_bytes(&e, &base_uri)?);
+            push_uint(&mut out, token_id as u128);
+            return bytes_to_string(&e, &out);
+        }
+
+        let (status, current_value) = match read_core_commitment(&e, &nft.metadata.commitment_id) {
+            Some(commitment) => (commitment.status, commitment.current_value),
+            None => {
+                let status = match read_lifecycle_state(&e, &nft) {
+                    LifecycleState::Active => "active",
+                    LifecycleState::Settled => "settled",
+                    LifecycleState::EarlyExited => "early_exit",
+                    LifecycleState::Violated => "violated",
+                };
+                (String::from_str(&e, status), nft.metadata.initial_amount)
+            }
+        };
+
+        out.extend_from_slice(b"{\"name\":\"");
+        out.extend_from_slice(COLLECTION_NAME.as_bytes());
+        out.extend_from_slice(b" #");
+        push_uint(&mut out, token_id as u128);
+        out.extend_from_slice(b"\",\"commitment_id\":\"");
+        push_escaped(&mut out, &string_bytes(&e, &nft.metadata.commitment_id)?);
+        out.extend_from_slice(b"\",\"commitment_type\":\"");
+        push_escaped(&mut out, &string_bytes(&e, &nft.metadata.commitment_type)?);
+        out.extend_from_slice(b"\",\"amount\":\"");
+        push_int(&mut out, nft.metadata.initial_amount);
+        out.extend_from_slice(b"\",\"current_value\":\"");
+        push_int(&mut out, current_value);
+        out.extend_from_slice(b"\",\"asset\":\"");
+        out.append(&string_bytes(&e, &nft.metadata.asset_address.to_string())?);
+        out.extend_from_slice(b"\",\"expires_at\":");
+        push_uint(&mut out, nft.metadata.expires_at as u128);
+        out.extend_from_slice(b",\"status\":\"");
+        push_escaped(&mut out, &string_bytes(&e, &status)?);
+        out.extend_from_slice(b"\"}");
+
+        bytes_to_string(&e, &out)
+    }
+
+    /// Check if NFT is active
+    pub fn is_active(e: Env, token_id: u32) -> Result<bool, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+
+        Ok(nft.is_active)
+    }
+
+    /// Get total supply of NFTs (minted minus burned)
+    pub fn total_supply(e: Env) -> u32 {
+        circulating_supply(&e)
+    }
+
+    /// Get NFT count for a specific owner
+    pub fn balance_of(e: Env, owner: Address) -> u32 {
+        e.storage()
+            .persistent()
+            .get(&DataKey::OwnerBalance(owner))
+            .unwrap_or(0)
+    }
+
+    /// Get a page of NFT metadata across all tokens (for frontend)
+    ///
+    /// `limit` is capped at MAX_PAGE_SIZE.
+    pub fn get_all_metadata(e: Env, start: u32, limit: u32) -> Vec<CommitmentNFT> {
+        let end = page_end(start, limit, circulating_supply(&e));
+        let mut nfts: Vec<CommitmentNFT> = Vec::new(&e);
+
+        for index in start..end {
+            let token_id: u32 = e
+                .storage()
+                .persistent()
+                .get(&DataKey::TokenByIndex(index))
+                .unwrap();
+            if let Some(nft) = e
+                .storage()
+                .persistent()
+                .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
+            {
+                nfts.push_back(nft);
+            }
+        }
+
+        nfts
+    }
+
+    /// Get the token at position `index` of `owner`'s enumeration
+    pub fn token_of_owner_by_index(
+        e: Env,
+        owner: Address,
+        index: u32,
+    ) -> Result<u32, ContractError> {
+        e.storage()
+            .persistent()
+            .get(&DataKey::OwnerTokenByIndex(owner, index))
+            .ok_or(ContractError::InvalidTokenId)
+    }
+
+    /// Get a page of token ids owned by `owner`
+    ///
+    /// `limit` is capped at MAX_PAGE_SIZE.
+    pub fn tokens_of_owner(e: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        let end = page_end(start, limit, owner_balance(&e, &owner));
+        let mut token_ids: Vec<u32> = Vec::new(&e);
+
+        for index in start..end {
+            if let Some(token_id) = e
+                .storage()
+                .persistent()
+                .get::<_, u32>(&DataKey::OwnerTokenByIndex(owner.clone(), index))
+            {
+                token_ids.push_back(token_id);
+            }
+        }
+
+        token_ids
+    }
+
+    /// Get all NFTs owned by a specific address
+    pub fn get_nfts_by_owner(e: Env, owner: Address) -> Vec<CommitmentNFT> {
+        let mut owned_nfts: Vec<CommitmentNFT> = Vec::new(&e);
+
+        for index in 0..owner_balance(&e, &owner) {
+            if let Some(nft) = e
+                .storage()
+                .persistent()
+                .get::<_, u32>(&DataKey::OwnerTokenByIndex(owner.clone(), index))
+                .and_then(|token_id| {
+                    e.storage()
+                        .persistent()
+                        .get::<DataKey, CommitmentNFT>(&DataKey::NFT(token_id))
+                })
+            {
+                owned_nfts.push_back(nft);
+            }
+        }
+
+        owned_nfts
+    }
+
+    /// Get NFTs owned by `owner` that are in lifecycle `state`
+    pub fn get_nfts_by_owner_and_state(
+        e: Env,
+        owner: Address,
+        state: LifecycleState,
+    ) -> Vec<CommitmentNFT> {
+        let mut nfts: Vec<CommitmentNFT> = Vec::new(&e);
+        for nft in Self::get_nfts_by_owner(e.clone(), owner).iter() {
+            if read_lifecycle_state(&e, &nft) == state {
+                nfts.push_back(nft);
+            }
+        }
+        nfts
+    }
+
+    // ========================================================================
+    // Settlement (Issue #5 - Main Implementation)
     // ========================================================================
 
     /// Mark NFT as settled (after maturity)
     ///
+    /// Only the registered commitment_core contract may settle NFTs.
+    ///
     /// # Reentrancy Protection
     /// Uses checks-effects-interactions pattern. This function only writes to storage
     /// and doesn't make external calls, but still protected for consistency.
-    pub fn settle(e: Env, token_id: u32) -> Result<(), ContractError> {
+    pub fn settle(e: Env, caller: Address, token_id: u32) -> Result<(), ContractError> {
         // Reentrancy protection
         let guard: bool = e
             .storage()
@@ -669,6 +1259,14 @@ impl CommitmentNFTContract {
         e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
         EmergencyControl::require_not_emergency(&e);
 
+        // CHECKS: Only the registered core contract may settle
+        if let Err(err) = require_core_contract(&e, &caller) {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            return Err(err);
+        }
+
         // CHECKS: Get the NFT
         let mut nft: CommitmentNFT = e
             .storage()
@@ -702,6 +1300,9 @@ impl CommitmentNFTContract {
         // Mark as inactive (settled)
         nft.is_active = false;
         e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        e.storage()
+            .persistent()
+            .set(&DataKey::LifecycleState(token_id), &LifecycleState::Settled);
 
         // Clear reentrancy guard
         e.storage()
@@ -715,6 +1316,104 @@ impl CommitmentNFTContract {
         Ok(())
     }
 
+    /// Mirror a lifecycle state change from commitment_core (core contract only)
+    ///
+    /// Active NFTs can be moved to Settled, EarlyExited or Violated. A Violated
+    /// NFT can be paid out (Settled, EarlyExited) or reinstated (Active) when the
+    /// violation is overturned. Unlike `settle`, this does not require expiry.
+    pub fn set_lifecycle_state(
+        e: Env,
+        caller: Address,
+        token_id: u32,
+        state: LifecycleState,
+    ) -> Result<(), ContractError> {
+        require_core_contract(&e, &caller)?;
+
+        let mut nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        let allowed = match read_lifecycle_state(&e, &nft) {
+            LifecycleState::Active => state != LifecycleState::Active,
+            LifecycleState::Violated => state != LifecycleState::Violated,
+            LifecycleState::Settled | LifecycleState::EarlyExited => false,
+        };
+        if !allowed {
+            return Err(ContractError::InvalidLifecycleTransition);
+        }
+
+        nft.is_active = state == LifecycleState::Active;
+        e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+        e.storage()
+            .persistent()
+            .set(&DataKey::LifecycleState(token_id), &state);
+
+        e.events().publish(
+            (Symbol::new(&e, "LifecycleChanged"), token_id),
+            (state, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
+    /// Get the lifecycle state of an NFT
+    pub fn get_lifecycle_state(e: Env, token_id: u32) -> Result<LifecycleState, ContractError> {
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        Ok(read_lifecycle_state(&e, &nft))
+    }
+
+    /// Burn a closed NFT
+    ///
+    /// The owner or the core contract may burn an NFT once it has left the
+    /// Active state. The token is removed from all enumerations and from
+    /// `total_supply`.
+    pub fn burn(e: Env, caller: Address, token_id: u32) -> Result<(), ContractError> {
+        caller.require_auth();
+
+        let nft: CommitmentNFT = e
+            .storage()
+            .persistent()
+            .get(&DataKey::NFT(token_id))
+            .ok_or(ContractError::TokenNotFound)?;
+        let core_contract: Option<Address> = e.storage().instance().get(&DataKey::CoreContract);
+        if caller != nft.owner && Some(caller.clone()) != core_contract {
+            return Err(ContractError::NotAuthorized);
+        }
+        if read_lifecycle_state(&e, &nft) == LifecycleState::Active {
+            return Err(ContractError::InvalidLifecycleTransition);
+        }
+
+        e.storage().persistent().remove(&DataKey::NFT(token_id));
+        e.storage().persistent().remove(&DataKey::Approval(token_id));
+        e.storage()
+            .persistent()
+            .remove(&DataKey::LifecycleState(token_id));
+        e.storage().persistent().remove(&DataKey::TokenRoyalty(token_id));
+        e.storage().persistent().remove(&DataKey::Creator(token_id));
+
+        remove_owner_token(&e, &nft.owner, token_id);
+        unindex_token(&e, token_id, circulating_supply(&e) - 1);
+
+        let burned: u32 = e
+            .storage()
+            .instance()
+            .get(&DataKey::BurnedCount)
+            .unwrap_or(0);
+        e.storage()
+            .instance()
+            .set(&DataKey::BurnedCount, &(burned + 1));
+
+        e.events().publish(
+            (symbol_short!("Burn"), token_id, nft.owner),
+            (caller, e.ledger().timestamp()),
+        );
+        Ok(())
+    }
+
     /// Check if an NFT has expired (based on time)
     pub fn is_expired(e: Env, token_id: u32) -> Result<bool, ContractError> {
         let nft: CommitmentNFT = e
@@ -770,6 +1469,358 @@ fn require_admin(e: &Env, caller: &Address) -> Result<(), ContractError> {
     Ok(())
 }
 
+fn transfer_token(
+    e: &Env,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+    token_id: u32,
+) -> Result<(), ContractError> {
+    // Reentrancy protection
+    let guard: bool = e
+        .storage()
+        .instance()
+        .get(&DataKey::ReentrancyGuard)
+        .unwrap_or(false);
+
+    if guard {
+        return Err(ContractError::ReentrancyDetected);
+    }
+    e.storage().instance().set(&DataKey::ReentrancyGuard, &true);
+    EmergencyControl::require_not_emergency(e);
+
+    // Get the NFT
+    let nft: CommitmentNFT = e
+        .storage()
+        .persistent()
+        .get(&DataKey::NFT(token_id))
+        .ok_or_else(|| {
+            e.storage()
+                .instance()
+                .set(&DataKey::ReentrancyGuard, &false);
+            ContractError::TokenNotFound
+        })?;
+
+    // Verify ownership
+    if nft.owner != *from {
+        e.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &false);
+        return Err(ContractError::NotOwner);
+    }
+
+    // Apply the transfer policy of the NFT's commitment type
+    if !transfer_permitted(e, &nft, spender, from, to) {
+        e.storage()
+            .instance()
+            .set(&DataKey::ReentrancyGuard, &false);
+        return Err(ContractError::TransferNotAllowed);
+    }
+
+    // EFFECTS: Update state
+    apply_transfer(e, nft, from, to);
+
+    // Clear reentrancy guard
+    e.storage()
+        .instance()
+        .set(&DataKey::ReentrancyGuard, &false);
+
+    Ok(())
+}
+
+/// Move a validated NFT from `from` to `to` and emit the Transfer event.
+fn apply_transfer(e: &Env, mut nft: CommitmentNFT, from: &Address, to: &Address) {
+    let token_id = nft.token_id;
+
+    // Update owner
+    nft.owner = to.clone();
+    e.storage().persistent().set(&DataKey::NFT(token_id), &nft);
+
+    // Approvals do not survive a change of owner
+    e.storage().persistent().remove(&DataKey::Approval(token_id));
+
+    // Update owner enumerations
+    remove_owner_token(e, from, token_id);
+    add_owner_token(e, to, token_id);
+
+    e.events().publish(
+        (symbol_short!("Transfer"), from.clone(), to.clone()),
+        (token_id, e.ledger().timestamp()),
+    );
+}
+
+fn owner_balance(e: &Env, owner: &Address) -> u32 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::OwnerBalance(owner.clone()))
+        .unwrap_or(0)
+}
+
+fn circulating_supply(e: &Env) -> u32 {
+    let minted: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::TokenCounter)
+        .unwrap_or(0);
+    let burned: u32 = e
+        .storage()
+        .instance()
+        .get(&DataKey::BurnedCount)
+        .unwrap_or(0);
+    minted - burned
+}
+
+fn page_end(start: u32, limit: u32, len: u32) -> u32 {
+    start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len)
+}
+
+/// Append `token_id` to `owner`'s enumeration and bump their balance.
+fn add_owner_token(e: &Env, owner: &Address, token_id: u32) {
+    let balance = owner_balance(e, owner);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerTokenByIndex(owner.clone(), balance), &token_id);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerTokenIndex(token_id), &balance);
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerBalance(owner.clone()), &(balance + 1));
+}
+
+/// Remove `token_id` from `owner`'s enumeration in O(1) by moving the last
+/// entry into its slot, and decrement their balance.
+fn remove_owner_token(e: &Env, owner: &Address, token_id: u32) {
+    let balance = owner_balance(e, owner);
+    if balance == 0 {
+        return;
+    }
+    let last = balance - 1;
+    let index: u32 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::OwnerTokenIndex(token_id))
+        .unwrap_or(last);
+    if index != last {
+        let last_token: u32 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::OwnerTokenByIndex(owner.clone(), last))
+            .unwrap();
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerTokenByIndex(owner.clone(), index), &last_token);
+        e.storage()
+            .persistent()
+            .set(&DataKey::OwnerTokenIndex(last_token), &index);
+    }
+    e.storage()
+        .persistent()
+        .remove(&DataKey::OwnerTokenByIndex(owner.clone(), last));
+    e.storage()
+        .persistent()
+        .remove(&DataKey::OwnerTokenIndex(token_id));
+    e.storage()
+        .persistent()
+        .set(&DataKey::OwnerBalance(owner.clone()), &last);
+}
+
+/// Place `token_id` at `index` of the global enumeration.
+fn index_token(e: &Env, token_id: u32, index: u32) {
+    e.storage()
+        .persistent()
+        .set(&DataKey::TokenByIndex(index), &token_id);
+    e.storage()
+        .persistent()
+        .set(&DataKey::TokenIndex(token_id), &index);
+}
+
+/// Remove `token_id` from the global enumeration whose last slot is `last`.
+fn unindex_token(e: &Env, token_id: u32, last: u32) {
+    let index: u32 = e
+        .storage()
+        .persistent()
+        .get(&DataKey::TokenIndex(token_id))
+        .unwrap_or(last);
+    if index != last {
+        let last_token: u32 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::TokenByIndex(last))
+            .unwrap();
+        index_token(e, last_token, index);
+    }
+    e.storage().persistent().remove(&DataKey::TokenByIndex(last));
+    e.storage().persistent().remove(&DataKey::TokenIndex(token_id));
+}
+
+fn require_core_contract(e: &Env, caller: &Address) -> Result<(), ContractError> {
+    caller.require_auth();
+    let core_contract: Address = e
+        .storage()
+        .instance()
+        .get(&DataKey::CoreContract)
+        .ok_or(ContractError::NotInitialized)?;
+    if *caller != core_contract {
+        return Err(ContractError::NotAuthorized);
+    }
+    Ok(())
+}
+
+fn read_lifecycle_state(e: &Env, nft: &CommitmentNFT) -> LifecycleState {
+    e.storage()
+        .persistent()
+        .get(&DataKey::LifecycleState(nft.token_id))
+        .unwrap_or(if nft.is_active {
+            LifecycleState::Active
+        } else {
+            LifecycleState::Settled
+        })
+}
+
+fn read_transfer_policy(e: &Env, commitment_type: &String) -> TransferPolicy {
+    e.storage()
+        .instance()
+        .get(&DataKey::TransferPolicy(commitment_type.clone()))
+        .unwrap_or(TransferPolicy::Free)
+}
+
+fn transfer_permitted(
+    e: &Env,
+    nft: &CommitmentNFT,
+    spender: &Address,
+    from: &Address,
+    to: &Address,
+) -> bool {
+    match read_transfer_policy(e, &nft.metadata.commitment_type) {
+        TransferPolicy::Free => true,
+        TransferPolicy::SoulboundWhileActive => !nft.is_active,
+        TransferPolicy::AllowlistOnly => e
+            .storage()
+            .instance()
+            .get(&DataKey::TransferAllowlist(to.clone()))
+            .unwrap_or(false),
+        TransferPolicy::MarketplaceOnly => {
+            match e
+                .storage()
+                .instance()
+                .get::<_, Address>(&DataKey::MarketplaceContract)
+            {
+                Some(marketplace) => {
+                    *spender == marketplace || *from == marketplace || *to == marketplace
+                }
+                None => false,
+            }
+        }
+    }
+}
+
+/// Fetch the live commitment from commitment_core, if one is registered and knows it.
+fn read_core_commitment(e: &Env, commitment_id: &String) -> Option<Commitment> {
+    let core: Address = e.storage().instance().get(&DataKey::CoreContract)?;
+    let mut args = Vec::new(e);
+    args.push_back(commitment_id.clone().into_val(e));
+    match e.try_invoke_contract::<Val, soroban_sdk::Error>(
+        &core,
+        &Symbol::new(e, "get_commitment"),
+        args,
+    ) {
+        Ok(Ok(val)) => val.try_into_val(e).ok(),
+        _ => None,
+    }
+}
+
+// An ScVal string encodes as a 4-byte type tag, a 4-byte length, the UTF-8
+// bytes and zero padding to a 4-byte boundary.
+/// Raw UTF-8 bytes of a soroban String.
+fn string_bytes(e: &Env, s: &String) -> Result<Bytes, ContractError> {
+    let len = s.len();
+    if len > MAX_URI_LEN {
+        return Err(ContractError::UriTooLong);
+    }
+    let mut buf = [0u8; MAX_URI_LEN as usize];
+    s.copy_into_slice(&mut buf[..len as usize]);
+    Ok(Bytes::from_slice(e, &buf[..len as usize]))
+}
+
+/// Build a soroban String from raw UTF-8 bytes.
+fn bytes_to_string(e: &Env, bytes: &Bytes) -> Result<String, ContractError> {
+    let len = bytes.len();
+    if len > MAX_URI_LEN {
+        return Err(ContractError::UriTooLong);
+    }
+    let mut buf = [0u8; MAX_URI_LEN as usize];
+    bytes.copy_into_slice(&mut buf[..len as usize]);
+    Ok(String::from_bytes(e, &buf[..len as usize]))
+}
+
+/// Append `value` to `out`, escaping characters that are special in JSON strings.
+fn push_escaped(out: &mut Bytes, value: &Bytes) {
+    for b in value.iter() {
+        match b {
+            b'"' | b'\\' => {
+                out.push_back(b'\\');
+                out.push_back(b);
+            }
+            0..=0x1f => {}
+            _ => out.push_back(b),
+        }
+    }
+}
+
+fn push_uint(out: &mut Bytes, mut value: u128) {
+    let mut digits = [0u8; 39];
+    let mut i = digits.len();
+    loop {
+        i -= 1;
+        digits[i] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    out.extend_from_slice(&digits[i..]);
+}
+
+fn push_int(out: &mut Bytes, value: i128) {
+    if value < 0 {
+        out.push_back(b'-');
+    }
+    push_uint(out, value.unsigned_abs());
+}
+
+fn read_approved(e: &Env, token_id: u32) -> Option<Address> {
+    let approval: TokenApproval = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Approval(token_id))?;
+    if approval.expiration_ledger < e.ledger().sequence() {
+        return None;
+    }
+    Some(approval.spender)
+}
+
+fn read_operator(e: &Env, owner: &Address, operator: &Address) -> bool {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Operator(owner.clone(), operator.clone()))
+        .unwrap_or(false)
+}
+
+fn is_authorized_minter(e: &Env, address: &Address) -> bool {
+    let is_admin = e
+        .storage()
+        .instance()
+        .get::<_, Address>(&DataKey::Admin)
+        .map(|admin| admin == *address)
+        .unwrap_or(false);
+    is_admin
+        || e.storage()
+            .instance()
+            .get::<_, bool>(&DataKey::AuthorizedMinter(address.clone()))
+            .unwrap_or(false)
+}
+
 fn require_valid_wasm_hash(e: &Env, wasm_hash: &BytesN<32>) -> Result<(), ContractError> {
     let zero = BytesN::from_array(e, &[0; 32]);
     if *wasm_hash == zero {
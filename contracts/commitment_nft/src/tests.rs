@@ -6,10 +6,11 @@ use crate::*;
 use soroban_sdk::{
     symbol_short,
     testutils::{Address as _, Events, Ledger},
-    vec, Address, Env, IntoVal, String,
+    contract, contractimpl, vec, Address, Env, IntoVal, String,
 };
 
 fn setup_contract(e: &Env) -> (Address, CommitmentNFTContractClient<'_>) {
+    e.mock_all_auths();
     let contract_id = e.register_contract(None, CommitmentNFTContract);
     let client = CommitmentNFTContractClient::new(e, &contract_id);
     let admin = Address::generate(e);
@@ -60,6 +61,24 @@ fn setup_env() -> (Env, Address, Address) {
     (e, contract_id, admin)
 }
 
+fn mint_test_nft(e: &Env, client: &CommitmentNFTContractClient, admin: &Address, owner: &Address) -> u32 {
+    let asset_address = Address::generate(e);
+    let (commitment_id, duration, max_loss, commitment_type, amount, asset, penalty) =
+        create_test_metadata(e, &asset_address);
+
+    client.mint(
+        admin,
+        owner,
+        &commitment_id,
+        &duration,
+        &max_loss,
+        &commitment_type,
+        &amount,
+        &asset,
+        &penalty,
+    )
+}
+
 // ============================================================================
 // Initialization Tests
 // ============================================================================
@@ -91,6 +110,7 @@ fn test_mint() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -134,6 +154,7 @@ fn test_mint_multiple() {
 
     // Mint 3 NFTs
     let token_id_0 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_0"),
         &30,
@@ -146,6 +167,7 @@ fn test_mint_multiple() {
     assert_eq!(token_id_0, 0);
 
     let token_id_1 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_1"),
         &30,
@@ -158,6 +180,7 @@ fn test_mint_multiple() {
     assert_eq!(token_id_1, 1);
 
     let token_id_2 = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "commitment_2"),
         &30,
@@ -177,7 +200,7 @@ fn test_mint_multiple() {
 #[should_panic(expected = "Error(Contract, #1)")] // NotInitialized
 fn test_mint_without_initialize_fails() {
     let e = Env::default();
-    let (_admin, client) = setup_contract(&e);
+    let (admin, client) = setup_contract(&e);
     let owner = Address::generate(&e);
     let asset_address = Address::generate(&e);
 
@@ -185,6 +208,79 @@ fn test_mint_without_initialize_fails() {
         create_test_metadata(&e, &asset_address);
 
     client.mint(
+        &admin,
+        &owner,
+        &commitment_id,
+        &duration,
+        &max_loss,
+        &commitment_type,
+        &amount,
+        &asset,
+        &penalty,
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_mint_by_unauthorized_caller_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let attacker = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+
+    let (commitment_id, duration, max_loss, commitment_type, amount, asset, penalty) =
+        create_test_metadata(&e, &asset_address);
+
+    client.mint(
+        &attacker,
+        &attacker,
+        &commitment_id,
+        &duration,
+        &max_loss,
+        &commitment_type,
+        &amount,
+        &asset,
+        &penalty,
+    );
+}
+
+#[test]
+fn test_add_and_remove_minter() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let minter = Address::generate(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+    assert!(client.is_minter(&admin));
+    assert!(!client.is_minter(&minter));
+
+    client.add_minter(&admin, &minter);
+    assert!(client.is_minter(&minter));
+
+    let (commitment_id, duration, max_loss, commitment_type, amount, asset, penalty) =
+        create_test_metadata(&e, &asset_address);
+    let token_id = client.mint(
+        &minter,
+        &owner,
+        &commitment_id,
+        &duration,
+        &max_loss,
+        &commitment_type,
+        &amount,
+        &asset,
+        &penalty,
+    );
+    assert_eq!(client.owner_of(&token_id), owner);
+
+    client.remove_minter(&admin, &minter);
+    assert!(!client.is_minter(&minter));
+
+    let result = client.try_mint(
+        &minter,
         &owner,
         &commitment_id,
         &duration,
@@ -194,6 +290,18 @@ fn test_mint_without_initialize_fails() {
         &asset,
         &penalty,
     );
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_add_minter_non_admin_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let attacker = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.add_minter(&attacker, &attacker);
 }
 
 // ============================================
@@ -216,6 +324,7 @@ fn test_get_metadata() {
     let amount = 5000i128;
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -267,6 +376,7 @@ fn test_owner_of() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -309,6 +419,7 @@ fn test_is_active() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -320,7 +431,7 @@ fn test_is_active() {
     );
 
     // Newly minted NFT should be active
-    assert_eq!(client.is_active(&token_id), true);
+    assert!(client.is_active(&token_id));
 }
 
 #[test]
@@ -360,6 +471,7 @@ fn test_total_supply_after_minting() {
     // Mint 5 NFTs
     for _ in 0..5 {
         client.mint(
+            &admin,
             &owner,
             &String::from_str(&e, "commitment"),
             &30,
@@ -403,6 +515,7 @@ fn test_balance_of_after_minting() {
     // Mint 3 NFTs for owner1
     for _ in 0..3 {
         client.mint(
+            &admin,
             &owner1,
             &String::from_str(&e, "owner1_commitment"),
             &30,
@@ -417,6 +530,7 @@ fn test_balance_of_after_minting() {
     // Mint 2 NFTs for owner2
     for _ in 0..2 {
         client.mint(
+            &admin,
             &owner2,
             &String::from_str(&e, "owner2_commitment"),
             &30,
@@ -443,7 +557,7 @@ fn test_get_all_metadata_empty() {
 
     client.initialize(&admin);
 
-    let all_nfts = client.get_all_metadata();
+    let all_nfts = client.get_all_metadata(&0, &10);
     assert_eq!(all_nfts.len(), 0);
 }
 
@@ -459,6 +573,7 @@ fn test_get_all_metadata() {
     // Mint 3 NFTs
     for _ in 0..3 {
         client.mint(
+            &admin,
             &owner,
             &String::from_str(&e, "commitment"),
             &30,
@@ -470,7 +585,7 @@ fn test_get_all_metadata() {
         );
     }
 
-    let all_nfts = client.get_all_metadata();
+    let all_nfts = client.get_all_metadata(&0, &10);
     assert_eq!(all_nfts.len(), 3);
 
     // Verify each NFT owner
@@ -508,6 +623,7 @@ fn test_get_nfts_by_owner() {
     // Mint 2 NFTs for owner1
     for _ in 0..2 {
         client.mint(
+            &admin,
             &owner1,
             &String::from_str(&e, "owner1"),
             &30,
@@ -522,6 +638,7 @@ fn test_get_nfts_by_owner() {
     // Mint 3 NFTs for owner2
     for _ in 0..3 {
         client.mint(
+            &admin,
             &owner2,
             &String::from_str(&e, "owner2"),
             &30,
@@ -545,6 +662,110 @@ fn test_get_nfts_by_owner() {
     }
 }
 
+// ============================================
+// Enumeration Tests
+// ============================================
+
+#[test]
+fn test_get_all_metadata_pagination() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    for _ in 0..5 {
+        mint_test_nft(&e, &client, &admin, &owner);
+    }
+
+    let page = client.get_all_metadata(&0, &2);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get(0).unwrap().token_id, 0);
+    assert_eq!(page.get(1).unwrap().token_id, 1);
+
+    let page = client.get_all_metadata(&4, &2);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().token_id, 4);
+
+    assert_eq!(client.get_all_metadata(&5, &2).len(), 0);
+}
+
+#[test]
+fn test_owner_enumeration_after_transfer() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_2 = mint_test_nft(&e, &client, &admin, &owner);
+
+    assert_eq!(client.token_of_owner_by_index(&owner, &1), token_1);
+
+    // Removing from the middle moves the last token into the freed slot
+    client.transfer(&owner, &recipient, &token_1);
+
+    assert_eq!(client.tokens_of_owner(&owner, &0, &10), vec![&e, token_0, token_2]);
+    assert_eq!(client.tokens_of_owner(&recipient, &0, &10), vec![&e, token_1]);
+    assert_eq!(client.token_of_owner_by_index(&recipient, &0), token_1);
+
+    let result = client.try_token_of_owner_by_index(&owner, &2);
+    assert_eq!(result, Err(Ok(ContractError::InvalidTokenId)));
+}
+
+#[test]
+fn test_tokens_of_owner_pagination() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    for _ in 0..4 {
+        mint_test_nft(&e, &client, &admin, &owner);
+    }
+
+    assert_eq!(client.tokens_of_owner(&owner, &1, &2), vec![&e, 1, 2]);
+    assert_eq!(client.tokens_of_owner(&owner, &3, &10), vec![&e, 3]);
+    assert_eq!(client.tokens_of_owner(&owner, &10, &10).len(), 0);
+}
+
+#[test]
+fn test_migrate_rebuilds_enumerations() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &owner);
+
+    // Rewrite storage into the v1 Vec-based layout
+    e.as_contract(&client.address, || {
+        let storage = e.storage().persistent();
+        for index in 0..2u32 {
+            storage.remove(&DataKey::OwnerTokenByIndex(owner.clone(), index));
+            storage.remove(&DataKey::TokenByIndex(index));
+        }
+        storage.remove(&DataKey::OwnerTokenIndex(token_0));
+        storage.remove(&DataKey::OwnerTokenIndex(token_1));
+        storage.remove(&DataKey::TokenIndex(token_0));
+        storage.remove(&DataKey::TokenIndex(token_1));
+        storage.set(&DataKey::OwnerTokens(owner.clone()), &vec![&e, token_0, token_1]);
+        e.storage()
+            .instance()
+            .set(&DataKey::TokenIds, &vec![&e, token_0, token_1]);
+        e.storage().instance().set(&DataKey::Version, &1u32);
+    });
+
+    client.migrate(&admin, &1);
+
+    assert_eq!(client.get_version(), CURRENT_VERSION);
+    assert_eq!(client.balance_of(&owner), 2);
+    assert_eq!(client.tokens_of_owner(&owner, &0, &10), vec![&e, token_0, token_1]);
+    assert_eq!(client.get_all_metadata(&0, &10).len(), 2);
+}
+
 // ============================================
 // Transfer Tests
 // ============================================
@@ -578,6 +799,7 @@ fn test_transfer() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner1,
         &commitment_id,
         &duration,
@@ -637,6 +859,7 @@ fn test_transfer_not_owner() {
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -667,117 +890,970 @@ fn test_transfer_nonexistent_token() {
 }
 
 // ============================================
-// Settle Tests
+// Batch Transfer Tests
 // ============================================
 
 #[test]
-fn test_settle() {
+fn test_batch_transfer() {
     let e = Env::default();
     let (admin, client) = setup_contract(&e);
     let owner = Address::generate(&e);
-    let asset_address = Address::generate(&e);
+    let custody = Address::generate(&e);
 
     client.initialize(&admin);
+    let mut transfers = Vec::new(&e);
+    for _ in 0..3 {
+        let token_id = mint_test_nft(&e, &client, &admin, &owner);
+        transfers.push_back(TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id,
+        });
+    }
 
-    // Mint with 1 day duration
-    let token_id = client.mint(
-        &owner,
-        &String::from_str(&e, "test_commitment"),
-        &1, // 1 day duration
-        &10,
-        &String::from_str(&e, "safe"),
-        &1000,
-        &asset_address,
-        &5,
-    );
-
-    // NFT should be active initially
-    assert_eq!(client.is_active(&token_id), true);
-
-    // Fast forward time past expiration (2 days = 172800 seconds)
-    e.ledger().with_mut(|li| {
-        li.timestamp = 172800;
-    });
+    let result = client.batch_transfer(&transfers, &BatchMode::Atomic);
 
-    // Verify it's expired
-    assert_eq!(client.is_expired(&token_id), true);
+    assert!(result.success);
+    assert_eq!(result.success_count, 3);
+    assert_eq!(client.balance_of(&owner), 0);
+    assert_eq!(client.balance_of(&custody), 3);
+    assert_eq!(client.tokens_of_owner(&custody, &0, &10).len(), 3);
+}
 
-    // Settle the NFT
-    client.settle(&token_id);
+#[test]
+fn test_batch_transfer_atomic_rejects_all() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let other = Address::generate(&e);
+    let custody = Address::generate(&e);
 
-    // NFT should now be inactive
-    assert_eq!(client.is_active(&token_id), false);
+    client.initialize(&admin);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &other);
+
+    let transfers = vec![
+        &e,
+        TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id: token_0,
+        },
+        TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id: token_1,
+        },
+    ];
+
+    let result = client.batch_transfer(&transfers, &BatchMode::Atomic);
+
+    assert!(!result.success);
+    assert_eq!(result.success_count, 0);
+    assert_eq!(result.errors.get(0).unwrap().index, 1);
+    assert_eq!(
+        result.errors.get(0).unwrap().error_code,
+        ContractError::NotOwner as u32
+    );
+    assert_eq!(client.owner_of(&token_0), owner);
+    assert_eq!(client.balance_of(&custody), 0);
+}
 
-    // Verify Settle event
-    let events = e.events().all();
-    let last_event = events.last().unwrap();
+#[test]
+fn test_batch_transfer_best_effort_reports_errors() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let custody = Address::generate(&e);
 
-    assert_eq!(last_event.0, client.address);
+    client.initialize(&admin);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &owner);
+
+    let transfers = vec![
+        &e,
+        TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id: token_0,
+        },
+        TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id: 999,
+        },
+        TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id: token_0,
+        },
+        TransferParams {
+            from: owner.clone(),
+            to: custody.clone(),
+            token_id: token_1,
+        },
+    ];
+
+    let result = client.batch_transfer(&transfers, &BatchMode::BestEffort);
+
+    assert!(!result.success);
+    assert_eq!(result.success_count, 2);
+    assert_eq!(result.errors.len(), 2);
     assert_eq!(
-        last_event.1,
-        vec![
-            &e,
-            symbol_short!("Settle").into_val(&e),
-            token_id.into_val(&e)
-        ]
+        result.errors.get(0).unwrap().error_code,
+        ContractError::TokenNotFound as u32
     );
-    let data: u64 = last_event.2.into_val(&e);
-    assert_eq!(data, e.ledger().timestamp());
+    assert_eq!(result.errors.get(1).unwrap().index, 2);
+    assert_eq!(
+        result.errors.get(1).unwrap().error_code,
+        ContractError::InvalidTokenId as u32
+    );
+    assert_eq!(client.balance_of(&custody), 2);
+    assert_eq!(client.owner_of(&token_1), custody);
 }
 
+// ============================================
+// Approval Tests
+// ============================================
+
 #[test]
-#[should_panic(expected = "Error(Contract, #9)")] // NotExpired
-fn test_settle_not_expired() {
+fn test_approve_and_transfer_from() {
     let e = Env::default();
     let (admin, client) = setup_contract(&e);
     let owner = Address::generate(&e);
-    let asset_address = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let recipient = Address::generate(&e);
 
     client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
 
-    let token_id = client.mint(
-        &owner,
-        &String::from_str(&e, "test_commitment"),
-        &30, // 30 days duration
-        &10,
-        &String::from_str(&e, "safe"),
-        &1000,
-        &asset_address,
-        &5,
-    );
+    let expiration = e.ledger().sequence() + 100;
+    client.approve(&owner, &spender, &token_id, &expiration);
+    assert_eq!(client.get_approved(&token_id), Some(spender.clone()));
 
-    // Try to settle before expiration (should fail)
-    client.settle(&token_id);
+    client.transfer_from(&spender, &owner, &recipient, &token_id);
+
+    assert_eq!(client.owner_of(&token_id), recipient);
+    assert_eq!(client.balance_of(&owner), 0);
+    assert_eq!(client.balance_of(&recipient), 1);
+    // Approval is cleared on transfer
+    assert_eq!(client.get_approved(&token_id), None);
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #8)")] // AlreadySettled
-fn test_settle_already_settled() {
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_transfer_from_without_approval_fails() {
     let e = Env::default();
     let (admin, client) = setup_contract(&e);
     let owner = Address::generate(&e);
-    let asset_address = Address::generate(&e);
+    let spender = Address::generate(&e);
 
     client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
 
-    let token_id = client.mint(
-        &owner,
-        &String::from_str(&e, "test_commitment"),
-        &1,
-        &10,
-        &String::from_str(&e, "safe"),
-        &1000,
-        &asset_address,
-        &5,
-    );
+    client.transfer_from(&spender, &owner, &spender, &token_id);
+}
+
+#[test]
+fn test_approval_expires() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    let expiration = e.ledger().sequence() + 10;
+    client.approve(&owner, &spender, &token_id, &expiration);
 
-    // Fast forward time
     e.ledger().with_mut(|li| {
-        li.timestamp = 172800;
+        li.sequence_number = expiration + 1;
     });
 
-    client.settle(&token_id);
-    client.settle(&token_id); // Should fail
+    assert_eq!(client.get_approved(&token_id), None);
+    let result = client.try_transfer_from(&spender, &owner, &spender, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+#[test]
+fn test_approve_zero_expiration_revokes() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    client.approve(&owner, &spender, &token_id, &(e.ledger().sequence() + 10));
+    client.approve(&owner, &spender, &token_id, &0);
+
+    assert_eq!(client.get_approved(&token_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #5)")] // NotOwner
+fn test_approve_by_non_owner_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let attacker = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    client.approve(&attacker, &attacker, &token_id, &(e.ledger().sequence() + 10));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")] // InvalidExpirationLedger
+fn test_approve_past_expiration_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let spender = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    e.ledger().with_mut(|li| {
+        li.sequence_number = 100;
+    });
+    client.approve(&owner, &spender, &token_id, &50);
+}
+
+#[test]
+fn test_set_approval_for_all() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let operator = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &owner);
+
+    assert!(!client.is_approved_for_all(&owner, &operator));
+    client.set_approval_for_all(&owner, &operator, &true);
+    assert!(client.is_approved_for_all(&owner, &operator));
+
+    client.transfer_from(&operator, &owner, &recipient, &token_0);
+    assert_eq!(client.owner_of(&token_0), recipient);
+
+    client.set_approval_for_all(&owner, &operator, &false);
+    assert!(!client.is_approved_for_all(&owner, &operator));
+
+    let result = client.try_transfer_from(&operator, &owner, &recipient, &token_1);
+    assert_eq!(result, Err(Ok(ContractError::NotAuthorized)));
+}
+
+// ============================================
+// Transfer Policy Tests
+// ============================================
+
+#[test]
+fn test_transfer_policy_defaults_to_free() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    assert_eq!(client.get_transfer_policy(&token_id), TransferPolicy::Free);
+}
+
+#[test]
+fn test_soulbound_while_active() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let recipient = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    client.set_transfer_policy(
+        &admin,
+        &String::from_str(&e, "balanced"),
+        &TransferPolicy::SoulboundWhileActive,
+    );
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+    assert_eq!(
+        client.get_transfer_policy(&token_id),
+        TransferPolicy::SoulboundWhileActive
+    );
+
+    let result = client.try_transfer(&owner, &recipient, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::TransferNotAllowed)));
+
+    // Settled NFTs become transferable
+    e.ledger().with_mut(|li| {
+        li.timestamp = 31 * 86400;
+    });
+    client.settle(&core, &token_id);
+    client.transfer(&owner, &recipient, &token_id);
+    assert_eq!(client.owner_of(&token_id), recipient);
+}
+
+#[test]
+fn test_allowlist_only_transfer() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let custodian = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_transfer_policy(
+        &admin,
+        &String::from_str(&e, "balanced"),
+        &TransferPolicy::AllowlistOnly,
+    );
+    client.set_transfer_allowlisted(&admin, &custodian, &true);
+    assert!(client.is_transfer_allowlisted(&custodian));
+
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    let result = client.try_transfer(&owner, &stranger, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::TransferNotAllowed)));
+
+    client.transfer(&owner, &custodian, &token_id);
+    assert_eq!(client.owner_of(&token_id), custodian);
+}
+
+#[test]
+fn test_marketplace_only_transfer() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let marketplace = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_transfer_policy(
+        &admin,
+        &String::from_str(&e, "balanced"),
+        &TransferPolicy::MarketplaceOnly,
+    );
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    // No marketplace configured: nothing may move
+    let result = client.try_transfer(&owner, &buyer, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::TransferNotAllowed)));
+
+    client.set_marketplace_contract(&admin, &marketplace);
+    let result = client.try_transfer(&owner, &buyer, &token_id);
+    assert_eq!(result, Err(Ok(ContractError::TransferNotAllowed)));
+
+    client.approve(&owner, &marketplace, &token_id, &(e.ledger().sequence() + 10));
+    client.transfer_from(&marketplace, &owner, &buyer, &token_id);
+    assert_eq!(client.owner_of(&token_id), buyer);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_set_transfer_policy_non_admin_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let attacker = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_transfer_policy(
+        &attacker,
+        &String::from_str(&e, "safe"),
+        &TransferPolicy::SoulboundWhileActive,
+    );
+}
+
+// ============================================
+// Token Metadata Tests
+// ============================================
+
+#[contract]
+struct MockCoreContract;
+
+#[contractimpl]
+impl MockCoreContract {
+    pub fn get_commitment(e: Env, commitment_id: String) -> Commitment {
+        Commitment {
+            commitment_id,
+            owner: Address::generate(&e),
+            nft_token_id: 0,
+            rules: CommitmentRules {
+                duration_days: 30,
+                max_loss_percent: 10,
+                commitment_type: String::from_str(&e, "balanced"),
+                early_exit_penalty: 5,
+                min_fee_threshold: 0,
+                grace_period_days: 0,
+            },
+            amount: 1000,
+            asset_address: Address::generate(&e),
+            created_at: 0,
+            expires_at: 30 * 86400,
+            current_value: 900,
+            status: String::from_str(&e, "violated"),
+        }
+    }
+}
+
+fn expected_token_uri(
+    e: &Env,
+    token_id: u32,
+    asset: &Address,
+    current_value: i128,
+    status: &str,
+) -> String {
+    let strkey = asset.to_string();
+    let mut asset_buf = std::vec![0u8; strkey.len() as usize];
+    strkey.copy_into_slice(&mut asset_buf);
+    let json = std::format!(
+        "{{\"name\":\"Commitment NFT #{}\",\"commitment_id\":\"commitment_001\",\"commitment_type\":\"balanced\",\"amount\":\"1000\",\"current_value\":\"{}\",\"asset\":\"{}\",\"expires_at\":{},\"status\":\"{}\"}}",
+        token_id,
+        current_value,
+        std::str::from_utf8(&asset_buf).unwrap(),
+        30 * 86400,
+        status
+    );
+    String::from_str(e, &json)
+}
+
+#[test]
+fn test_name_and_symbol() {
+    let e = Env::default();
+    let (_admin, client) = setup_contract(&e);
+
+    assert_eq!(client.name(), String::from_str(&e, "Commitment NFT"));
+    assert_eq!(client.symbol(), String::from_str(&e, "COMMIT"));
+}
+
+#[test]
+fn test_token_uri_without_core() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+    let asset = client.get_metadata(&token_id).metadata.asset_address;
+
+    assert_eq!(
+        client.token_uri(&token_id),
+        expected_token_uri(&e, token_id, &asset, 1000, "active")
+    );
+}
+
+#[test]
+fn test_token_uri_uses_live_core_status() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = e.register_contract(None, MockCoreContract);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+    let asset = client.get_metadata(&token_id).metadata.asset_address;
+
+    assert_eq!(
+        client.token_uri(&token_id),
+        expected_token_uri(&e, token_id, &asset, 900, "violated")
+    );
+}
+
+#[test]
+fn test_token_uri_base_uri_override() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    client.set_base_uri(&admin, &String::from_str(&e, "https://nft.example/"));
+    assert_eq!(
+        client.token_uri(&token_id),
+        String::from_str(&e, "https://nft.example/0")
+    );
+
+    client.set_base_uri(&admin, &String::from_str(&e, ""));
+    assert_eq!(client.get_base_uri(), None);
+}
+
+#[test]
+fn test_token_uri_too_long() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    let too_long = [b'a'; MAX_URI_LEN as usize + 1];
+    let result = client.try_set_base_uri(&admin, &String::from_bytes(&e, &too_long));
+    assert_eq!(result, Err(Ok(ContractError::UriTooLong)));
+
+    // A base URI at the limit leaves no room for the token_id
+    let at_limit = [b'a'; MAX_URI_LEN as usize];
+    client.set_base_uri(&admin, &String::from_bytes(&e, &at_limit));
+    let result = client.try_token_uri(&token_id);
+    assert_eq!(result, Err(Ok(ContractError::UriTooLong)));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")] // TokenNotFound
+fn test_token_uri_nonexistent_token() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+
+    client.initialize(&admin);
+    client.token_uri(&999);
+}
+
+// ============================================
+// Settle Tests
+// ============================================
+
+#[test]
+fn test_settle() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+    let core = Address::generate(&e);
+    client.set_core_contract(&core);
+
+    // Mint with 1 day duration
+    let token_id = client.mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "test_commitment"),
+        &1, // 1 day duration
+        &10,
+        &String::from_str(&e, "safe"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+
+    // NFT should be active initially
+    assert!(client.is_active(&token_id));
+
+    // Fast forward time past expiration (2 days = 172800 seconds)
+    e.ledger().with_mut(|li| {
+        li.timestamp = 172800;
+    });
+
+    // Verify it's expired
+    assert!(client.is_expired(&token_id));
+
+    // Settle the NFT
+    client.settle(&core, &token_id);
+
+    // NFT should now be inactive
+    assert!(!client.is_active(&token_id));
+
+    // Verify Settle event
+    let events = e.events().all();
+    let last_event = events.last().unwrap();
+
+    assert_eq!(last_event.0, client.address);
+    assert_eq!(
+        last_event.1,
+        vec![
+            &e,
+            symbol_short!("Settle").into_val(&e),
+            token_id.into_val(&e)
+        ]
+    );
+    let data: u64 = last_event.2.into_val(&e);
+    assert_eq!(data, e.ledger().timestamp());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #9)")] // NotExpired
+fn test_settle_not_expired() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+    let core = Address::generate(&e);
+    client.set_core_contract(&core);
+
+    let token_id = client.mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "test_commitment"),
+        &30, // 30 days duration
+        &10,
+        &String::from_str(&e, "safe"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+
+    // Try to settle before expiration (should fail)
+    client.settle(&core, &token_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #8)")] // AlreadySettled
+fn test_settle_already_settled() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+    let core = Address::generate(&e);
+    client.set_core_contract(&core);
+
+    let token_id = client.mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "test_commitment"),
+        &1,
+        &10,
+        &String::from_str(&e, "safe"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+
+    // Fast forward time
+    e.ledger().with_mut(|li| {
+        li.timestamp = 172800;
+    });
+
+    client.settle(&core, &token_id);
+    client.settle(&core, &token_id); // Should fail
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_settle_by_non_core_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let attacker = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+    let core = Address::generate(&e);
+    client.set_core_contract(&core);
+
+    let token_id = client.mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "test_commitment"),
+        &1,
+        &10,
+        &String::from_str(&e, "safe"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = 172800;
+    });
+
+    client.settle(&attacker, &token_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #1)")] // NotInitialized
+fn test_settle_without_core_contract_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let asset_address = Address::generate(&e);
+
+    client.initialize(&admin);
+
+    let token_id = client.mint(
+        &admin,
+        &owner,
+        &String::from_str(&e, "test_commitment"),
+        &1,
+        &10,
+        &String::from_str(&e, "safe"),
+        &1000,
+        &asset_address,
+        &5,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = 172800;
+    });
+
+    client.settle(&admin, &token_id);
+}
+
+// ============================================
+// Lifecycle and Burn Tests
+// ============================================
+
+#[test]
+fn test_set_lifecycle_state_from_core() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+    assert_eq!(client.get_lifecycle_state(&token_id), LifecycleState::Active);
+
+    // Early exit does not require expiry
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::EarlyExited);
+    assert_eq!(
+        client.get_lifecycle_state(&token_id),
+        LifecycleState::EarlyExited
+    );
+    assert!(!client.is_active(&token_id));
+
+    // Closed states are final
+    let result = client.try_set_lifecycle_state(&core, &token_id, &LifecycleState::Violated);
+    assert_eq!(result, Err(Ok(ContractError::InvalidLifecycleTransition)));
+}
+
+#[test]
+fn test_violated_lifecycle_can_be_reinstated_or_paid_out() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    // Overturned violation reactivates the NFT
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::Violated);
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::Active);
+    assert_eq!(
+        client.get_lifecycle_state(&token_id),
+        LifecycleState::Active
+    );
+    assert!(client.is_active(&token_id));
+
+    // A standing violation is paid out
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::Violated);
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::Settled);
+    assert_eq!(
+        client.get_lifecycle_state(&token_id),
+        LifecycleState::Settled
+    );
+    assert!(!client.is_active(&token_id));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_set_lifecycle_state_non_core_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    client.set_lifecycle_state(&owner, &token_id, &LifecycleState::Violated);
+}
+
+#[test]
+fn test_settle_sets_settled_state() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = 31 * 86400;
+    });
+    client.settle(&core, &token_id);
+
+    assert_eq!(client.get_lifecycle_state(&token_id), LifecycleState::Settled);
+}
+
+#[test]
+fn test_burn_closed_token() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &owner);
+    client.set_lifecycle_state(&core, &token_0, &LifecycleState::Violated);
+
+    assert_eq!(
+        client
+            .get_nfts_by_owner_and_state(&owner, &LifecycleState::Violated)
+            .len(),
+        1
+    );
+    assert_eq!(
+        client
+            .get_nfts_by_owner_and_state(&owner, &LifecycleState::Active)
+            .get(0)
+            .unwrap()
+            .token_id,
+        token_1
+    );
+
+    client.burn(&owner, &token_0);
+
+    assert!(!client.token_exists(&token_0));
+    assert_eq!(client.total_supply(), 1);
+    assert_eq!(client.balance_of(&owner), 1);
+    assert_eq!(client.get_nfts_by_owner(&owner).len(), 1);
+    assert_eq!(client.get_all_metadata(&0, &10).len(), 1);
+}
+
+#[test]
+fn test_core_can_burn_closed_token() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::EarlyExited);
+
+    client.burn(&core, &token_id);
+    assert_eq!(client.total_supply(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")] // InvalidLifecycleTransition
+fn test_burn_active_token_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    client.burn(&owner, &token_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_burn_by_stranger_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let core = Address::generate(&e);
+
+    client.initialize(&admin);
+    client.set_core_contract(&core);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+    client.set_lifecycle_state(&core, &token_id, &LifecycleState::Violated);
+
+    client.burn(&stranger, &token_id);
+}
+
+// ============================================
+// Royalty Tests
+// ============================================
+
+#[test]
+fn test_royalty_info_without_config_is_zero() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    assert_eq!(client.royalty_info(&token_id, &10_000), (owner, 0));
+}
+
+#[test]
+fn test_default_royalty_pays_creator_after_transfer() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let creator = Address::generate(&e);
+    let buyer = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &creator);
+    client.set_default_royalty(&admin, &None, &500); // 5%
+    client.transfer(&creator, &buyer, &token_id);
+
+    assert_eq!(client.royalty_info(&token_id, &10_000), (creator, 500));
+}
+
+#[test]
+fn test_token_royalty_overrides_default() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+    let collection_receiver = Address::generate(&e);
+    let artist = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_0 = mint_test_nft(&e, &client, &admin, &owner);
+    let token_1 = mint_test_nft(&e, &client, &admin, &owner);
+    client.set_default_royalty(&admin, &Some(collection_receiver.clone()), &250);
+    client.set_token_royalty(&admin, &token_1, &artist, &1000);
+
+    assert_eq!(client.royalty_info(&token_0, &10_000), (collection_receiver.clone(), 250));
+    assert_eq!(client.royalty_info(&token_1, &10_000), (artist, 1000));
+
+    client.remove_token_royalty(&admin, &token_1);
+    assert_eq!(client.royalty_info(&token_1, &10_000), (collection_receiver, 250));
+}
+
+#[test]
+fn test_royalty_above_cap_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let owner = Address::generate(&e);
+
+    client.initialize(&admin);
+    let token_id = mint_test_nft(&e, &client, &admin, &owner);
+
+    assert_eq!(
+        client.try_set_default_royalty(&admin, &None, &(MAX_ROYALTY_BPS + 1)),
+        Err(Ok(ContractError::InvalidRoyalty))
+    );
+    assert_eq!(
+        client.try_set_token_royalty(&admin, &token_id, &owner, &(MAX_ROYALTY_BPS + 1)),
+        Err(Ok(ContractError::InvalidRoyalty))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")] // NotAuthorized
+fn test_set_default_royalty_non_admin_fails() {
+    let e = Env::default();
+    let (admin, client) = setup_contract(&e);
+    let stranger = Address::generate(&e);
+
+    client.initialize(&admin);
+
+    client.set_default_royalty(&stranger, &Some(stranger.clone()), &500);
 }
 
 // ============================================
@@ -794,6 +1870,7 @@ fn test_is_expired() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test_commitment"),
         &1, // 1 day
@@ -805,7 +1882,7 @@ fn test_is_expired() {
     );
 
     // Should not be expired initially
-    assert_eq!(client.is_expired(&token_id), false);
+    assert!(!client.is_expired(&token_id));
 
     // Fast forward 2 days
     e.ledger().with_mut(|li| {
@@ -813,7 +1890,7 @@ fn test_is_expired() {
     });
 
     // Should now be expired
-    assert_eq!(client.is_expired(&token_id), true);
+    assert!(client.is_expired(&token_id));
 }
 
 #[test]
@@ -841,12 +1918,13 @@ fn test_token_exists() {
     client.initialize(&admin);
 
     // Token 0 should not exist yet
-    assert_eq!(client.token_exists(&0), false);
+    assert!(!client.token_exists(&0));
 
     let (commitment_id, duration, max_loss, commitment_type, amount, asset, penalty) =
         create_test_metadata(&e, &asset_address);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &commitment_id,
         &duration,
@@ -858,10 +1936,10 @@ fn test_token_exists() {
     );
 
     // Token should now exist
-    assert_eq!(client.token_exists(&token_id), true);
+    assert!(client.token_exists(&token_id));
 
     // Non-existent token should return false
-    assert_eq!(client.token_exists(&999), false);
+    assert!(!client.token_exists(&999));
 }
 
 // ============================================
@@ -907,6 +1985,7 @@ fn test_metadata_timestamps() {
     client.initialize(&admin);
 
     let token_id = client.mint(
+        &admin,
         &owner,
         &String::from_str(&e, "test"),
         &30, // 30 days
@@ -939,6 +2018,7 @@ fn test_balance_updates_after_transfer() {
 
     // Mint multiple NFTs for owner1
     client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_0"),
         &30,
@@ -949,6 +2029,7 @@ fn test_balance_updates_after_transfer() {
         &5,
     );
     client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_1"),
         &30,
@@ -959,6 +2040,7 @@ fn test_balance_updates_after_transfer() {
         &5,
     );
     client.mint(
+        &admin,
         &owner1,
         &String::from_str(&e, "commitment_2"),
         &30,
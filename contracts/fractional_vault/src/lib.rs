@@ -0,0 +1,615 @@
+#![no_std]
+//! Fractionalization vault for commitment NFTs.
+//!
+//! Each vault instance locks a single commitment NFT and issues SEP-41
+//! compatible share tokens. Shares are redeemable pro-rata for the settlement
+//! proceeds paid to the vault by commitment_core, or for the buyout price paid
+//! by whoever recombines the shares into the NFT.
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address,
+    Env, IntoVal, String, Symbol, Val, Vec,
+};
+
+/// Decimals reported for share tokens
+pub const SHARE_DECIMALS: u32 = 7;
+/// Share token name reported by `name()`
+pub const SHARE_NAME: &str = "Commitment Shares";
+/// Share token symbol reported by `symbol()`
+pub const SHARE_SYMBOL: &str = "CSHARE";
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Vault errors
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum VaultError {
+    /// Vault has not locked an NFT yet
+    NotInitialized = 1,
+    /// Vault already holds an NFT
+    AlreadyInitialized = 2,
+    /// Curator does not own the NFT
+    NotNftOwner = 3,
+    /// Amount must be positive (or non-negative for approvals)
+    InvalidAmount = 4,
+    /// Share balance too low
+    InsufficientBalance = 5,
+    /// Share allowance too low
+    InsufficientAllowance = 6,
+    /// Allowance expiration ledger is in the past
+    InvalidExpirationLedger = 7,
+    /// Operation requires the NFT to still be locked
+    NotLocked = 8,
+    /// Commitment is still active, there is nothing to redeem
+    CommitmentStillActive = 9,
+    /// Only active commitments can be fractionalized or bought out
+    CommitmentNotActive = 10,
+    /// Call to the NFT contract failed
+    NftContractError = 11,
+    /// Commitment was closed as violated and has not been paid out yet
+    SettlementPending = 12,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// Vault lifecycle
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VaultState {
+    /// NFT locked in the vault, shares trading
+    Locked,
+    /// Commitment settled; shares redeem for the settlement proceeds
+    Settled,
+    /// NFT bought out; shares redeem for the buyout payment
+    BoughtOut,
+}
+
+/// Summary of a vault
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultInfo {
+    pub nft_contract: Address,
+    pub token_id: u32,
+    pub curator: Address,
+    /// Commitment asset; settlement proceeds and buyouts are paid in it
+    pub payment_token: Address,
+    /// Price of the whole NFT for a buyout
+    pub reserve_price: i128,
+    pub state: VaultState,
+    /// Payment tokens still owed to share holders
+    pub proceeds: i128,
+    pub buyer: Option<Address>,
+}
+
+/// Share allowance, valid through `expiration_ledger`
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+// Mirrors of the commitment_nft types (defined locally for cross-contract calls)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentMetadata {
+    pub commitment_id: String,
+    pub duration_days: u32,
+    pub max_loss_percent: u32,
+    pub commitment_type: String,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub initial_amount: i128,
+    pub asset_address: Address,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LifecycleState {
+    Active,
+    Settled,
+    EarlyExited,
+    Violated,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommitmentNFT {
+    pub owner: Address,
+    pub token_id: u32,
+    pub metadata: CommitmentMetadata,
+    pub is_active: bool,
+    pub early_exit_penalty: u32,
+}
+
+/// Storage keys for the vault
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    /// Vault summary (VaultInfo)
+    Vault,
+    /// Outstanding shares
+    TotalSupply,
+    /// Share balance (holder -> i128)
+    Balance(Address),
+    /// Share allowance ((from, spender) -> AllowanceValue)
+    Allowance(Address, Address),
+}
+
+// ============================================================================
+// Contract
+// ============================================================================
+
+#[contract]
+pub struct FractionalVault;
+
+#[contractimpl]
+impl FractionalVault {
+    /// Lock `token_id` in this vault and mint `total_shares` to the curator.
+    ///
+    /// The curator must own the NFT and the commitment must be active.
+    /// `reserve_price` is the price of the whole NFT for a buyout;
+    /// `reserve_price * total_shares` must fit in an i128.
+    pub fn fractionalize(
+        e: Env,
+        curator: Address,
+        nft_contract: Address,
+        token_id: u32,
+        total_shares: i128,
+        reserve_price: i128,
+    ) -> Result<(), VaultError> {
+        curator.require_auth();
+
+        if e.storage().instance().has(&DataKey::Vault) {
+            return Err(VaultError::AlreadyInitialized);
+        }
+        if total_shares <= 0 || reserve_price <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        // Keeps buyout_price computable for any share split
+        if reserve_price.checked_mul(total_shares).is_none() {
+            return Err(VaultError::InvalidAmount);
+        }
+
+        let nft = read_nft(&e, &nft_contract, token_id)?;
+        if nft.owner != curator {
+            return Err(VaultError::NotNftOwner);
+        }
+        if !nft.is_active {
+            return Err(VaultError::CommitmentNotActive);
+        }
+
+        let vault = VaultInfo {
+            nft_contract: nft_contract.clone(),
+            token_id,
+            curator: curator.clone(),
+            payment_token: nft.metadata.asset_address,
+            reserve_price,
+            state: VaultState::Locked,
+            proceeds: 0,
+            buyer: None,
+        };
+        e.storage().instance().set(&DataKey::Vault, &vault);
+
+        // Lock the NFT
+        transfer_nft(&e, &nft_contract, &curator, &e.current_contract_address(), token_id);
+
+        e.storage().instance().set(&DataKey::TotalSupply, &total_shares);
+        write_balance(&e, &curator, total_shares);
+
+        e.events().publish(
+            (Symbol::new(&e, "Fractionalized"), token_id),
+            (curator.clone(), total_shares, reserve_price),
+        );
+        e.events().publish(
+            (symbol_short!("mint"), e.current_contract_address(), curator),
+            total_shares,
+        );
+
+        Ok(())
+    }
+
+    /// Vault summary
+    pub fn get_vault(e: Env) -> Result<VaultInfo, VaultError> {
+        read_vault(&e)
+    }
+
+    /// Payment needed for `buyer` to buy out the NFT.
+    ///
+    /// Shares the buyer already holds count toward the reserve price.
+    pub fn get_buyout_price(e: Env, buyer: Address) -> Result<i128, VaultError> {
+        let vault = read_vault(&e)?;
+        buyout_price(&e, &vault, &buyer)
+    }
+
+    /// Buy the NFT out of the vault at the reserve price.
+    ///
+    /// The buyer's own shares are burned and the remaining holders
+    /// redeem their shares for the buyer's payment.
+    pub fn buyout(e: Env, buyer: Address) -> Result<i128, VaultError> {
+        buyer.require_auth();
+
+        let mut vault = read_vault(&e)?;
+        if vault.state != VaultState::Locked {
+            return Err(VaultError::NotLocked);
+        }
+        if !read_nft(&e, &vault.nft_contract, vault.token_id)?.is_active {
+            return Err(VaultError::CommitmentNotActive);
+        }
+
+        let price = buyout_price(&e, &vault, &buyer)?;
+
+        // EFFECTS
+        let buyer_shares = read_balance(&e, &buyer);
+        if buyer_shares > 0 {
+            write_balance(&e, &buyer, 0);
+            write_total_supply(&e, read_total_supply(&e) - buyer_shares);
+            e.events().publish((symbol_short!("burn"), buyer.clone()), buyer_shares);
+        }
+        vault.state = VaultState::BoughtOut;
+        vault.proceeds = price;
+        vault.buyer = Some(buyer.clone());
+        e.storage().instance().set(&DataKey::Vault, &vault);
+
+        // INTERACTIONS
+        if price > 0 {
+            token::Client::new(&e, &vault.payment_token).transfer(
+                &buyer,
+                &e.current_contract_address(),
+                &price,
+            );
+        }
+        transfer_nft(
+            &e,
+            &vault.nft_contract,
+            &e.current_contract_address(),
+            &buyer,
+            vault.token_id,
+        );
+
+        e.events()
+            .publish((Symbol::new(&e, "BoughtOut"), vault.token_id), (buyer, price));
+
+        Ok(price)
+    }
+
+    /// Record settlement proceeds once the commitment has closed.
+    ///
+    /// Proceeds are the vault's whole payment token balance at that point,
+    /// including anything sent to the vault while it was locked. Callable by
+    /// anyone; `redeem` records settlement automatically.
+    pub fn record_settlement(e: Env) -> Result<i128, VaultError> {
+        let vault = settle_if_closed(&e)?;
+        Ok(vault.proceeds)
+    }
+
+    /// Burn `shares` for their pro-rata part of the settlement or buyout proceeds.
+    pub fn redeem(e: Env, holder: Address, shares: i128) -> Result<i128, VaultError> {
+        holder.require_auth();
+
+        if shares <= 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        let mut vault = settle_if_closed(&e)?;
+
+        let balance = read_balance(&e, &holder);
+        if balance < shares {
+            return Err(VaultError::InsufficientBalance);
+        }
+
+        // The last shares take any rounding dust
+        let supply = read_total_supply(&e);
+        let payout = vault
+            .proceeds
+            .checked_mul(shares)
+            .ok_or(VaultError::InvalidAmount)?
+            / supply;
+
+        write_balance(&e, &holder, balance - shares);
+        write_total_supply(&e, supply - shares);
+        vault.proceeds -= payout;
+        e.storage().instance().set(&DataKey::Vault, &vault);
+
+        if payout > 0 {
+            token::Client::new(&e, &vault.payment_token).transfer(
+                &e.current_contract_address(),
+                &holder,
+                &payout,
+            );
+        }
+
+        e.events()
+            .publish((symbol_short!("burn"), holder.clone()), shares);
+        e.events()
+            .publish((symbol_short!("Redeemed"), holder), (shares, payout));
+
+        Ok(payout)
+    }
+
+    // ========================================================================
+    // SEP-41 Token Interface
+    // ========================================================================
+
+    pub fn allowance(e: Env, from: Address, spender: Address) -> i128 {
+        read_allowance(&e, &from, &spender).amount
+    }
+
+    pub fn approve(
+        e: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), VaultError> {
+        from.require_auth();
+
+        if amount < 0 {
+            return Err(VaultError::InvalidAmount);
+        }
+        if amount > 0 && expiration_ledger < e.ledger().sequence() {
+            return Err(VaultError::InvalidExpirationLedger);
+        }
+
+        let key = DataKey::Allowance(from.clone(), spender.clone());
+        if amount == 0 {
+            e.storage().persistent().remove(&key);
+        } else {
+            e.storage().persistent().set(
+                &key,
+                &AllowanceValue {
+                    amount,
+                    expiration_ledger,
+                },
+            );
+        }
+
+        e.events().publish(
+            (symbol_short!("approve"), from, spender),
+            (amount, expiration_ledger),
+        );
+        Ok(())
+    }
+
+    pub fn balance(e: Env, id: Address) -> i128 {
+        read_balance(&e, &id)
+    }
+
+    pub fn transfer(e: Env, from: Address, to: Address, amount: i128) -> Result<(), VaultError> {
+        from.require_auth();
+        move_shares(&e, &from, &to, amount)
+    }
+
+    pub fn transfer_from(
+        e: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), VaultError> {
+        spender.require_auth();
+        spend_allowance(&e, &from, &spender, amount)?;
+        move_shares(&e, &from, &to, amount)
+    }
+
+    pub fn burn(e: Env, from: Address, amount: i128) -> Result<(), VaultError> {
+        from.require_auth();
+        burn_shares(&e, &from, amount)
+    }
+
+    pub fn burn_from(
+        e: Env,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), VaultError> {
+        spender.require_auth();
+        spend_allowance(&e, &from, &spender, amount)?;
+        burn_shares(&e, &from, amount)
+    }
+
+    pub fn decimals(_e: Env) -> u32 {
+        SHARE_DECIMALS
+    }
+
+    pub fn name(e: Env) -> String {
+        String::from_str(&e, SHARE_NAME)
+    }
+
+    pub fn symbol(e: Env) -> String {
+        String::from_str(&e, SHARE_SYMBOL)
+    }
+
+    /// Outstanding shares
+    pub fn total_supply(e: Env) -> i128 {
+        read_total_supply(&e)
+    }
+}
+
+// ============================================================================
+// Helpers
+// ============================================================================
+
+fn read_vault(e: &Env) -> Result<VaultInfo, VaultError> {
+    e.storage()
+        .instance()
+        .get(&DataKey::Vault)
+        .ok_or(VaultError::NotInitialized)
+}
+
+fn read_total_supply(e: &Env) -> i128 {
+    e.storage().instance().get(&DataKey::TotalSupply).unwrap_or(0)
+}
+
+fn write_total_supply(e: &Env, supply: i128) {
+    e.storage().instance().set(&DataKey::TotalSupply, &supply);
+}
+
+fn read_balance(e: &Env, id: &Address) -> i128 {
+    e.storage()
+        .persistent()
+        .get(&DataKey::Balance(id.clone()))
+        .unwrap_or(0)
+}
+
+fn write_balance(e: &Env, id: &Address, amount: i128) {
+    let key = DataKey::Balance(id.clone());
+    if amount == 0 {
+        e.storage().persistent().remove(&key);
+    } else {
+        e.storage().persistent().set(&key, &amount);
+    }
+}
+
+/// Live allowance; expired allowances read as zero
+fn read_allowance(e: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    let allowance: Option<AllowanceValue> = e
+        .storage()
+        .persistent()
+        .get(&DataKey::Allowance(from.clone(), spender.clone()));
+    match allowance {
+        Some(allowance) if allowance.expiration_ledger >= e.ledger().sequence() => allowance,
+        _ => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+fn spend_allowance(
+    e: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), VaultError> {
+    let mut allowance = read_allowance(e, from, spender);
+    if allowance.amount < amount {
+        return Err(VaultError::InsufficientAllowance);
+    }
+    allowance.amount -= amount;
+    e.storage()
+        .persistent()
+        .set(&DataKey::Allowance(from.clone(), spender.clone()), &allowance);
+    Ok(())
+}
+
+fn move_shares(e: &Env, from: &Address, to: &Address, amount: i128) -> Result<(), VaultError> {
+    if amount < 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+    let from_balance = read_balance(e, from);
+    if from_balance < amount {
+        return Err(VaultError::InsufficientBalance);
+    }
+    write_balance(e, from, from_balance - amount);
+    write_balance(e, to, read_balance(e, to) + amount);
+
+    e.events()
+        .publish((symbol_short!("transfer"), from.clone(), to.clone()), amount);
+    Ok(())
+}
+
+fn burn_shares(e: &Env, from: &Address, amount: i128) -> Result<(), VaultError> {
+    if amount < 0 {
+        return Err(VaultError::InvalidAmount);
+    }
+    let balance = read_balance(e, from);
+    if balance < amount {
+        return Err(VaultError::InsufficientBalance);
+    }
+    write_balance(e, from, balance - amount);
+    write_total_supply(e, read_total_supply(e) - amount);
+
+    e.events().publish((symbol_short!("burn"), from.clone()), amount);
+    Ok(())
+}
+
+/// Reserve price minus the part already covered by the buyer's shares (rounded up)
+fn buyout_price(e: &Env, vault: &VaultInfo, buyer: &Address) -> Result<i128, VaultError> {
+    let supply = read_total_supply(e);
+    if supply == 0 {
+        return Ok(0);
+    }
+    let others = supply - read_balance(e, buyer);
+    vault
+        .reserve_price
+        .checked_mul(others)
+        .and_then(|covered| covered.checked_add(supply - 1))
+        .map(|covered| covered / supply)
+        .ok_or(VaultError::InvalidAmount)
+}
+
+/// Move a Locked vault to Settled once its commitment has been paid out.
+///
+/// Proceeds are whatever commitment_core paid the vault as NFT holder, taken
+/// as the vault's whole payment token balance: the vault holds no other funds
+/// while locked, so any tokens sent to it directly are shared out too. Only
+/// the vault can exit early, so a closed commitment is either settled at
+/// maturity or still waiting on the payout of a violation.
+fn settle_if_closed(e: &Env) -> Result<VaultInfo, VaultError> {
+    let mut vault = read_vault(e)?;
+    if vault.state != VaultState::Locked {
+        return Ok(vault);
+    }
+    match read_lifecycle_state(e, &vault.nft_contract, vault.token_id)? {
+        LifecycleState::Active => return Err(VaultError::CommitmentStillActive),
+        LifecycleState::Violated => return Err(VaultError::SettlementPending),
+        LifecycleState::Settled | LifecycleState::EarlyExited => {}
+    }
+
+    vault.state = VaultState::Settled;
+    vault.proceeds =
+        token::Client::new(e, &vault.payment_token).balance(&e.current_contract_address());
+    e.storage().instance().set(&DataKey::Vault, &vault);
+
+    e.events().publish(
+        (Symbol::new(e, "VaultSettled"), vault.token_id),
+        vault.proceeds,
+    );
+    Ok(vault)
+}
+
+fn read_nft(e: &Env, nft_contract: &Address, token_id: u32) -> Result<CommitmentNFT, VaultError> {
+    let args: Vec<Val> = vec![e, token_id.into_val(e)];
+    match e.try_invoke_contract::<CommitmentNFT, soroban_sdk::Error>(
+        nft_contract,
+        &Symbol::new(e, "get_metadata"),
+        args,
+    ) {
+        Ok(Ok(nft)) => Ok(nft),
+        _ => Err(VaultError::NftContractError),
+    }
+}
+
+fn read_lifecycle_state(
+    e: &Env,
+    nft_contract: &Address,
+    token_id: u32,
+) -> Result<LifecycleState, VaultError> {
+    let args: Vec<Val> = vec![e, token_id.into_val(e)];
+    match e.try_invoke_contract::<LifecycleState, soroban_sdk::Error>(
+        nft_contract,
+        &Symbol::new(e, "get_lifecycle_state"),
+        args,
+    ) {
+        Ok(Ok(state)) => Ok(state),
+        _ => Err(VaultError::NftContractError),
+    }
+}
+
+fn transfer_nft(e: &Env, nft_contract: &Address, from: &Address, to: &Address, token_id: u32) {
+    let args: Vec<Val> = vec![
+        e,
+        from.into_val(e),
+        to.into_val(e),
+        token_id.into_val(e),
+    ];
+    e.invoke_contract::<()>(nft_contract, &Symbol::new(e, "transfer"), args);
+}
+
+#[cfg(test)]
+mod tests;
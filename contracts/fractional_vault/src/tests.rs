@@ -0,0 +1,391 @@
+#![cfg(test)]
+
+use super::*;
+use commitment_core::{CommitmentCoreContract, CommitmentCoreContractClient, CommitmentRules};
+use commitment_nft::{CommitmentNFTContract, CommitmentNFTContractClient, LifecycleState};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    Address, Env, String,
+};
+
+const TOTAL_SHARES: i128 = 1000;
+const RESERVE_PRICE: i128 = 10_000;
+
+struct Setup<'a> {
+    nft: CommitmentNFTContractClient<'a>,
+    vault: FractionalVaultClient<'a>,
+    asset: Address,
+    core: Address,
+    curator: Address,
+    token_id: u32,
+}
+
+fn setup(e: &Env) -> Setup<'_> {
+    e.mock_all_auths();
+
+    let admin = Address::generate(e);
+    let core = Address::generate(e);
+    let curator = Address::generate(e);
+    let asset = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+
+    let nft_id = e.register_contract(None, CommitmentNFTContract);
+    let nft = CommitmentNFTContractClient::new(e, &nft_id);
+    nft.initialize(&admin);
+    nft.set_core_contract(&core);
+    let token_id = nft.mint(
+        &admin,
+        &curator,
+        &String::from_str(e, "commitment_001"),
+        &30,
+        &10,
+        &String::from_str(e, "balanced"),
+        &100_000,
+        &asset,
+        &5,
+    );
+
+    let vault_id = e.register_contract(None, FractionalVault);
+    let vault = FractionalVaultClient::new(e, &vault_id);
+    vault.fractionalize(&curator, &nft_id, &token_id, &TOTAL_SHARES, &RESERVE_PRICE);
+
+    Setup {
+        nft,
+        vault,
+        asset,
+        core,
+        curator,
+        token_id,
+    }
+}
+
+/// Close the commitment and pay the vault as core would on settlement
+fn settle(e: &Env, s: &Setup, proceeds: i128) {
+    s.nft
+        .set_lifecycle_state(&s.core, &s.token_id, &LifecycleState::Settled);
+    StellarAssetClient::new(e, &s.asset).mint(&s.vault.address, &proceeds);
+}
+
+// ============================================================================
+// Fractionalization Tests
+// ============================================================================
+
+#[test]
+fn test_fractionalize_locks_nft_and_mints_shares() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    assert_eq!(s.nft.owner_of(&s.token_id), s.vault.address);
+    assert_eq!(s.vault.balance(&s.curator), TOTAL_SHARES);
+    assert_eq!(s.vault.total_supply(), TOTAL_SHARES);
+    assert_eq!(s.vault.decimals(), SHARE_DECIMALS);
+    assert_eq!(s.vault.symbol(), String::from_str(&e, SHARE_SYMBOL));
+
+    let info = s.vault.get_vault();
+    assert_eq!(info.state, VaultState::Locked);
+    assert_eq!(info.payment_token, s.asset);
+    assert_eq!(info.reserve_price, RESERVE_PRICE);
+}
+
+#[test]
+fn test_fractionalize_twice_fails() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    assert_eq!(
+        s.vault.try_fractionalize(
+            &s.curator,
+            &s.nft.address,
+            &s.token_id,
+            &TOTAL_SHARES,
+            &RESERVE_PRICE
+        ),
+        Err(Ok(VaultError::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_fractionalize_not_owner_fails() {
+    let e = Env::default();
+    let s = setup(&e);
+    let stranger = Address::generate(&e);
+
+    let vault = FractionalVaultClient::new(&e, &e.register_contract(None, FractionalVault));
+    assert_eq!(
+        vault.try_fractionalize(&stranger, &s.nft.address, &s.token_id, &100, &100),
+        Err(Ok(VaultError::NotNftOwner))
+    );
+}
+
+#[test]
+fn test_fractionalize_reserve_overflow_fails() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    let vault = FractionalVaultClient::new(&e, &e.register_contract(None, FractionalVault));
+    assert_eq!(
+        vault.try_fractionalize(&s.curator, &s.nft.address, &s.token_id, &2, &i128::MAX),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
+
+// ============================================================================
+// Share Token Tests
+// ============================================================================
+
+#[test]
+fn test_share_transfer_and_allowance() {
+    let e = Env::default();
+    let s = setup(&e);
+    let alice = Address::generate(&e);
+    let bob = Address::generate(&e);
+
+    // Shares work through the standard token client
+    let shares = TokenClient::new(&e, &s.vault.address);
+    shares.transfer(&s.curator, &alice, &300);
+    shares.approve(&alice, &bob, &100, &1000);
+    shares.transfer_from(&bob, &alice, &bob, &60);
+
+    assert_eq!(shares.balance(&s.curator), 700);
+    assert_eq!(shares.balance(&alice), 240);
+    assert_eq!(shares.balance(&bob), 60);
+    assert_eq!(shares.allowance(&alice, &bob), 40);
+
+    assert_eq!(
+        s.vault.try_transfer_from(&bob, &alice, &bob, &41),
+        Err(Ok(VaultError::InsufficientAllowance))
+    );
+    assert_eq!(
+        s.vault.try_transfer(&alice, &bob, &241),
+        Err(Ok(VaultError::InsufficientBalance))
+    );
+}
+
+// ============================================================================
+// Redemption Tests
+// ============================================================================
+
+#[test]
+fn test_redeem_settlement_proceeds_pro_rata() {
+    let e = Env::default();
+    let s = setup(&e);
+    let alice = Address::generate(&e);
+    let asset = TokenClient::new(&e, &s.asset);
+
+    s.vault.transfer(&s.curator, &alice, &250);
+    settle(&e, &s, 5_000);
+
+    assert_eq!(s.vault.redeem(&alice, &250), 1_250);
+    assert_eq!(s.vault.get_vault().state, VaultState::Settled);
+    assert_eq!(s.vault.redeem(&s.curator, &750), 3_750);
+
+    assert_eq!(asset.balance(&alice), 1_250);
+    assert_eq!(asset.balance(&s.curator), 3_750);
+    assert_eq!(s.vault.total_supply(), 0);
+    assert_eq!(s.vault.get_vault().proceeds, 0);
+}
+
+#[test]
+fn test_settlement_includes_tokens_sent_while_locked() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    // A direct transfer to the vault is part of the proceeds
+    StellarAssetClient::new(&e, &s.asset).mint(&s.vault.address, &500);
+    settle(&e, &s, 5_000);
+
+    assert_eq!(s.vault.record_settlement(), 5_500);
+    assert_eq!(s.vault.redeem(&s.curator, &TOTAL_SHARES), 5_500);
+}
+
+#[test]
+fn test_redeem_overflow_fails() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    settle(&e, &s, i128::MAX);
+    assert_eq!(
+        s.vault.try_redeem(&s.curator, &TOTAL_SHARES),
+        Err(Ok(VaultError::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_redeem_while_active_fails() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    assert_eq!(
+        s.vault.try_redeem(&s.curator, &100),
+        Err(Ok(VaultError::CommitmentStillActive))
+    );
+}
+
+// ============================================================================
+// Buyout Tests
+// ============================================================================
+
+#[test]
+fn test_buyout_recombines_nft() {
+    let e = Env::default();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+    let asset = TokenClient::new(&e, &s.asset);
+
+    // Buyer already holds a quarter of the shares
+    s.vault.transfer(&s.curator, &buyer, &250);
+    StellarAssetClient::new(&e, &s.asset).mint(&buyer, &RESERVE_PRICE);
+
+    assert_eq!(s.vault.get_buyout_price(&buyer), 7_500);
+    assert_eq!(s.vault.buyout(&buyer), 7_500);
+
+    assert_eq!(s.nft.owner_of(&s.token_id), buyer);
+    assert_eq!(s.vault.balance(&buyer), 0);
+    assert_eq!(s.vault.get_vault().state, VaultState::BoughtOut);
+
+    assert_eq!(s.vault.redeem(&s.curator, &750), 7_500);
+    assert_eq!(asset.balance(&s.curator), 7_500);
+    assert_eq!(asset.balance(&buyer), 2_500);
+}
+
+#[test]
+fn test_buyout_after_settlement_fails() {
+    let e = Env::default();
+    let s = setup(&e);
+    let buyer = Address::generate(&e);
+
+    settle(&e, &s, 5_000);
+
+    assert_eq!(
+        s.vault.try_buyout(&buyer),
+        Err(Ok(VaultError::CommitmentNotActive))
+    );
+}
+
+#[test]
+fn test_redeem_while_violated_is_pending() {
+    let e = Env::default();
+    let s = setup(&e);
+
+    s.nft
+        .set_lifecycle_state(&s.core, &s.token_id, &LifecycleState::Violated);
+
+    assert_eq!(
+        s.vault.try_redeem(&s.curator, &100),
+        Err(Ok(VaultError::SettlementPending))
+    );
+    assert_eq!(s.vault.get_vault().state, VaultState::Locked);
+}
+
+// ============================================================================
+// Commitment Core Integration Tests
+// ============================================================================
+
+struct CoreSetup<'a> {
+    core: CommitmentCoreContractClient<'a>,
+    vault: FractionalVaultClient<'a>,
+    asset: Address,
+    admin: Address,
+    curator: Address,
+    commitment_id: String,
+}
+
+/// Commitment created through commitment_core and fractionalized by the curator
+fn setup_with_core(e: &Env) -> CoreSetup<'_> {
+    e.mock_all_auths_allowing_non_root_auth();
+
+    let admin = Address::generate(e);
+    let curator = Address::generate(e);
+    let asset = e
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    StellarAssetClient::new(e, &asset).mint(&curator, &10_000);
+
+    let nft_id = e.register_contract(None, CommitmentNFTContract);
+    let nft = CommitmentNFTContractClient::new(e, &nft_id);
+    let core_id = e.register_contract(None, CommitmentCoreContract);
+    let core = CommitmentCoreContractClient::new(e, &core_id);
+    nft.initialize(&admin);
+    nft.set_core_contract(&core_id);
+    nft.add_minter(&admin, &core_id);
+    core.initialize(&admin, &nft_id);
+
+    let rules = CommitmentRules {
+        duration_days: 30,
+        max_loss_percent: 10,
+        commitment_type: String::from_str(e, "balanced"),
+        early_exit_penalty: 5,
+        min_fee_threshold: 0,
+        grace_period_days: 0,
+    };
+    let commitment_id = core.create_commitment(&curator, &10_000, &asset, &rules);
+    let token_id = core.get_commitment(&commitment_id).nft_token_id;
+
+    let vault = FractionalVaultClient::new(e, &e.register_contract(None, FractionalVault));
+    vault.fractionalize(&curator, &nft_id, &token_id, &TOTAL_SHARES, &RESERVE_PRICE);
+
+    CoreSetup {
+        core,
+        vault,
+        asset,
+        admin,
+        curator,
+        commitment_id,
+    }
+}
+
+#[test]
+fn test_curator_cannot_exit_fractionalized_commitment() {
+    let e = Env::default();
+    let s = setup_with_core(&e);
+    let alice = Address::generate(&e);
+    s.vault.transfer(&s.curator, &alice, &400);
+
+    // The vault holds the NFT, so the curator can no longer exit
+    assert!(s.core.try_early_exit(&s.commitment_id, &s.curator).is_err());
+    assert_eq!(
+        s.core.get_commitment(&s.commitment_id).status,
+        String::from_str(&e, "active")
+    );
+
+    // Settlement at maturity pays the vault
+    e.ledger().with_mut(|li| li.timestamp += 31 * 86400);
+    s.core.settle(&s.commitment_id);
+
+    let asset_client = TokenClient::new(&e, &s.asset);
+    assert_eq!(asset_client.balance(&s.vault.address), 10_000);
+    assert_eq!(s.vault.redeem(&alice, &400), 4_000);
+    assert_eq!(s.vault.redeem(&s.curator, &600), 6_000);
+    assert_eq!(asset_client.balance(&s.curator), 6_000);
+}
+
+#[test]
+fn test_violated_commitment_redeems_after_payout() {
+    let e = Env::default();
+    let s = setup_with_core(&e);
+    let alice = Address::generate(&e);
+    let enforcer = Address::generate(&e);
+    s.vault.transfer(&s.curator, &alice, &250);
+
+    s.core.set_enforcer(&s.admin, &enforcer, &true);
+    s.core.mark_violated(
+        &enforcer,
+        &s.commitment_id,
+        &String::from_str(&e, "violation"),
+    );
+
+    // Nothing has been paid yet, so shares cannot be redeemed
+    assert_eq!(
+        s.vault.try_redeem(&alice, &250),
+        Err(Ok(VaultError::SettlementPending))
+    );
+    assert!(s.core.try_early_exit(&s.commitment_id, &s.curator).is_err());
+
+    e.ledger().with_mut(|li| li.timestamp += 31 * 86400);
+    s.core.settle(&s.commitment_id);
+
+    assert_eq!(s.vault.redeem(&alice, &250), 2_500);
+    assert_eq!(s.vault.redeem(&s.curator, &750), 7_500);
+}
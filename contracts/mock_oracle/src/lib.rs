@@ -9,7 +9,7 @@
 //! - Error conditions
 
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, Address, Env, Map, Symbol, symbol_short,
+    contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, symbol_short,
 };
 
 /// Oracle-specific errors
@@ -25,9 +25,9 @@ impl ActionType {
     pub fn get_delay(&self) -> u64 {
         match self {
             ActionType::AdminChange => 172800,    // 2 days
-            ActionType::ParameterChange => 86400, // 1 day
+            ActionType::ParameterChange => MIN_DELAY,
             ActionType::Upgrade => 259200,        // 3 days
-            ActionType::FeeChange => 86400,       // 1 day
+            ActionType::FeeChange => MIN_DELAY,
         }
     }
 }
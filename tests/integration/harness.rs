@@ -111,6 +111,13 @@ impl TestHarness {
             CommitmentNFTContract::initialize(env.clone(), accounts.admin.clone()).unwrap();
             // Set core contract for NFT
             CommitmentNFTContract::set_core_contract(env.clone(), commitment_core.clone()).unwrap();
+            // Allow commitment_core to mint NFTs
+            CommitmentNFTContract::add_minter(
+                env.clone(),
+                accounts.admin.clone(),
+                commitment_core.clone(),
+            )
+            .unwrap();
         });
 
         // Initialize commitment_core
@@ -41,6 +41,8 @@ impl IntegrationTestFixture {
         let core_contract_id = env.register_contract(None, CommitmentCoreContract);
         let core_client = CommitmentCoreContractClient::new(&env, &core_contract_id);
         core_client.initialize(&admin, &nft_contract_id);
+        nft_client.set_core_contract(&core_contract_id);
+        nft_client.add_minter(&admin, &core_contract_id);
 
         // Deploy Attestation Engine contract
         let attestation_contract_id = env.register_contract(None, AttestationEngineContract);